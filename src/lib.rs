@@ -49,6 +49,121 @@
 //! These mostly follow a (slight superset of) Rust's syntax, with the exception
 //! that a trailing type indicator is not allowed.
 //!
+//! For values that should always be written in hex, like addresses and masks,
+//! add `hex_required` after the type. Then a value without the `0x` prefix
+//! (such as `deadbeef` or `123`) is an error, rather than being read as decimal
+//! or failing on the first letter.
+//!
+//! ```
+//! const MASK: u32 = envparse::parse_env!("MYCRATE_MASK" as u32 hex_required else 0xffff_0000);
+//! ```
+//!
+//! For addresses that must also be aligned, like the base of a register block,
+//! an unsigned type can be followed by `hex_required aligned N`, where `N` is a
+//! power of two. A value that isn't a multiple of `N` is an error (as is a
+//! default that isn't), and an `N` that isn't a power of two is rejected.
+//!
+//! ```
+//! const MMIO_BASE: usize =
+//!     envparse::parse_env!("MYCRATE_MMIO_BASE" as usize hex_required aligned 4096 else 0x4000_0000);
+//! ```
+//!
+//! For fixed-width hex values, like a short commit hash, `hex digits N` after
+//! an unsigned type requires exactly `N` hex digits, with an optional `0x`
+//! prefix (and no underscores or sign).
+//!
+//! ```
+//! // With `MYCRATE_BUILD_HASH=1a2b3c`, this would be `0x1a2b3c`.
+//! const BUILD_HASH: u32 = envparse::parse_env!("MYCRATE_BUILD_HASH" as u32 hex digits 6 else 0);
+//! ```
+//!
+//! When the width isn't known up front (say, in generic code), `int` accepts
+//! the full integer syntax, including negative numbers, and always produces an
+//! `i128` for you to narrow yourself. Ranges given with `in` are of `i128`.
+//!
+//! ```
+//! const OFFSET: i128 = envparse::parse_env!("MYCRATE_OFFSET" as int (in -1000..=1000) else 0);
+//! let offset = i16::try_from(OFFSET).unwrap();
+//! # assert_eq!(offset, 0);
+//! ```
+//!
+//! For sizes relative to some other constant, like a thread count based on the
+//! number of cores, `relative_to N` after an unsigned type accepts `N` itself,
+//! `N` followed by `+`, `-`, `*` or `/` and a number (like `N-1` or `N/2`), or
+//! a plain number. `N` is case-insensitive, whitespace is ignored, and the
+//! arithmetic saturates at the bounds of the type. The base is a single token,
+//! so wrap it in parentheses if it's more than a name or literal.
+//!
+//! ```
+//! const CORES: usize = 8;
+//! // With `MYCRATE_THREADS=N-1`, this would be 7, and with `N/2`, it would be 4.
+//! const THREADS: usize = envparse::parse_env!("MYCRATE_THREADS" as usize relative_to CORES else CORES);
+//! # assert_eq!(THREADS, 8);
+//! ```
+//!
+//! When the acceptable range is itself configurable, `"VAR" in_env_range
+//! "RANGE_VAR" as T` reads the range from a second variable, written like a
+//! Rust range of integers: `10..100`, `10..=99`, `10..` or `..100`. The range
+//! must be non-empty. If `RANGE_VAR` is unset or blank, any `T` is allowed. A
+//! value outside the range fails to build (as does a default given with
+//! `else`), unless `clamp` is added after the type, in which case the value
+//! (and the default) are clamped to the range instead.
+//!
+//! ```
+//! // With `MYCRATE_WORKERS_RANGE=1..=16`, a value of 32 would fail to build,
+//! // but with `clamp`, it would be 16.
+//! const WORKERS: u32 = envparse::parse_env!("MYCRATE_WORKERS" in_env_range "MYCRATE_WORKERS_RANGE" as u32 else 4);
+//! const WORKERS_CLAMPED: u32 =
+//!     envparse::parse_env!("MYCRATE_WORKERS" in_env_range "MYCRATE_WORKERS_RANGE" as u32 clamp else 4);
+//! # assert_eq!((WORKERS, WORKERS_CLAMPED), (4, 4));
+//! ```
+//!
+//! ## File modes
+//!
+//! `mode` parses a file permission mode into a `u32`, like `chmod` does: the
+//! number is **always octal**, even without a `0o` prefix, unlike everywhere
+//! else, where a bare number is decimal. So `644`, `0644` and `0o644` are all
+//! `0o644`. The mode can be at most `0o7777`, and a digit like `8` or `9` fails
+//! to build, rather than being read as decimal.
+//!
+//! ```
+//! // With `MYCRATE_LOG_MODE=640`, this would be `0o640`.
+//! const LOG_MODE: u32 = envparse::parse_env!("MYCRATE_LOG_MODE" as mode else 0o644);
+//! ```
+//!
+//! ## `NonZero` integers
+//!
+//! The `NonZero*` types from [`core::num`] (like `NonZeroU32` or `NonZeroI8`)
+//! are supported too, and fail to parse if the value is zero. Ranges given with
+//! `in` are of the underlying integer type, and must not include zero (nor be
+//! empty), since nothing would ever parse. That's checked at compile time, even
+//! if the variable isn't set.
+//!
+//! ```
+//! use core::num::NonZeroU32;
+//! const WORKERS: NonZeroU32 = envparse::parse_env!("MYCRATE_WORKERS" as NonZeroU32 (in 1..=16) else NonZeroU32::MIN);
+//! ```
+//!
+//! ```compile_fail
+//! use core::num::NonZeroU32;
+//! // Error: the range includes zero.
+//! const WORKERS: NonZeroU32 = envparse::parse_env!("MYCRATE_WORKERS" as NonZeroU32 (in 0..=16) else NonZeroU32::MIN);
+//! ```
+//!
+//! ## `Wrapping` integers
+//!
+//! `Wrapping<T>` (from [`core::num`]), where `T` is a primitive integer type,
+//! parses a `T` and wraps it, for values used in wrapping arithmetic like hash
+//! seeds. The value still has to fit in `T`: only the arithmetic wraps, not
+//! the parsing. Ranges given with `in` are of `T`, and a default given with
+//! `else` is a `Wrapping<T>`.
+//!
+//! ```
+//! use core::num::Wrapping;
+//! const SEED: Wrapping<u32> = envparse::parse_env!("MYCRATE_SEED" as Wrapping<u32> else Wrapping(0x9e37_79b9));
+//! # assert_eq!(SEED, Wrapping(0x9e37_79b9));
+//! ```
+//!
 //! ## Booleans
 //!
 //! Booleans are supported, following some mostly ad-hoc conventions described
@@ -58,10 +173,586 @@
 //! Note that the empty string is not considered a valid bool, so `FOOBAR=""`
 //! neither works to enable or disable something.
 //!
-//! | `bool` value | accepted strings (case-insensitive, trimmed) |
-//! | :--          | :--                                          |
-//! | `false`      | `0`, `false`, `f`, `off`, `no` or `n`        |
-//! | `true`       | `1`, `true`, `t`, `on`, `yes` or `y`         |
+//! | `bool` value | accepted strings (case-insensitive, trimmed)                 |
+//! | :--          | :--                                                          |
+//! | `false`      | `0`, `false`, `f`, `off`, `no`, `n`, `disable` or `disabled` |
+//! | `true`       | `1`, `true`, `t`, `on`, `yes`, `y`, `enable` or `enabled`    |
+//!
+//! With the `bool-i18n` feature enabled, `bool` also accepts the words for yes
+//! and no (and true and false) in a few other languages, like `oui`/`non` and
+//! `ja`/`nein`. See `parse::BOOL_I18N_TOKENS` for the full list. This is off by
+//! default.
+//!
+//! Similarly, the `bool-emoji` feature makes `bool` accept check marks for
+//! `true` (`✓`, `✔` or `✅`) and crosses for `false` (`✗`, `✘` or `❌`), for
+//! values that come from UIs which use them. See `parse::BOOL_EMOJI_TOKENS`.
+//! This is also off by default.
+//!
+//! For matching a wire format, `bool_tf` is the strict opposite: it accepts
+//! exactly `T` (for `true`) or `F` (for `false`), case-sensitively, with no
+//! whitespace allowed. Anything else (including `t`, `1` or `true`) is an error.
+//!
+//! ```
+//! const ACK: bool = envparse::parse_env!("MYCRATE_ACK" as bool_tf else true);
+//! ```
+//!
+//! Going the other way, `bool_first_char` is for sloppy upstream configs from
+//! legacy tools that only look at the first character: after any whitespace,
+//! `t`, `T` or `1` is `true`, `f`, `F` or `0` is `false`, and the rest of the
+//! value is ignored. This is lossy (`truthy` and `falsey` are accepted, as is a
+//! typo like `ture`), so it's only used when asked for, and never by `bool`.
+//!
+//! ```
+//! // With `MYCRATE_LEGACY=Tru`, this would be `true`.
+//! const LEGACY: bool = envparse::parse_env!("MYCRATE_LEGACY" as bool_first_char else false);
+//! ```
+//!
+//! For interoperating with tools that use `set -e` / `set +e` style flags,
+//! `bool_sign` accepts exactly `+` (for `true`) or `-` (for `false`). Giving a
+//! name, as in `bool_sign "e"`, also accepts it directly after the sign, so
+//! `+e` and `-e` work too. The name is case-sensitive, and anything else (like
+//! `+E`, `+ e` or `e`) is an error. See [`parse::parse_bool_sign_named`].
+//!
+//! ```
+//! // With `MYCRATE_ERREXIT=-e`, this would be `false`.
+//! const ERREXIT: bool = envparse::parse_env!("MYCRATE_ERREXIT" as bool_sign "e" else true);
+//! ```
+//!
+//! For settings given as a count but used as a switch, like a verbosity level,
+//! `bool_at_least N` parses a non-negative integer and produces `true` if it's
+//! at least `N`, which must be a `u128` (a literal, or a name or parenthesized
+//! expression of that type). Anything else (including words like `yes`) is an
+//! error, not `false`.
+//!
+//! ```
+//! // With `MYCRATE_VERBOSE=3`, this would be `true`, and `false` with `1`.
+//! const TRACE: bool = envparse::parse_env!("MYCRATE_VERBOSE" as bool_at_least 2 else false);
+//! ```
+//!
+//! ## Sizes with case-sensitive suffixes
+//!
+//! `cased_size` parses a `usize` with an optional suffix, using the convention
+//! (found in some other tools) where lowercase suffixes are SI multipliers, and
+//! uppercase suffixes are binary ones. Note that this is case-sensitive, unlike
+//! everything else in this crate, which is why it's opt-in.
+//!
+//! | suffix | multiplier | suffix | multiplier |
+//! | :--    | :--        | :--    | :--        |
+//! | `k`    | 1000¹      | `K`    | 1024¹      |
+//! | `m`    | 1000²      | `M`    | 1024²      |
+//! | `g`    | 1000³      | `G`    | 1024³      |
+//! | `t`    | 1000⁴      | `T`    | 1024⁴      |
+//! | `p`    | 1000⁵      | `P`    | 1024⁵      |
+//!
+//! ```
+//! // With `MYCRATE_BUF_SIZE=4K`, this would be 4096 (and 4000 with `4k`).
+//! const BUF_SIZE: usize = envparse::parse_env!("MYCRATE_BUF_SIZE" as cased_size (in ..=1 << 20) else 8192);
+//! ```
+//!
+//! ## Pages
+//!
+//! `pages(N)` parses a count of pages of `N` bytes each, producing the number
+//! of bytes as a `usize`, so with `pages(4096)`, a value of `16` is `65536`.
+//! The count uses the integer syntax. Ranges given with `in` apply to the byte
+//! count, rather than the number of pages.
+//!
+//! ```
+//! const ARENA_BYTES: usize = envparse::parse_env!("MYCRATE_ARENA" as pages(4096) (in ..=1 << 30) else 1 << 16);
+//! ```
+//!
+//! ## Rates
+//!
+//! `rate` parses a throughput like `10MB/s` into a `u64` number of bytes per
+//! second. The size uses the `cased_size` suffixes (so `10MB/s` is `10 << 20`,
+//! and `10mB/s` is `10_000_000`), and the time unit after the `/` is one of
+//! `ms`, `s`, `min` or `h`. Note that minutes are `min` here, but `m` in a
+//! `duration`. Rates per minute or hour are rounded down to whole bytes per
+//! second. Ranges given with `in` apply to the bytes per second.
+//!
+//! ```
+//! const UPLOAD_LIMIT: u64 = envparse::parse_env!("MYCRATE_UPLOAD_LIMIT" as rate (in 1..) else 1 << 20);
+//! ```
+//!
+//! ## Degrees
+//!
+//! `degrees` parses an `i32` with an optional `deg` (case-insensitive) or `°`
+//! suffix, for things like angles and temperatures, so `90`, `90deg` and `90°`
+//! are all `90`. As with integers, a range can be given with `in`.
+//!
+//! ```
+//! const ANGLE: i32 = envparse::parse_env!("MYCRATE_ANGLE" as degrees (in -180..=180) else 0);
+//! ```
+//!
+//! ## Basis points
+//!
+//! `basis_points` parses a decimal fraction like `0.0325` into an `i64` number
+//! of basis points (hundredths of a percent), which is the value times 10000,
+//! so `0.0325` is `325`, and `1` is `10000`. This gives exact rates without
+//! floats, so at most 4 digits are allowed after the `.`, and more precision
+//! than that fails to build. As with integers, a range can be given with `in`,
+//! which applies to the basis points.
+//!
+//! ```
+//! // With `MYCRATE_FEE_RATE=0.0325`, this would be `325`.
+//! const FEE_RATE_BP: i64 = envparse::parse_env!("MYCRATE_FEE_RATE" as basis_points (in 0..=10000) else 25);
+//! ```
+//!
+//! ## Fixed-point numbers
+//!
+//! `q(I, F)` parses a decimal number into a signed fixed-point integer in Q
+//! format, with `I` integer bits (including the sign bit) and `F` fractional
+//! bits, which is the value times `2^F`. This is for baking things like DSP
+//! coefficients into a build without floats. The result is rounded to the
+//! nearest representable value, with ties rounded away from zero (see
+//! [`parse::parse_qfixed`]).
+//!
+//! The result is an `i128` which fits in `I + F` bits, so it can be converted
+//! to a narrower type with `as`. A value that doesn't fit is an error, unless
+//! `clamp` is given, in which case it saturates.
+//!
+//! ```
+//! // With `MYCRATE_COEF=1.5`, this would be `0x0001_8000`.
+//! const COEF: i32 = envparse::parse_env!("MYCRATE_COEF" as q(16, 16) else 0x0001_0000) as i32;
+//! const GAIN: i16 = envparse::parse_env!("MYCRATE_GAIN" as q(1, 15) clamp else 0x4000) as i16;
+//! # assert_eq!((COEF, GAIN), (0x0001_0000, 0x4000));
+//! ```
+//!
+//! ## Durations
+//!
+//! `duration` parses a [`Duration`](core::time::Duration) in the format used
+//! by Go's `time.ParseDuration`, such as `1h30m`, `1.5h`, `250ms` or `0`, so
+//! configs can be shared with Go tools. See [`parse::parse_duration`] for the
+//! details, and the few places we differ (mainly, negative durations aren't
+//! allowed).
+//!
+//! ```
+//! use core::time::Duration;
+//! const TIMEOUT: Duration = envparse::parse_env!("MYCRATE_TIMEOUT" as duration else Duration::from_secs(30));
+//! ```
+//!
+//! `duration_range` parses a range of durations like `100ms..=500ms`, for
+//! things like jittered backoff, into a `(u64, u64)` of the inclusive bounds in
+//! nanoseconds. Each side needs its own unit (`100..500ms` is an error, rather
+//! than a guess), and as with other ranges, `..` excludes the end. Reversed or
+//! empty ranges are errors. See [`parse::parse_duration_range`] for the details.
+//!
+//! ```
+//! // With `MYCRATE_RETRY=100ms..=500ms`, this would be `(100_000_000, 500_000_000)`.
+//! const RETRY_NS: (u64, u64) = envparse::parse_env!("MYCRATE_RETRY" as duration_range else (50_000_000, 250_000_000));
+//! # assert_eq!(RETRY_NS, (50_000_000, 250_000_000));
+//! ```
+//!
+//! ## Characters
+//!
+//! `char` is supported, and the value must be exactly one character (ignoring
+//! surrounding whitespace, although a single whitespace character is accepted
+//! as-is). Ranges work here too, and compare by code point.
+//!
+//! ```
+//! const DELIM: char = envparse::parse_env!("MYCRATE_DELIM" as char (in '!'..='~') else ',');
+//! ```
+//!
+//! For characters that are hard to type, `char_code` parses a code point
+//! number into a `char` instead, written as `U+` followed by hex digits (like
+//! `U+001F`), or as an integer (like `0x1F` or `31`). Surrogates and numbers
+//! above `0x10FFFF` aren't valid `char`s, so they fail to build.
+//!
+//! ```
+//! // With `MYCRATE_RECORD_SEP=U+001E`, this would be `'\x1e'`.
+//! const RECORD_SEP: char = envparse::parse_env!("MYCRATE_RECORD_SEP" as char_code else '\x1e');
+//! ```
+//!
+//! Since `char_code` works in [arrays](#arrays), a small set of characters
+//! can be given as a list of code points, which is handy for input filters in
+//! `no_std` code. With `loose`, they can be separated by spaces, as in `U+0041
+//! U+0042 U+0043`. Each item is checked separately, so the error for a bad
+//! code point says which one it was, and the count has to match as usual.
+//!
+//! ```
+//! // With `MYCRATE_ALLOWED=U+0041 U+0042 U+0043`, this would be `['A', 'B', 'C']`.
+//! const ALLOWED: [char; 3] = envparse::parse_env!("MYCRATE_ALLOWED" as [char_code; 3] loose else ['a', 'b', 'c']);
+//! ```
+//!
+//! ## Ratios
+//!
+//! `ratio` parses a fraction like `-3/4` into a `(numerator, denominator)` pair
+//! of `i128`s, as a float-free way to configure fractional values. Only the
+//! numerator may have a sign, and the denominator must be nonzero. A plain
+//! integer like `3` is accepted as `3/1`. The fraction is not reduced (see
+//! [`parse::parse_ratio`] if you want that).
+//!
+//! ```
+//! // With `MYCRATE_GAIN=-3/4`, this would be `(-3, 4)`.
+//! const GAIN: (i128, i128) = envparse::parse_env!("MYCRATE_GAIN" as ratio else (1, 2));
+//! ```
+//!
+//! ## `on`/`off`/`auto`
+//!
+//! `on_off_auto` parses exactly one of `on`, `off` or `auto` (case-insensitive,
+//! trimmed) into an [`OnOffAuto`]. See its documentation for why it doesn't
+//! accept the other spellings the `bool` parser does.
+//!
+//! ## `auto` or a number
+//!
+//! `auto_or(T)`, where `T` is a primitive integer type, produces an
+//! `Option<T>`. A value of `auto` (case-insensitive, trimmed), an empty value,
+//! or `0` all give `None`, and any other integer gives `Some`. Negative values
+//! are an error, even if `T` is signed. A range given with `in` applies to the
+//! numeric branch only, so `0` is still `None` with `in 1..=64`. A default
+//! given with `else` is an `Option<T>`.
+//!
+//! ```
+//! const THREADS: Option<usize> = envparse::parse_env!("MYCRATE_THREADS" as auto_or(usize) (in 1..=64) else None);
+//! ```
+//!
+//! ## Limits
+//!
+//! `limit(T)`, where `T` is a primitive integer type, is for ulimit-style
+//! settings. It produces a [`Limit<T>`]: `auto` gives `Limit::Auto`, `max`
+//! gives `Limit::Max` (both case-insensitive, trimmed), and an integer gives
+//! `Limit::Value`. [`Limit::resolve`] turns this into a `T`, with `max` as
+//! `T::MAX`, and `auto` as a value you provide. A default given with `else` is
+//! a `Limit<T>`.
+//!
+//! ```
+//! use envparse::Limit;
+//! const MAX_CONNS: u32 = envparse::parse_env!("MYCRATE_MAX_CONNS" as limit(u32) else Limit::Value(100)).resolve(64);
+//! # assert_eq!(MAX_CONNS, 100);
+//! ```
+//!
+//! ## Flags with an argument
+//!
+//! `flag(T; on N)`, where `T` is a primitive integer type, is for settings that
+//! are either off, or on with an optional argument, producing an `Option<T>`.
+//! Anything that parses as a false [boolean](#booleans) (like `off` or `0`)
+//! gives `None`, and a true one (like `on`) gives `Some(N)`. A true one can be
+//! followed by `:` and an argument, as in `on:256`, which gives `Some(256)`.
+//! The argument has to parse as a `T`, and a false flag can't have one. A
+//! default given with `else` is an `Option<T>`.
+//!
+//! ```
+//! // With `MYCRATE_CACHE=on:256`, this would be `Some(256)`, with `on`, it
+//! // would be `Some(64)`, and with `off`, it would be `None`.
+//! const CACHE_SIZE: Option<u32> = envparse::parse_env!("MYCRATE_CACHE" as flag(u32; on 64) else None);
+//! ```
+//!
+//! For gradual rollouts, `weighted_flag` parses a boolean with an optional
+//! percentage after a `:`, producing a `(bool, u8)`, so `true:50` is `(true,
+//! 50)`. Without a percentage, `true` is `(true, 100)`, and `false` is `(false,
+//! 0)`. The percentage must be from 0 to 100, and a false flag can't have one.
+//!
+//! ```
+//! // With `MYCRATE_NEW_PARSER=on:25`, this would be `(true, 25)`.
+//! const NEW_PARSER: (bool, u8) = envparse::parse_env!("MYCRATE_NEW_PARSER" as weighted_flag else (false, 0));
+//! ```
+//!
+//! ## Self-bounded integers
+//!
+//! `self_bounded(T)`, where `T` is a primitive integer type, parses a value
+//! that carries its own bounds, as `value:min:max` (e.g. `8:1:64`). This checks
+//! that `min <= value <= max`, and produces just the value as a `T`. Each part
+//! uses the integer syntax.
+//!
+//! ```
+//! // With `MYCRATE_POOL=8:1:64`, this would be 8, and `100:1:64` would fail.
+//! const POOL: usize = envparse::parse_env!("MYCRATE_POOL" as self_bounded(usize) else 4);
+//! ```
+//!
+//! ## Sort directions
+//!
+//! `sort_dir` parses `asc`, `ascending` or `<`, or `desc`, `descending` or `>`
+//! (case-insensitive, trimmed) into a [`SortDir`].
+//!
+//! ## Prompt policies
+//!
+//! `prompt_policy` parses a setting for whether an interactive tool should ask
+//! before doing something into a [`PromptPolicy`]. The synonyms are
+//! (case-insensitive, trimmed):
+//!
+//! - `yes` or `always`: [`PromptPolicy::Always`], go ahead without asking.
+//! - `no` or `never`: [`PromptPolicy::Never`], decline without asking.
+//! - `ask` or `prompt`: [`PromptPolicy::Ask`], ask each time.
+//!
+//! Anything else is an error. In particular, the other spellings the `bool`
+//! parser accepts (like `1` or `true`) aren't, since it's unclear whether
+//! they'd mean "always" or "ask".
+//!
+//! ```
+//! use envparse::PromptPolicy;
+//! // With `MYCRATE_CONFIRM=never`, this would be `PromptPolicy::Never`.
+//! const CONFIRM: PromptPolicy = envparse::parse_env!("MYCRATE_CONFIRM" as prompt_policy else PromptPolicy::Ask);
+//! # assert_eq!(CONFIRM, PromptPolicy::Ask);
+//! ```
+//!
+//! ## Lint levels
+//!
+//! `lint_level` parses `allow`, `warn`, `deny` or `forbid` (case-insensitive,
+//! trimmed) into a [`LintLevel`], like the lint levels of `rustc` and Clippy.
+//! The levels are ordered from least to most strict in that order, so
+//! `LintLevel::Warn < LintLevel::Deny`, and [`LintLevel::at_least`] compares
+//! them in const.
+//!
+//! ```
+//! use envparse::LintLevel;
+//! const STRICTNESS: LintLevel = envparse::parse_env!("MYCRATE_STRICTNESS" as lint_level else LintLevel::Warn);
+//! const CHECKS_ARE_ERRORS: bool = STRICTNESS.at_least(LintLevel::Deny);
+//! # assert!(!CHECKS_ARE_ERRORS);
+//! ```
+//!
+//! ## Log levels
+//!
+//! `rust_log` reads the global level from a `RUST_LOG`-style value, for a
+//! compile-time default log level that's set with the familiar syntax. The
+//! level is a `u8` from 0 (`off`) to 5 (`trace`), the same as the values of
+//! `log::LevelFilter`, so `warn,my_crate::net=trace` gives `2`. Adding a module
+//! path, as in `rust_log "my_crate::net"`, gives the level for that module
+//! instead.
+//!
+//! Only a simple subset of `env_logger`'s syntax is supported. The value is a
+//! comma-separated list of directives, each of which is a level name (which
+//! sets the global level), `path=level`, or a bare module path (which gets
+//! `trace`). Anything after a `/` is ignored. See [`parse::parse_rust_log`]
+//! for the details.
+//!
+//! ```
+//! // With `RUST_LOG=info,my_crate::net=trace`, these would be `3` and `5`.
+//! const LOG_LEVEL: u8 = envparse::parse_env!("RUST_LOG" as rust_log else 2);
+//! const NET_LOG_LEVEL: u8 = envparse::parse_env!("RUST_LOG" as rust_log "my_crate::net" else 2);
+//! ```
+//!
+//! ## Key/value pairs
+//!
+//! `(str ':' T)` parses a string key and a value of type `T` separated by the
+//! given character (the first occurrence of it, specifically), producing a
+//! `(&'static str, T)`. Whitespace around the key is trimmed. The length of the
+//! key (in bytes) can be restricted by writing `(str (in 1..=16) ':' T)`.
+//!
+//! ```
+//! // With `MYCRATE_OVERRIDE=timeout:500`, this would be `("timeout", 500)`.
+//! const OVERRIDE: (&str, u32) = envparse::parse_env!("MYCRATE_OVERRIDE" as (str (in 1..) ':' u32) else ("", 0));
+//! ```
+//!
+//! For a table of them, like the weights for a weighted choice,
+//! `pairs(str ':' T; N)` parses a comma-separated list of exactly `N` pairs
+//! into parallel arrays of the keys and values, producing a `([&'static str;
+//! N], [T; N])`. Each key must be non-empty, and there can't be any empty
+//! items. The errors say which pair is malformed, or how many pairs were
+//! expected and found. With `else`, the default is used when the value is unset
+//! or blank.
+//!
+//! ```
+//! // With `MYCRATE_WEIGHTS=a:3,b:1,c:5`, this would be `(["a", "b", "c"], [3, 1, 5])`.
+//! const WEIGHTS: ([&str; 3], [u32; 3]) =
+//!     envparse::parse_env!("MYCRATE_WEIGHTS" as pairs(str ':' u32; 3) else (["a", "b", "c"], [1, 1, 1]));
+//! # assert_eq!(WEIGHTS, (["a", "b", "c"], [1, 1, 1]));
+//! ```
+//!
+//! ## Strings
+//!
+//! `str` passes the value through unchanged, as a `&'static str`, much like
+//! [`env!`](macro@env) does. It never fails.
+//!
+//! Values captured with something like `$(cat file)` often end up with a
+//! trailing newline, which you probably don't want. `trimmed_str` is the same,
+//! but trims whitespace from both ends (and treats a whitespace-only value like
+//! an empty one). Every other type trims whitespace already, so `"42\n"` is fine
+//! for a `u32`, and `"yes\n"` for a `bool`.
+//!
+//! ```
+//! // With `MYCRATE_NAME=$(cat name.txt)`, this wouldn't have a trailing newline.
+//! const NAME: &str = envparse::parse_env!("MYCRATE_NAME" as trimmed_str else "default");
+//! ```
+//!
+//! Either can be followed by `starts_with "..."` or `ends_with "..."` (or
+//! `starts_with_ignore_case` and `ends_with_ignore_case`, which ignore ASCII
+//! case) to fail the build if the value doesn't have that prefix or suffix. An
+//! `else` default has to satisfy it too.
+//!
+//! ```
+//! const URL: &str =
+//!     envparse::parse_env!("MYCRATE_URL" as trimmed_str starts_with "https://" else "https://example.com");
+//! ```
+//!
+//! For a string without a heap, `strbuf[N]` copies the UTF-8 bytes of the
+//! value into a `[u8; N]`, and gives a `([u8; N], usize)` of the buffer and the
+//! number of bytes used. The rest of the buffer is zeros. A value longer than
+//! `N` bytes fails to build. If the value starts with `"` (after whitespace),
+//! it's a quoted string, which can use the escapes `\n`, `\r`, `\t`, `\0`,
+//! `\\`, `\"`, `\'` and `\xNN` (up to `\x7F`), so control characters can be
+//! included, and only whitespace can follow its closing `"`. Otherwise, it's
+//! used as-is. With `else`, the default is a `&str`, used when the value is
+//! unset or blank.
+//!
+//! ```
+//! // With `MYCRATE_BANNER='"hello\tworld\n"'`, this would hold `hello<tab>world<newline>`.
+//! const BANNER: ([u8; 32], usize) = envparse::parse_env!("MYCRATE_BANNER" as strbuf[32] else "hello");
+//! # assert_eq!(&BANNER.0[..BANNER.1], b"hello");
+//! ```
+//!
+//! To restrict the value to a set of characters, like for a name that ends up
+//! in an identifier or a path, use `chars "..."` with a character class,
+//! written like the inside of a regex `[...]`: single characters and ranges
+//! like `a-z`. A `-` at the start or end stands for itself. There's no
+//! escaping or negation, and the class is matched byte by byte, so it should
+//! be ASCII. The error says which character isn't allowed, and where. An
+//! `else` default has to satisfy it too.
+//!
+//! ```
+//! const PREFIX: &str = envparse::parse_env!("MYCRATE_PREFIX" as trimmed_str chars "a-zA-Z0-9_" else "my_crate");
+//! # assert_eq!(PREFIX, "my_crate");
+//! ```
+//!
+//! For values that end up in logs or text protocols, `printable` is a shorthand
+//! for `chars " -~"`, which only allows printable ASCII (`0x20` to `0x7E`), so
+//! control characters and non-ASCII text fail to build. As with `chars`, the
+//! error gives the offending byte and its position. For anything stricter, use
+//! `chars` with a narrower class.
+//!
+//! ```
+//! const USER_AGENT: &str = envparse::parse_env!("MYCRATE_USER_AGENT" as trimmed_str printable else "mycrate/1.0");
+//! # assert_eq!(USER_AGENT, "mycrate/1.0");
+//! ```
+//!
+//! ## IPv4 addresses
+//!
+//! `Ipv4Addr` parses a dotted-decimal address like `192.168.0.1` into a
+//! [`core::net::Ipv4Addr`]. It's most useful in an array, for things like
+//! allowlists.
+//!
+//! ```
+//! use core::net::Ipv4Addr;
+//! // With `MYCRATE_DNS=8.8.8.8,1.1.1.1`, this would be those two addresses.
+//! const DNS: [Ipv4Addr; 2] = envparse::parse_env!("MYCRATE_DNS" as [Ipv4Addr; 2] else [Ipv4Addr::LOCALHOST; 2]);
+//! ```
+//!
+//! ## Versions
+//!
+//! `version` parses a version like `1.2.3` into a `(u64, u64, u64)` of the
+//! major, minor and patch numbers. A leading `v` (or `V`) is allowed, so a git
+//! tag name like `v1.2.3` can be passed straight through. SemVer's pre-release
+//! and build metadata suffixes (like `-rc.1`) aren't supported.
+//!
+//! ```
+//! const MIN_PROTOCOL: (u64, u64, u64) = envparse::parse_env!("MYCRATE_MIN_PROTOCOL" as version else (1, 0, 0));
+//! ```
+//!
+//! To turn something on based on a version, like that of a toolchain passed in
+//! through the environment, `satisfies` (instead of `as`) checks a version
+//! against a requirement and gives a `bool`. The requirement is a comparison
+//! (`=`, `>`, `>=`, `<` or `<=`) with a version of one to three parts, and as
+//! in Cargo, only the parts given are compared, so `<=1.70` includes `1.70.5`.
+//! See [`parse::version_matches`]. With `else`, the requirement has to be
+//! wrapped in parentheses, and the default is used if the variable is unset or
+//! blank.
+//!
+//! ```
+//! // With `MYCRATE_TOOL_VERSION=1.75.0`, these would be `true` and `false`.
+//! const NEW_TOOL: bool = envparse::parse_env!("MYCRATE_TOOL_VERSION" satisfies (>=1.70) else false);
+//! const OLD_TOOL: bool = envparse::parse_env!("MYCRATE_TOOL_VERSION" satisfies (<1.60) else true);
+//! # assert_eq!((NEW_TOOL, OLD_TOOL), (false, true));
+//! ```
+//!
+//! ## Arrays
+//!
+//! Arrays of any of the above (for example `[bool; 4]` or `[u16; 3]`) are
+//! supported, parsed from a comma-separated list of items. Whitespace around
+//! each item is ignored, so ` 2 , 3 , 5 ` is the same as `2,3,5`. A trailing
+//! comma (as in `2,3,`) is always an error, rather than a shorter array.
+//! Without `else`, exactly as many items as the array holds are required, and
+//! none of them may be empty. With `else`, empty items in between others (as in
+//! `2,,5`) keep the default's value for that position. If an item fails to
+//! parse, the error says which one, and if the count is wrong, it says how
+//! many were expected and found. Each item is parsed on its own, so integers
+//! can each have a different prefix, as in `0xff,0b1010,16`.
+//!
+//! Arrays of `Option`s (like `[Option<bool>; 3]`) are also supported, for when
+//! some positions may be left unspecified. Empty items are `None`, as are any
+//! missing trailing items, so for a `[Option<bool>; 3]`, `1,,0` parses as
+//! `[Some(true), None, Some(false)]`, and `1` parses as `[Some(true), None,
+//! None]`. As with other arrays, a trailing comma is an error, so `1,,0,` is
+//! rejected rather than read as ending in `None`.
+//!
+//! For a list of up to `N` items, use `list(T; max N)`, which gives a
+//! `([T; N], usize)` of the items and how many there were. The unused slots
+//! are zero (or `false`, and so on), or the value given with `fill`. A blank
+//! value is an empty list, and with `else`, the default is a slice of at most
+//! `N` items, used when the variable is unset or blank. Empty items, including
+//! after a trailing comma, are errors.
+//!
+//! ```
+//! // With `BACKOFF_MS=100,200,400`, this would be `([100, 200, 400, 0, 0, 0, 0, 0], 3)`.
+//! const BACKOFF_MS: ([u32; 8], usize) =
+//!     envparse::parse_env!("MYCRATE_BACKOFF_MS" as list(u32; max 8) else &[50, 500]);
+//! const RETRIES: usize = BACKOFF_MS.1;
+//! # assert_eq!(BACKOFF_MS, ([50, 500, 0, 0, 0, 0, 0, 0], 2));
+//! const LEVELS: ([u8; 4], usize) = envparse::parse_env!("MYCRATE_LEVELS" as list(u8; max 4, fill 255) else &[]);
+//! # assert_eq!(LEVELS, ([255; 4], 0));
+//! ```
+//!
+//! For a set of integers where order and repeats don't matter, like ports to
+//! listen on, `list(T; max N) unique` gives the distinct items sorted in
+//! ascending order, and how many there were. `N` is the capacity for distinct
+//! items, so duplicates don't use it up: `8,1,8,3` fits in a `max 3`, and gives
+//! `([1, 3, 8], 3)`. More than `N` distinct items is an error. The unused slots
+//! are zero, and a default given with `else` is sorted and deduplicated in the
+//! same way. This is only supported for the primitive integer types.
+//!
+//! ```
+//! // With `MYCRATE_PORTS=8080, 80, 443, 80`, this would be `([80, 443, 8080, 0], 3)`.
+//! const PORTS: ([u16; 4], usize) = envparse::parse_env!("MYCRATE_PORTS" as list(u16; max 4) unique else &[443, 80]);
+//! # assert_eq!(PORTS, ([80, 443, 0, 0], 2));
+//! ```
+//!
+//! Both arrays and lists can be followed by `loose`, to also accept items
+//! separated by whitespace, as in `1 2 3` or `1, 2, 3`. In this mode, any run
+//! of commas and whitespace is a single separator, which means there's no way
+//! to write an empty item: `1,,3` is the same as `1,3`.
+//!
+//! ```
+//! // With `MYCRATE_PORTS="80 443"`, this would be `[80, 443]`.
+//! const PORTS: [u16; 2] = envparse::parse_env!("MYCRATE_PORTS" as [u16; 2] loose else [8080, 8443]);
+//! ```
+//!
+//! To accept values written like literals in other languages, arrays
+//! (including arrays of `Option`s) and lists (including `unique` ones) can also
+//! be followed by `bracketed`, after `loose` and `unique` if those are used.
+//! This strips one pair of brackets from around the value before it's split
+//! into items, if there is one. The recognized pairs are `[...]`, `(...)` and
+//! `{...}`, and the two ends have to match, so `[1, 2)` and `1, 2]` are errors.
+//! Values without brackets are still accepted, and `[]` is the same as a blank
+//! value.
+//!
+//! ```
+//! // With `MYCRATE_ORIGIN="(10, -4)"` or `MYCRATE_ORIGIN=10,-4`, this would be `[10, -4]`.
+//! const ORIGIN: [i32; 2] = envparse::parse_env!("MYCRATE_ORIGIN" as [i32; 2] bracketed else [0, 0]);
+//! # assert_eq!(ORIGIN, [0, 0]);
+//! // With `MYCRATE_LANES="{1 3}"`, this would be `([1, 3, 0, 0], 2)`.
+//! const LANES: ([u8; 4], usize) = envparse::parse_env!("MYCRATE_LANES" as list(u8; max 4) loose bracketed else &[0]);
+//! # assert_eq!(LANES, ([0, 0, 0, 0], 1));
+//! ```
+//!
+//! Arrays of arrays, like `[[bool; 4]; 2]`, are parsed as a grid, with rows
+//! separated by `;` and the items in each row by `,`, so `1,0,0,1;0,1,1,0`
+//! gives `[[true, false, false, true], [false, true, true, false]]`. Without
+//! `else`, every row needs exactly as many items as the inner array holds, and
+//! there must be exactly as many rows as the outer one. With `else`, missing
+//! or empty rows and items keep the default's value for that position.
+//!
+//! ```
+//! // With `PIN_MUX=1,0;0,1`, this would be `[[true, false], [false, true]]`.
+//! const PIN_MUX: [[bool; 2]; 2] = envparse::parse_env!("MYCRATE_PIN_MUX" as [[bool; 2]; 2] else [[false; 2]; 2]);
+//! # assert_eq!(PIN_MUX, [[false; 2]; 2]);
+//! ```
+//!
+//! For filling a byte buffer with a single value, `[u8; N] fill` takes
+//! `value*count` instead, like `0xAA*16` for sixteen `0xAA` bytes. The count
+//! must be exactly `N`, so that changing the buffer size doesn't silently
+//! change what the setting means. A value without `*count` counts as one.
+//!
+//! ```
+//! const POISON: [u8; 16] = envparse::parse_env!("MYCRATE_POISON" as [u8; 16] fill else [0xAA; 16]);
+//! # assert_eq!(POISON, [0xAA; 16]);
+//! ```
 //!
 //! # Syntax
 //!
@@ -88,32 +779,490 @@
 //! digit_hex: [0-9a-fA-F]
 //! ```
 //!
-//! ## Booleans
+//! ## Sizes with case-sensitive suffixes
 //!
-//! This is entirely case-insensitive, and any whitespace is trimmed from either
-//! end.
+//! Whitespace is allowed between the number and the suffix. The suffix is
+//! case-sensitive (see the table above), although the number isn't.
 //!
-//! We're fairly forgiving here (perhaps more-so than we should be), in order to
-//! be compatible with some other ways of configuration (rustc's command line
-//! arguments, for example).
+//! ```txt
+//! cased_size: unsigned_integer suffix?
+//! suffix: ('k' | 'm' | 'g' | 't' | 'p' | 'K' | 'M' | 'G' | 'T' | 'P')
+//! ```
+//!
+//! ## Rates
+//!
+//! The size is case-sensitive, like `cased_size`, but the time unit isn't.
+//! Whitespace is allowed around the `/`.
 //!
 //! ```txt
-//! boolean: (true_str | false_str)
-//! false_str: ( '0' | 'false' | 'f' | 'off' | 'no'  | 'n' )
-//! true_str:  ( '1' | 'true'  | 't' | 'on'  | 'yes' | 'y' )
+//! rate: cased_size 'B'? '/' ('ms' | 's' | 'min' | 'h')
 //! ```
-#![no_std]
-
-/// Not part of the public API. Please do not use.
-mod privat;
-
-#[doc(hidden)]
-pub mod __priv {
-    // Export stuff we need from the macro.
-    pub use core;
+//!
+//! ## Degrees
+//!
+//! Whitespace is allowed between the number and the suffix.
+//!
+//! ```txt
+//! degrees: integer ('deg' | '°')?
+//! ```
+//!
+//! ## Basis points
+//!
+//! Only base 10 is supported, and trailing zeros past the 4th decimal place are
+//! ignored.
+//!
+//! ```txt
+//! basis_points: ('+' | '-')? (digit_dec+ ('.' digit_dec*)? | '.' digit_dec+)
+//! ```
+//!
+//! ## Fixed-point numbers
+//!
+//! Only base 10 is supported, and trailing zeros past the 38th decimal place
+//! are ignored.
+//!
+//! ```txt
+//! q: ('+' | '-')? (digit_dec+ ('.' digit_dec*)? | '.' digit_dec+)
+//! ```
+//!
+//! ## Durations
+//!
+//! Units are case-sensitive, and no whitespace is allowed between the parts.
+//! Minutes are `m`, unlike the `min` of a `rate`.
+//!
+//! ```txt
+//! duration: '+'? ('0' | (number unit)+)
+//! number: digit_dec+ ('.' digit_dec*)? | '.' digit_dec+
+//! unit: 'ns' | 'us' | 'µs' | 'μs' | 'ms' | 's' | 'm' | 'h'
+//! duration_range: duration? ('..' | '..=') duration?
+//! ```
+//!
+//! ## File modes
+//!
+//! ```txt
+//! mode: ('0o' | '0O')? (digit_oct | '_')* digit_oct (digit_oct | '_')*
+//! ```
+//!
+//! ## Ratios
+//!
+//! Both halves follow the integer syntax above, although only the numerator may
+//! have a sign. Whitespace around the `/` is ignored.
+//!
+//! ```txt
+//! ratio: integer ('/' unsigned_integer)?
+//! ```
+//!
+//! ## IPv4 addresses
+//!
+//! Each part is a decimal number from 0 to 255. Leading zeros aren't allowed,
+//! since some tools would read them as octal.
+//!
+//! ```txt
+//! ipv4: octet '.' octet '.' octet '.' octet
+//! octet: ('0' | [1-9] digit_dec? digit_dec?)
+//! ```
+//!
+//! ## Versions
+//!
+//! As in SemVer, leading zeros aren't allowed.
+//!
+//! ```txt
+//! version: ('v' | 'V')? part '.' part '.' part
+//! part: '0' | [1-9] digit_dec*
+//! ```
+//!
+//! Whitespace is allowed between the operator and the version in a
+//! requirement for `satisfies`.
+//!
+//! ```txt
+//! requirement: ('=' | '>' | '>=' | '<' | '<=') part ('.' part ('.' part)?)?
+//! ```
+//!
+//! ## Booleans
+//!
+//! This is entirely case-insensitive, and any whitespace is trimmed from either
+//! end.
+//!
+//! We're fairly forgiving here (perhaps more-so than we should be), in order to
+//! be compatible with some other ways of configuration (rustc's command line
+//! arguments, for example).
+//!
+//! ```txt
+//! boolean: (true_str | false_str)
+//! false_str: ( '0' | 'false' | 'f' | 'off' | 'no'  | 'n' | 'disable' | 'disabled' )
+//! true_str:  ( '1' | 'true'  | 't' | 'on'  | 'yes' | 'y' | 'enable'  | 'enabled'  )
+//! ```
+#![no_std]
+
+/// Not part of the public API. Please do not use.
+mod privat;
+
+#[doc(hidden)]
+pub mod __priv {
+    // Export stuff we need from the macro.
+    pub use core;
     pub use core::option::Option::{self, None, Some};
+    pub use core::result::Result::{self, Err, Ok};
+
+    pub use crate::privat::{
+        affix, aligned, array_message, array_parsers, auto_or, chars_message, diagnose, env_range, eq, fail_message,
+        fill, flag, grid_message, grid_parsers, hex_digits, hex_required, in_range, limit, list_parsers,
+        option_array_parsers, pair_message, pairs_message, pairs_parsers, parse_bounded, parsers, qfixed, qfixed_fits,
+        qfixed_valid, range_message, range_problem, relative, self_bounded, split_pair, strbuf, triple_message, types,
+        unique_list_parsers, valid_char_class, word_index, AlignError, Message, PairError, RangeWrap,
+    };
+}
+
+/// Returns `percent`% of `T::MAX` (rounded down), for use in the bounds of a
+/// range.
+///
+/// This is for constraints like "no more than half the addressable range",
+/// which can't be written as a literal in the range passed to `in`.
+///
+/// ```
+/// use envparse::{frac_of_max, parse_env};
+/// const LIMIT: u32 = parse_env!("MYCRATE_LIMIT" as u32 (in ..=frac_of_max::<u32>(50)) else 100);
+/// assert_eq!(frac_of_max::<u8>(50), 127);
+/// assert_eq!(frac_of_max::<i16>(100), i16::MAX);
+/// ```
+///
+/// # Panics
+///
+/// Panics (at compile time, if used in a const context) if `percent` is over
+/// 100.
+pub const fn frac_of_max<T: FracOfMax>(percent: u32) -> T {
+    assert!(percent <= 100, "`frac_of_max` takes a percentage between 0 and 100");
+    T::TABLE[percent as usize]
+}
+
+/// Integer types supported by [`frac_of_max`]. This trait is sealed.
+pub trait FracOfMax: Copy + privat::Sealed {
+    /// `TABLE[i]` is `i`% of `Self::MAX`. Since we can't do arithmetic on a
+    /// generic type in const, [`frac_of_max`] looks the answer up here instead.
+    #[doc(hidden)]
+    const TABLE: [Self; 101];
+}
+
+macro_rules! impl_frac_of_max {
+    ($($t:ident),*) => {$(
+        impl privat::Sealed for $t {}
+        impl FracOfMax for $t {
+            const TABLE: [$t; 101] = {
+                let mut table = [0; 101];
+                let mut i = 0;
+                while i <= 100 {
+                    // `MAX * i / 100`, without overflowing.
+                    let max = $t::MAX as u128;
+                    table[i] = (max / 100 * (i as u128) + max % 100 * (i as u128) / 100) as $t;
+                    i += 1;
+                }
+                table
+            };
+        }
+    )*};
+}
+
+impl_frac_of_max!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A setting which can be forced on or off, or left to be detected
+/// automatically, parsed from exactly `on`, `off` or `auto` (the convention used
+/// by things like git's `color.ui`).
+///
+/// This is deliberately stricter than the `bool` parser, and rejects things like
+/// `yes` or `true`. Values like those suggest the user expected a boolean, and
+/// didn't realize `auto` was an option (and the strictness matches the tools
+/// this convention comes from).
+///
+/// ```
+/// use envparse::OnOffAuto;
+/// const COLOR: OnOffAuto = envparse::parse_env!("MYCRATE_COLOR" as on_off_auto else OnOffAuto::Auto);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnOffAuto {
+    /// `on`
+    On,
+    /// `off`
+    Off,
+    /// `auto`
+    Auto,
+}
+
+/// A resource limit parsed by `limit(T)`: `auto`, `max`, or a number.
+///
+/// ```
+/// use envparse::Limit;
+/// const OPEN_FILES: Limit<u64> = envparse::parse_env!("MYCRATE_OPEN_FILES" as limit(u64) else Limit::Auto);
+/// const OPEN_FILES_N: u64 = OPEN_FILES.resolve(1024);
+/// # assert_eq!(OPEN_FILES_N, 1024);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit<T> {
+    /// `auto`, left for the program to decide.
+    Auto,
+    /// `max`, the largest value of the type.
+    Max,
+    /// An explicit number.
+    Value(T),
+}
+
+macro_rules! impl_limit_resolve {
+    ($($t:ident),* $(,)?) => {$(
+        impl Limit<$t> {
+            /// Gives the number this limit stands for: `auto` for `Auto`,
+            #[doc = concat!("`", stringify!($t), "::MAX` for `Max`, and the number itself otherwise.")]
+            pub const fn resolve(self, auto: $t) -> $t {
+                match self {
+                    Limit::Auto => auto,
+                    Limit::Max => $t::MAX,
+                    Limit::Value(v) => v,
+                }
+            }
+        }
+    )*};
+}
+
+impl_limit_resolve! {
+    usize, u8, u16, u32, u64, u128,
+    isize, i8, i16, i32, i64, i128,
+}
+
+/// A sort direction, parsed from `asc`, `ascending` or `<`, or `desc`,
+/// `descending` or `>` (case-insensitive, trimmed).
+///
+/// ```
+/// use envparse::SortDir;
+/// const ORDER: SortDir = envparse::parse_env!("MYCRATE_ORDER" as sort_dir else SortDir::Ascending);
+/// let mut v = [3, 1, 2];
+/// v.sort_by(|a, b| ORDER.apply(a.cmp(b)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDir {
+    /// `asc`, `ascending` or `<`
+    Ascending,
+    /// `desc`, `descending` or `>`
+    Descending,
+}
+
+impl SortDir {
+    /// Adjusts `ord`, the result of comparing two items in ascending order, to
+    /// this direction. That is, this reverses it for `Descending`.
+    pub const fn apply(self, ord: core::cmp::Ordering) -> core::cmp::Ordering {
+        match self {
+            SortDir::Ascending => ord,
+            SortDir::Descending => ord.reverse(),
+        }
+    }
+}
+
+/// Whether to go ahead with something without asking, parsed from `yes` or
+/// `always`, `no` or `never`, or `ask` or `prompt` (case-insensitive, trimmed).
+///
+/// ```
+/// use envparse::PromptPolicy;
+/// const CONFIRM: PromptPolicy = envparse::parse_env!("MYCRATE_CONFIRM" as prompt_policy else PromptPolicy::Ask);
+/// # assert_eq!(CONFIRM, PromptPolicy::Ask);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PromptPolicy {
+    /// `yes` or `always`
+    Always,
+    /// `no` or `never`
+    Never,
+    /// `ask` or `prompt`
+    Ask,
+}
+
+/// A lint level, parsed from `allow`, `warn`, `deny` or `forbid`
+/// (case-insensitive, trimmed).
+///
+/// These are ordered from least to most strict, as listed here.
+///
+/// ```
+/// use envparse::LintLevel;
+/// assert!(LintLevel::Allow < LintLevel::Warn);
+/// assert!(LintLevel::Deny.at_least(LintLevel::Warn));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintLevel {
+    /// `allow`
+    Allow,
+    /// `warn`
+    Warn,
+    /// `deny`
+    Deny,
+    /// `forbid`
+    Forbid,
+}
+
+impl LintLevel {
+    /// Returns true if this level is at least as strict as `level`. This is
+    /// `self >= level`, but usable in const.
+    pub const fn at_least(self, level: LintLevel) -> bool {
+        self as u8 >= level as u8
+    }
+}
+
+/// Whether to use color in output, as decided by [`color_choice!`] from the
+/// `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` conventions.
+///
+/// `Auto` means none of the variables settled it, so the usual check (is the
+/// output a terminal?) should decide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Never use color.
+    Never,
+    /// Use color if the output supports it.
+    Auto,
+    /// Always use color, even if the output isn't a terminal.
+    Always,
+}
+
+impl ColorChoice {
+    /// Decides the color choice from the values of `NO_COLOR`, `CLICOLOR` and
+    /// `CLICOLOR_FORCE` (or variables playing those roles), with `None` for
+    /// any that are unset. The first of these that applies wins:
+    ///
+    /// 1. `NO_COLOR` set to any non-empty value (even whitespace) gives `Never`,
+    ///    as described at <https://no-color.org>.
+    /// 2. `CLICOLOR_FORCE` set to anything other than `0` gives `Always`.
+    /// 3. `CLICOLOR` set to `0` gives `Never`.
+    /// 4. Otherwise, `Auto`. This includes `CLICOLOR` set to anything other
+    ///    than `0`, which only says color is supported *if* the output is a
+    ///    terminal.
+    ///
+    /// `NO_COLOR` beats `CLICOLOR_FORCE`, since it's usually set by the user,
+    /// and `CLICOLOR_FORCE` is more often set by a tool (like a build system)
+    /// capturing the output. For the other two, as everywhere else in this
+    /// crate, blank values are treated as unset, and whitespace around `0` is
+    /// ignored.
+    ///
+    /// ```
+    /// use envparse::ColorChoice;
+    /// assert_eq!(ColorChoice::from_vars(Some("1"), None, Some("1")), ColorChoice::Never);
+    /// assert_eq!(ColorChoice::from_vars(None, Some("0"), Some("1")), ColorChoice::Always);
+    /// assert_eq!(ColorChoice::from_vars(None, Some("0"), Some("0")), ColorChoice::Never);
+    /// assert_eq!(ColorChoice::from_vars(None, Some("1"), None), ColorChoice::Auto);
+    /// assert_eq!(ColorChoice::from_vars(Some(""), None, None), ColorChoice::Auto);
+    /// assert_eq!(ColorChoice::from_vars(Some(" "), None, None), ColorChoice::Never);
+    /// ```
+    pub const fn from_vars(no_color: Option<&str>, clicolor: Option<&str>, clicolor_force: Option<&str>) -> Self {
+        const fn set(v: Option<&str>) -> Option<bool> {
+            let s = match v {
+                Some(s) => s.as_bytes(),
+                None => return None,
+            };
+            match parse::trim_ws(s) {
+                // Whether it's set to something other than `0`.
+                Some((start, end)) => Some(end - start != 1 || s[start] != b'0'),
+                None => None,
+            }
+        }
+        // Unlike the others, only an empty `NO_COLOR` counts as unset.
+        let no_color = match no_color {
+            Some(s) => !s.is_empty(),
+            None => false,
+        };
+        if no_color {
+            ColorChoice::Never
+        } else if let Some(true) = set(clicolor_force) {
+            ColorChoice::Always
+        } else if let Some(false) = set(clicolor) {
+            ColorChoice::Never
+        } else {
+            ColorChoice::Auto
+        }
+    }
+}
+
+/// A value from `parse_env!(status ...)`, along with whether its fallback was
+/// used (because the variable was unset, blank or invalid).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Status<T> {
+    /// The parsed value, or the fallback.
+    pub value: T,
+    /// The name of the environment variable.
+    pub var_name: &'static str,
+    /// True if `value` is the fallback.
+    pub fell_back: bool,
+}
+
+impl<T> Status<T> {
+    /// Returns the variable's name and whether it fell back, for building a
+    /// list of these (which doesn't depend on `T`) in a constant.
+    pub const fn health(&self) -> (&'static str, bool) {
+        (self.var_name, self.fell_back)
+    }
+}
+
+/// A value from `parse_env!(checked ...)`: either the parsed value, or the
+/// message the build would have failed with.
+///
+/// ```
+/// use envparse::Checked;
+/// const LIMIT: Checked<u32> = envparse::parse_env!(checked "MYCRATE_LIMIT" as u32 else 64);
+/// const LIMIT_N: u32 = LIMIT.unwrap_or(64);
+/// # assert_eq!(LIMIT_N, 64);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Checked<T> {
+    /// The value parsed, or the default if the variable was unset.
+    Value(T),
+    /// A message describing what's wrong with the variable, like "the value in
+    /// `MYCRATE_LIMIT` doesn't parse as a `u32`: invalid digit `x` for base 10."
+    Invalid(&'static str),
+}
+
+impl<T: Copy> Checked<T> {
+    /// Returns true if this is a `Value`.
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Checked::Value(_))
+    }
+
+    /// Returns the value, or `None` if it was invalid.
+    pub const fn value(self) -> Option<T> {
+        match self {
+            Checked::Value(v) => Some(v),
+            Checked::Invalid(_) => None,
+        }
+    }
+
+    /// Returns the message, or `None` if the value was valid.
+    pub const fn message(self) -> Option<&'static str> {
+        match self {
+            Checked::Value(_) => None,
+            Checked::Invalid(msg) => Some(msg),
+        }
+    }
+
+    /// Returns the value, or `fallback` if it was invalid.
+    pub const fn unwrap_or(self, fallback: T) -> T {
+        match self {
+            Checked::Value(v) => v,
+            Checked::Invalid(_) => fallback,
+        }
+    }
+}
 
-    pub use crate::privat::{parse_bounded, parsers, RangeWrap};
+/// Returns true if `name` is plausible as the name of an environment variable:
+/// non-empty, and without any `=` or NUL characters.
+///
+/// The macros check this for the names they're given, to catch mistakes like
+/// passing a value where a name is expected.
+///
+/// ```
+/// assert!(envparse::valid_env_name("MYCRATE_MAX_LEN"));
+/// assert!(!envparse::valid_env_name(""));
+/// assert!(!envparse::valid_env_name("MAX_LEN=32"));
+/// ```
+pub const fn valid_env_name(name: &str) -> bool {
+    let name = name.as_bytes();
+    let mut i = 0;
+    while i < name.len() {
+        if name[i] == b'=' || name[i] == b'\0' {
+            return false;
+        }
+        i += 1;
+    }
+    !name.is_empty()
 }
 
 /// Parse an environment variable into some value. The main entry-point of this
@@ -147,7 +1296,98 @@ pub mod __priv {
 /// }
 /// ```
 ///
-/// If it's optional and you want an `Option` out of it, you can use `try`:
+/// The bounds can be any constant expression, including other constants
+/// produced by `parse_env!`, for configuration where one value constrains
+/// another.
+///
+/// ```
+/// const MIN_THREADS: u32 = envparse::parse_env!("MYCRATE_MIN_THREADS" as u32 (in 1..) else 1);
+/// const THREADS: u32 = envparse::parse_env!("MYCRATE_THREADS" as u32 (in MIN_THREADS..=64) else MIN_THREADS);
+/// ```
+///
+/// A narrow range also works for small integer states, like firmware modes
+/// numbered 0 to 2. A value outside of it fails to build with a message that
+/// names the range, like "the value in `MYCRATE_PIN_MODE` doesn't parse as a
+/// `u8`, or is outside of the range `0..=2`". (For states that have names, see
+/// `from` below.)
+///
+/// ```
+/// // 0 is input, 1 is output, 2 is open drain.
+/// const PIN_MODE: u8 = envparse::parse_env!("MYCRATE_PIN_MODE" as u8 (in 0..=2) else 0);
+/// ```
+///
+/// When both are given, the default has to be inside the range as well, so
+/// this fails to build, rather than quietly using a value the range forbids.
+///
+/// ```compile_fail
+/// const LEVEL: u32 = envparse::parse_env!("MYCRATE_LEVEL" as u32 (in 1..=10) else 42);
+/// ```
+///
+/// With `clamp`, a number outside of the range (or outside of the type) is
+/// clamped to the nearest end of it instead of failing to build. That includes
+/// negative numbers for unsigned types, which become the lowest allowed value,
+/// for settings where users expect `-1` to mean "as low as possible". A range
+/// needs parentheses here, as with `else`.
+///
+/// ```
+/// // With `RETRIES=-5`, this would be 0, and with `LEVEL=99`, it would be 10.
+/// const RETRIES: usize = envparse::parse_env!("MYCRATE_RETRIES" as usize clamp else 3);
+/// const LEVEL: u32 = envparse::parse_env!("MYCRATE_LEVEL" as u32 (in 1..=10) clamp else 5);
+/// # assert_eq!((RETRIES, LEVEL), (3, 5));
+/// ```
+///
+/// To let the user pick from some named values, while still accepting any
+/// number, use `from` with a table of names. The names are matched ignoring
+/// case and surrounding whitespace, and anything else has to parse as the
+/// type.
+///
+/// ```
+/// // With `LEVEL=high`, this would be 9, and with `LEVEL=3`, it would be 3.
+/// const LEVEL: u8 = envparse::parse_env!("MYCRATE_LEVEL" as u8 from { "low" => 1, "med" => 5, "high" => 9 } else 5);
+/// # assert_eq!(LEVEL, 5);
+/// ```
+///
+/// When a name should pick a slot in a table rather than a value, `index_in`
+/// gives the `usize` index of the name in a list, matched in the same way.
+/// Anything that isn't one of the names is an error. With `else`, the default
+/// index is used when the variable is unset or blank, and must be in bounds.
+///
+/// ```
+/// const REGION_URLS: [&str; 3] = ["https://us.example.com", "https://eu.example.com", "https://ap.example.com"];
+/// // With `MYCRATE_REGION=EU`, this would be 1.
+/// const REGION: usize = envparse::parse_env!("MYCRATE_REGION" index_in ["us", "eu", "ap"] else 0);
+/// const REGION_URL: &str = REGION_URLS[REGION];
+/// # assert_eq!(REGION_URL, "https://us.example.com");
+/// ```
+///
+/// To record what was configured (for logging, say), add `with_raw` after the
+/// type to get the original text of the variable alongside the parsed value.
+/// With `else`, the raw text is `""` when the variable isn't set (and is
+/// whatever the variable contained otherwise, even if that was blank and the
+/// default got used).
+///
+/// ```
+/// const LIMIT: (u32, &str) = envparse::parse_env!("MYCRATE_LIMIT" as u32 with_raw else 100);
+/// # assert_eq!(LIMIT, (100, ""));
+/// ```
+///
+/// The variable name is usually a string literal, but it can also be built
+/// with `concat!`, to namespace settings under a prefix. Anything `concat!`
+/// accepts works, including `env!` of a variable Cargo sets at build time,
+/// like `CARGO_PKG_NAME`. The name still has to be known at compile time,
+/// since the value is read with `env!`. This works for the plain forms (the
+/// type, optionally with `in` and `else`). The other forms need a literal.
+///
+/// ```
+/// const WORKERS: u32 = envparse::parse_env!(concat!("MYCRATE", "_WORKERS") as u32 (in 1..) else 4);
+/// const SHARDS: u32 = envparse::parse_env!(concat!(env!("CARGO_PKG_NAME"), "_SHARDS") as u32 else 16);
+/// # assert_eq!((WORKERS, SHARDS), (4, 16));
+/// ```
+///
+/// If it's optional and you want an `Option` out of it, you can use `try`.
+/// This gives `None` only if the variable is unset or empty, so `0` is
+/// `Some(0)`, for settings where zero means something different from "not
+/// configured".
 ///
 /// ```
 /// const MAX_LEN: usize = match envparse::parse_env!(try "OPTIONAL_MAX_LEN_LOG2" as u32 in 1..32) {
@@ -158,130 +1398,2884 @@ pub mod __priv {
 ///     len: [u8; MAX_LEN],
 /// }
 /// ```
+///
+/// With `else`, `try` gives the `Option` default instead when the variable is
+/// unset or empty, which can be `Some` or `None`. A value that's set still
+/// gives `Some` of it, and one that doesn't parse is still an error.
+///
+/// ```
+/// const SHARDS: Option<u32> = envparse::parse_env!(try "MYCRATE_SHARDS" as u32 else Some(4));
+/// # assert_eq!(SHARDS, Some(4));
+/// ```
+///
+/// For a feature that's on unless a variable like `NO_FOO` is set, use
+/// `absent`, which gives `true` if the variable is unset, and `false` if it's
+/// set to anything. That includes an empty value, since setting the variable
+/// at all is the signal. (This is stricter than the current wording of the
+/// `NO_COLOR` convention, which [`color_choice!`] follows, where an empty
+/// value is ignored.)
+///
+/// ```
+/// const FANCY_OUTPUT: bool = envparse::parse_env!(absent "MYCRATE_NO_FANCY_OUTPUT");
+/// # assert!(FANCY_OUTPUT);
+/// ```
+///
+/// To check whether a comma-separated list, like a set of enabled features,
+/// includes a given item, use `contains`. The items are compared ignoring case
+/// and the whitespace around them, and an unset variable is an empty list.
+///
+/// ```
+/// // With `MYCRATE_FEATURES="simd, tracing"`, this would be `true`.
+/// const TRACING: bool = envparse::parse_env!(contains "MYCRATE_FEATURES" "tracing");
+/// # assert!(!TRACING);
+/// ```
+///
+/// When an empty value should mean something of its own (like "use the
+/// system default"), rather than being treated the same as unset, use
+/// `empty_is`. A value that's set but empty (or only whitespace) gives the
+/// `empty_is` value, an unset one gives the `else` default, and anything else
+/// is parsed as usual. The `empty_is` value is a single token, so wrap it in
+/// parentheses if it's negative or more than a literal.
+///
+/// ```
+/// // Unset gives 8 threads, `MYCRATE_THREADS=` gives 0 (one per core).
+/// const THREADS: u32 = envparse::parse_env!("MYCRATE_THREADS" as u32 empty_is 0 else 8);
+/// # assert_eq!(THREADS, 8);
+/// ```
+///
+/// For config files that allow inline comments, `strip_comments` ignores
+/// everything from the first `#` onward before parsing, so `on # for staging`
+/// is `on`. Whitespace before the `#` is trimmed as usual, and a value that's
+/// only a comment counts as empty. This has to be asked for, since `#` could be
+/// part of a value elsewhere.
+///
+/// ```
+/// // With `MYCRATE_DEBUG="on # enabled for staging"`, this would be `true`.
+/// const DEBUG: bool = envparse::parse_env!("MYCRATE_DEBUG" as bool strip_comments else false);
+/// # assert!(!DEBUG);
+/// ```
+///
+/// To let whoever sets the variable mark a value as optional, add
+/// `try_marker` before `else`. Then a value ending in `?` (like `fast?`) falls
+/// back to the `else` default if it doesn't parse, rather than failing the
+/// build, and is parsed as usual (without the `?`) otherwise. A value without
+/// the `?` still has to parse. As always, an unset or blank value gives the
+/// default, so `try_marker` only changes what happens to invalid ones. It
+/// requires `else`, since there's nothing to fall back on otherwise.
+///
+/// ```
+/// // With `MYCRATE_LEVEL=9?`, this would be 3, but `MYCRATE_LEVEL=9` would
+/// // fail to build, and `MYCRATE_LEVEL=2?` would be 2.
+/// const LEVEL: u8 = envparse::parse_env!("MYCRATE_LEVEL" as u8 try_marker else 3);
+/// # assert_eq!(LEVEL, 3);
+/// ```
+///
+/// As a more advanced feature, for conventions that put a marker in front of
+/// the value (like `default:30`), `strip_prefix "..."` removes the given
+/// prefix if it's there (ignoring case and leading whitespace), and parses the
+/// rest. A value without the prefix is parsed as usual. The underlying
+/// [`parse::strip_prefix`] is public too, for building other conventions.
+///
+/// ```
+/// // With `MYCRATE_TIMEOUT=default:30` or `MYCRATE_TIMEOUT=30`, this would be 30.
+/// const TIMEOUT: u32 = envparse::parse_env!("MYCRATE_TIMEOUT" as u32 strip_prefix "default:" else 10);
+/// # assert_eq!(TIMEOUT, 10);
+/// ```
+///
+/// If a bad value shouldn't break the build, use `or_fallback` instead of
+/// `else`. The fallback is used when the variable is unset, blank, *or* fails
+/// to parse. Since a constant has no way to emit a warning, prefix it with
+/// `status` to get a [`Status`] recording whether the fallback was used, which
+/// can be collected into a list for reporting the health of the configuration.
+///
+/// ```
+/// const POOL: envparse::Status<usize> = envparse::parse_env!(status "MYCRATE_POOL" as usize or_fallback 8);
+/// const RETRIES: envparse::Status<u32> = envparse::parse_env!(status "MYCRATE_RETRIES" as u32 or_fallback 3);
+/// const FELL_BACK: [(&str, bool); 2] = [POOL.health(), RETRIES.health()];
+/// # assert_eq!(FELL_BACK, [("MYCRATE_POOL", true), ("MYCRATE_RETRIES", true)]);
+/// # assert_eq!(POOL.value, 8);
+/// ```
+///
+/// Arrays can be parsed from a comma-separated list. With `else`, the user
+/// can omit trailing items (or leave items empty) to keep the default for
+/// that position, but providing too many items is an error.
+///
+/// ```
+/// // With `STAGES=0,1`, this would be `[false, true, false, false]`.
+/// const STAGES: [bool; 4] = envparse::parse_env!("MYCRATE_STAGES" as [bool; 4] else [true, true, false, false]);
+/// ```
+///
+/// To report a bad value at runtime instead of failing the build, like for a
+/// plugin which checks its config when it's loaded, use `result`. This gives a
+/// `Result<T, &'static str>` (still computed at compile time), where the error
+/// is the message the build would have failed with. Without `else`, an unset
+/// variable is an error too.
+///
+/// ```
+/// const LIMIT: Result<u32, &str> = envparse::parse_env!(result "MYCRATE_LIMIT" as u32 else 64);
+/// let limit = LIMIT.unwrap_or_else(|msg| panic!("bad config: {msg}"));
+/// # assert_eq!(limit, 64);
+/// const PORT: Result<u16, &str> = envparse::parse_env!(result "MYCRATE_PORT" as u16);
+/// assert_eq!(PORT, Err("`MYCRATE_PORT` isn't set."));
+/// ```
+///
+/// `checked` is the same, but gives a [`Checked`], which has a `Value` and an
+/// `Invalid` case instead of `Ok` and `Err`. This never fails the build over
+/// the variable's value, so a library can compile with bad config and decide
+/// at runtime whether to panic, warn, or carry on with a fallback.
+///
+/// ```
+/// use envparse::Checked;
+/// const LEVEL: Checked<u8> = envparse::parse_env!(checked "MYCRATE_LEVEL" as u8);
+/// if let Some(msg) = LEVEL.message() {
+///     eprintln!("warning: {msg}, using level 1");
+/// }
+/// let level = LEVEL.unwrap_or(1);
+/// # assert_eq!(level, 1);
+/// ```
+///
+/// To parse a string literal with the same rules instead of reading an
+/// environment variable (handy for testing, or for strings produced by other
+/// macros), use `lit`. The `in` and `else` modifiers work here too, with `else`
+/// applying if the literal is empty.
+///
+/// ```
+/// const MASK: u32 = envparse::parse_env!(lit "0x1F" as u32);
+/// const SHIFT: u8 = envparse::parse_env!(lit "3" as u8 in 0..8);
+/// const COUNT: usize = envparse::parse_env!(lit "" as usize else 4);
+/// assert_eq!((MASK, SHIFT, COUNT), (31, 3, 4));
+/// ```
 #[macro_export]
 macro_rules! parse_env {
-    ($var_name:literal as $typ:ident) => {{
+    ($var_name:literal as $typ:ident) => {
+        $crate::parse_env!(@name ($var_name) as $typ)
+    };
+
+    ($var_name:literal as $typ:ident else $default:expr) => {
+        $crate::parse_env!(@name ($var_name) as $typ else $default)
+    };
+
+    ($var_name:literal as $typ:ident in $range:expr) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in $range) clamp (false))
+    };
+
+    ($var_name:literal as $typ:ident (in $range:expr) else $default:expr) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in $range) clamp (false) else $default)
+    };
+
+    (concat!($($part:tt)*) as $typ:ident) => {
+        $crate::parse_env!(@name ($crate::__priv::core::concat!($($part)*)) as $typ)
+    };
+
+    (concat!($($part:tt)*) as $typ:ident else $default:expr) => {
+        $crate::parse_env!(@name ($crate::__priv::core::concat!($($part)*)) as $typ else $default)
+    };
+
+    (concat!($($part:tt)*) as $typ:ident in $range:expr) => {
+        $crate::parse_env!(@name ($crate::__priv::core::concat!($($part)*)) as $typ (in $range) clamp (false))
+    };
+
+    (concat!($($part:tt)*) as $typ:ident (in $range:expr) else $default:expr) => {
+        $crate::parse_env!(
+            @name ($crate::__priv::core::concat!($($part)*)) as $typ (in $range) clamp (false) else $default
+        )
+    };
+
+    ($var_name:literal as $typ:ident clamp) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in ..) clamp (true))
+    };
+
+    ($var_name:literal as $typ:ident clamp else $default:expr) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in ..) clamp (true) else $default)
+    };
+
+    ($var_name:literal as $typ:ident (in $range:expr) clamp) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in $range) clamp (true))
+    };
+
+    ($var_name:literal as $typ:ident (in $range:expr) clamp else $default:expr) => {
+        $crate::parse_env!(@name ($var_name) as $typ (in $range) clamp (true) else $default)
+    };
+
+    ($var_name:literal in_env_range $range_var:literal as $typ:ident) => {
+        $crate::parse_env!(@env_range $var_name in_env_range $range_var as $typ clamp (false))
+    };
+
+    ($var_name:literal in_env_range $range_var:literal as $typ:ident else $default:expr) => {
+        $crate::parse_env!(@env_range $var_name in_env_range $range_var as $typ clamp (false) else $default)
+    };
+
+    ($var_name:literal in_env_range $range_var:literal as $typ:ident clamp) => {
+        $crate::parse_env!(@env_range $var_name in_env_range $range_var as $typ clamp (true))
+    };
+
+    ($var_name:literal in_env_range $range_var:literal as $typ:ident clamp else $default:expr) => {
+        $crate::parse_env!(@env_range $var_name in_env_range $range_var as $typ clamp (true) else $default)
+    };
+
+    (@env_range $var_name:literal in_env_range $range_var:literal as $typ:ident clamp ($clamp:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::parse_env!(@check_name $range_var);
+            let (min, max) = match $crate::__priv::env_range::$typ(match $crate::__priv::core::option_env!($range_var) {
+                $crate::__priv::Some(s) => s.as_bytes(),
+                $crate::__priv::None => b"",
+            }) {
+                $crate::__priv::Ok(bounds) => bounds,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $range_var,
+                        "` doesn't parse as a range of `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    e,
+                    ": expected a non-empty range like `10..100` or `10..=99`.",
+                )),
+            };
+            match $crate::__priv::parse_bounded::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+                $crate::__priv::Some(min),
+                $crate::__priv::Some(max),
+                $clamp,
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($crate::__priv::core::env!($var_name).as_bytes()),
+                    $crate::__priv::core::concat!(", or is outside of the range in `", $range_var, "`."),
+                )),
+            }
+        }
+    }};
+
+    (@env_range $var_name:literal in_env_range $range_var:literal as $typ:ident clamp ($clamp:expr)
+        else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::parse_env!(@check_name $range_var);
+            let (min, max) = match $crate::__priv::env_range::$typ(match $crate::__priv::core::option_env!($range_var) {
+                $crate::__priv::Some(s) => s.as_bytes(),
+                $crate::__priv::None => b"",
+            }) {
+                $crate::__priv::Ok(bounds) => bounds,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $range_var,
+                        "` doesn't parse as a range of `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    e,
+                    ": expected a non-empty range like `10..100` or `10..=99`.",
+                )),
+            };
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            // With `clamp`, the default is clamped like any other value.
+            let default = if !$crate::__priv::in_range::$typ(__ENVPARSE_DEFAULT, min, max) {
+                $crate::__priv::core::assert!(
+                    $clamp,
+                    $crate::__priv::core::concat!(
+                        "error: the default `",
+                        $crate::__priv::core::stringify!($default),
+                        "` is outside of the range in `",
+                        $range_var,
+                        "`.",
+                    ),
+                );
+                if __ENVPARSE_DEFAULT < min {
+                    min
+                } else {
+                    max
+                }
+            } else {
+                __ENVPARSE_DEFAULT
+            };
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => default,
+                $crate::__priv::Some(s) => match $crate::__priv::parse_bounded::$typ(
+                    s.as_bytes(),
+                    $crate::__priv::Some(default),
+                    $crate::__priv::Some(min),
+                    $crate::__priv::Some(max),
+                    $clamp,
+                ) {
+                    $crate::__priv::Some(v) => v,
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        $crate::__priv::diagnose::$typ(s.as_bytes()),
+                        $crate::__priv::core::concat!(", or is outside of the range in `", $range_var, "`."),
+                    )),
+                },
+            }
+        }
+    }};
+
+    // Fails the build if `$name` (a literal, or a `concat!` of them) isn't a
+    // valid variable name. The other macros use this too.
+    (@check_name $($name:tt)+) => {
+        $crate::__priv::core::assert!(
+            $crate::valid_env_name($($name)+),
+            $crate::__priv::core::concat!("error: `", $($name)+, "` is not a valid environment variable name."),
+        )
+    };
+
+    // Fails the build with a `Message`, like the ones from `fail_message`. The
+    // `panic!` has to be expanded in the caller's crate, or older compilers
+    // point the error at this one.
+    (@fail $msg:expr) => {
+        $crate::__priv::core::panic!("{}", $msg.as_str())
+    };
+
+    (@name ($($var_name:tt)*) as $typ:ident) => {{
+        const {
+            $crate::parse_env!(@check_name $($var_name)*);
+            match $crate::__priv::parsers::$typ(
+                $crate::__priv::core::env!($($var_name)*).as_bytes(),
+                $crate::__priv::None,
+            )
+            {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $($var_name)*,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($crate::__priv::core::env!($($var_name)*).as_bytes()),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    (@name ($($var_name:tt)*) as $typ:ident else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $($var_name)*);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($($var_name)*) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $($var_name)*,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    (@name ($($var_name:tt)*) as $typ:ident (in $range:expr) clamp ($clamp:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $($var_name)*);
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            match $crate::__priv::parse_bounded::$typ(
+                $crate::__priv::core::env!($($var_name)*).as_bytes(),
+                $crate::__priv::None,
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .start(),
+                ),
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .end_incl(),
+                ),
+                $clamp,
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $($var_name)*,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($crate::__priv::core::env!($($var_name)*).as_bytes()),
+                    $crate::__priv::core::concat!(
+                        ", or is outside of the range `",
+                        $crate::__priv::core::stringify!($range),
+                        "`.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    (@name ($($var_name:tt)*) as $typ:ident (in $range:expr) clamp ($clamp:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $($var_name)*);
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::in_range::$typ(
+                    __ENVPARSE_DEFAULT,
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .start(),
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .end_incl(),
+                ),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is outside of the range `",
+                    $crate::__priv::core::stringify!($range),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::core::option_env!($($var_name)*) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parse_bounded::$typ(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                        $crate::__priv::Some(
+                            $crate::__priv::RangeWrap(
+                                $range,
+                                $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                            )
+                            .start(),
+                        ),
+                        $crate::__priv::Some(
+                            $crate::__priv::RangeWrap(
+                                $range,
+                                $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                            )
+                            .end_incl(),
+                        ),
+                        $clamp,
+                    ) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $($var_name)*,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            $crate::__priv::core::concat!(
+                                ", or is outside of the range `",
+                                $crate::__priv::core::stringify!($range),
+                                "`.",
+                            ),
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex_required) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::hex_required::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex_required else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::hex_required::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            e,
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex_required aligned $align:tt) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_ALIGN: $crate::__priv::types::$typ = $align;
+            $crate::__priv::core::assert!(
+                __ENVPARSE_ALIGN.is_power_of_two(),
+                $crate::__priv::core::concat!(
+                    "error: the alignment for `",
+                    $var_name,
+                    "` must be a power of two, but is `",
+                    $crate::__priv::core::stringify!($align),
+                    "`.",
+                ),
+            );
+            let s = $crate::__priv::core::env!($var_name);
+            match $crate::__priv::aligned::$typ(s.as_bytes(), __ENVPARSE_ALIGN, $crate::__priv::None) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err($crate::__priv::AlignError::Misaligned) => {
+                    $crate::parse_env!(@fail $crate::__priv::Message::new()
+                        .push($crate::__priv::core::concat!("error: the value in `", $var_name, "` (`"))
+                        .push(s.trim_ascii())
+                        .push($crate::__priv::core::concat!(
+                            "`) isn't aligned to `",
+                            $crate::__priv::core::stringify!($align),
+                            "`.",
+                        )))
+                }
+                $crate::__priv::Err($crate::__priv::AlignError::Parse(e)) => {
+                    $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        e,
+                        ", or is out of range.",
+                    ))
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex_required aligned $align:tt else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_ALIGN: $crate::__priv::types::$typ = $align;
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            $crate::__priv::core::assert!(
+                __ENVPARSE_ALIGN.is_power_of_two(),
+                $crate::__priv::core::concat!(
+                    "error: the alignment for `",
+                    $var_name,
+                    "` must be a power of two, but is `",
+                    $crate::__priv::core::stringify!($align),
+                    "`.",
+                ),
+            );
+            $crate::__priv::core::assert!(
+                __ENVPARSE_DEFAULT % __ENVPARSE_ALIGN == 0,
+                $crate::__priv::core::concat!(
+                    "error: the default for `",
+                    $var_name,
+                    "` isn't aligned to `",
+                    $crate::__priv::core::stringify!($align),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::aligned::$typ(
+                        s.as_bytes(),
+                        __ENVPARSE_ALIGN,
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    ) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err($crate::__priv::AlignError::Misaligned) => {
+                            $crate::parse_env!(@fail $crate::__priv::Message::new()
+                                .push($crate::__priv::core::concat!("error: the value in `", $var_name, "` (`"))
+                                .push(s.trim_ascii())
+                                .push($crate::__priv::core::concat!(
+                                    "`) isn't aligned to `",
+                                    $crate::__priv::core::stringify!($align),
+                                    "`.",
+                                )))
+                        }
+                        $crate::__priv::Err($crate::__priv::AlignError::Parse(e)) => {
+                            $crate::parse_env!(@fail $crate::__priv::fail_message(
+                                $crate::__priv::core::concat!(
+                                    "error: the value in `",
+                                    $var_name,
+                                    "` doesn't parse as a `",
+                                    $crate::__priv::core::stringify!($typ),
+                                    "`",
+                                ),
+                                e,
+                                ", or is out of range.",
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident with_raw) => {
+        ($crate::parse_env!($var_name as $typ), $crate::__priv::core::env!($var_name))
+    };
+
+    ($var_name:literal as $typ:ident with_raw else $default:expr) => {
+        (
+            $crate::parse_env!($var_name as $typ else $default),
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => s,
+                $crate::__priv::None => "",
+            },
+        )
+    };
+
+    ($var_name:literal as rust_log $path:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::parse_bounded::rust_log_for(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+                $path,
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(rust_log $path),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::rust_log_for($crate::__priv::core::env!($var_name).as_bytes(), $path),
+                    ".",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as rust_log $path:literal else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: u8 = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parse_bounded::rust_log_for(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                        $path,
+                    ) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!(rust_log $path),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::rust_log_for(s.as_bytes(), $path),
+                            ".",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as bool_sign $name:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::parse_bounded::bool_sign_named(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+                $name,
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(bool_sign $name),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::bool_sign_named($crate::__priv::core::env!($var_name).as_bytes(), $name),
+                    $crate::__priv::core::concat!(": expected `+`, `-`, `+", $name, "` or `-", $name, "`."),
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as bool_sign $name:literal else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: bool = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parse_bounded::bool_sign_named(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                        $name,
+                    ) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!(bool_sign $name),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::bool_sign_named(s.as_bytes(), $name),
+                            $crate::__priv::core::concat!(": expected `+`, `-`, `+", $name, "` or `-", $name, "`."),
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident or_fallback $fallback:expr) => {
+        $crate::parse_env!(status $var_name as $typ or_fallback $fallback).value
+    };
+
+    (status $var_name:literal as $typ:ident or_fallback $fallback:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_FALLBACK: $crate::__priv::types::$typ = $fallback;
+            let parsed = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::None),
+                $crate::__priv::None => $crate::__priv::None,
+            };
+            match parsed {
+                $crate::__priv::Some(value) => $crate::Status { value, var_name: $var_name, fell_back: false },
+                $crate::__priv::None => {
+                    $crate::Status { value: __ENVPARSE_FALLBACK, var_name: $var_name, fell_back: true }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident empty_is $empty:tt) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_EMPTY: $crate::__priv::types::$typ = $empty;
+            match $crate::__priv::parsers::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::Some(__ENVPARSE_EMPTY),
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($crate::__priv::core::env!($var_name).as_bytes()),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident empty_is $empty:tt else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_EMPTY: $crate::__priv::types::$typ = $empty;
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_EMPTY)) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident relative_to $base:tt) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_BASE: $crate::__priv::types::$typ = $base;
+            match $crate::__priv::relative::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                __ENVPARSE_BASE,
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ relative_to $base),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident relative_to $base:tt else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            #[allow(unused_parens)]
+            const __ENVPARSE_BASE: $crate::__priv::types::$typ = $base;
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::relative::$typ(
+                        s.as_bytes(),
+                        __ENVPARSE_BASE,
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    ) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ relative_to $base),
+                                "`",
+                            ),
+                            e,
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident strip_comments) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            let s = $crate::parse::strip_comment($crate::__priv::core::env!($var_name).as_bytes());
+            match $crate::__priv::parsers::$typ(s, $crate::__priv::None) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ(s),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident strip_comments else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    let s = $crate::parse::strip_comment(s.as_bytes());
+                    match $crate::__priv::parsers::$typ(s, $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s),
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident try_marker else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    let (s, optional) = $crate::parse::strip_try_marker(s.as_bytes());
+                    match $crate::__priv::parsers::$typ(s, $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None if optional => __ENVPARSE_DEFAULT,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s),
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident strip_prefix $prefix:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            let s = $crate::__priv::core::env!($var_name).as_bytes();
+            let s = match $crate::parse::strip_prefix(s, $prefix.as_bytes()) {
+                $crate::__priv::Some(rest) => rest,
+                $crate::__priv::None => s,
+            };
+            match $crate::__priv::parsers::$typ(s, $crate::__priv::None) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ(s),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident strip_prefix $prefix:literal else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    let s = match $crate::parse::strip_prefix(s.as_bytes(), $prefix.as_bytes()) {
+                        $crate::__priv::Some(rest) => rest,
+                        $crate::__priv::None => s.as_bytes(),
+                    };
+                    match $crate::__priv::parsers::$typ(s, $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s),
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal index_in [$($word:literal),+ $(,)?]) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::word_index($crate::__priv::core::env!($var_name).as_bytes(), &[$($word),+]) {
+                $crate::__priv::Some(i) => i,
+                $crate::__priv::None => $crate::__priv::core::panic!($crate::__priv::core::concat!(
+                    "error: the value in `",
+                    $var_name,
+                    "` isn't one of ",
+                    $crate::__priv::core::stringify!([$($word),+]),
+                    ".",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal index_in [$($word:literal),+ $(,)?] else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: usize = $default;
+            $crate::__priv::core::assert!(
+                __ENVPARSE_DEFAULT < [$($word),+].len(),
+                $crate::__priv::core::concat!(
+                    "error: the default index `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is out of bounds for ",
+                    $crate::__priv::core::stringify!([$($word),+]),
+                    ".",
+                ),
+            );
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) if s.trim_ascii().is_empty() => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::word_index(s.as_bytes(), &[$($word),+]) {
+                    $crate::__priv::Some(i) => i,
+                    $crate::__priv::None => $crate::__priv::core::panic!($crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` isn't one of ",
+                        $crate::__priv::core::stringify!([$($word),+]),
+                        ".",
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal satisfies ($($req:tt)+) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_REQ: &str = $crate::__priv::core::stringify!($($req)+);
+            $crate::__priv::core::assert!(
+                $crate::parse::version_matches(__ENVPARSE_REQ.as_bytes(), (0, 0, 0)).is_ok(),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!($($req)+),
+                    "` isn't a valid version requirement (like `>=1.70`).",
+                ),
+            );
+            const __ENVPARSE_DEFAULT: bool = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) if s.trim_ascii().is_empty() => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::parsers::version(s.as_bytes(), $crate::__priv::None) {
+                    $crate::__priv::Some(v) => match $crate::parse::version_matches(__ENVPARSE_REQ.as_bytes(), v) {
+                        $crate::__priv::Ok(b) => b,
+                        $crate::__priv::Err(_) => $crate::__priv::core::unreachable!(),
+                    },
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `version`",
+                        ),
+                        $crate::__priv::diagnose::version(s.as_bytes()),
+                        ".",
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal satisfies $($req:tt)+) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_REQ: &str = $crate::__priv::core::stringify!($($req)+);
+            $crate::__priv::core::assert!(
+                $crate::parse::version_matches(__ENVPARSE_REQ.as_bytes(), (0, 0, 0)).is_ok(),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!($($req)+),
+                    "` isn't a valid version requirement (like `>=1.70`).",
+                ),
+            );
+            match $crate::__priv::parsers::version(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Some(v) => match $crate::parse::version_matches(__ENVPARSE_REQ.as_bytes(), v) {
+                    $crate::__priv::Ok(b) => b,
+                    $crate::__priv::Err(_) => $crate::__priv::core::unreachable!(),
+                },
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "` doesn't parse as a `version`"),
+                    $crate::__priv::diagnose::version($crate::__priv::core::env!($var_name).as_bytes()),
+                    ".",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident from { $($word:literal => $value:expr),+ $(,)? }) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_VALUES: &[$crate::__priv::types::$typ] = &[$($value),+];
+            let s = $crate::__priv::core::env!($var_name).as_bytes();
+            match $crate::__priv::word_index(s, &[$($word),+]) {
+                $crate::__priv::Some(i) => __ENVPARSE_VALUES[i],
+                $crate::__priv::None => match $crate::__priv::parsers::$typ(s, $crate::__priv::None) {
+                    $crate::__priv::Some(v) => v,
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` isn't one of ",
+                            $crate::__priv::core::stringify!([$($word),+]),
+                            ", and doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        $crate::__priv::diagnose::$typ(s),
+                        ", or is out of range.",
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident from { $($word:literal => $value:expr),+ $(,)? } else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_VALUES: &[$crate::__priv::types::$typ] = &[$($value),+];
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::word_index(s.as_bytes(), &[$($word),+]) {
+                    $crate::__priv::Some(i) => __ENVPARSE_VALUES[i],
+                    $crate::__priv::None => match $crate::__priv::parsers::$typ(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    ) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` isn't one of ",
+                                $crate::__priv::core::stringify!([$($word),+]),
+                                ", and doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            ", or is out of range.",
+                        )),
+                    },
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident chars $class:literal) => {
+        $crate::parse_env!(@chars $var_name as $typ ($class) ($crate::__priv::core::stringify!(chars $class)))
+    };
+
+    ($var_name:literal as $typ:ident chars $class:literal else $default:expr) => {
+        $crate::parse_env!(
+            @chars $var_name as $typ ($class) ($crate::__priv::core::stringify!(chars $class)) else $default
+        )
+    };
+
+    // `printable` is the same check as `chars " -~"`, with a clearer name in
+    // the error.
+    ($var_name:literal as $typ:ident printable) => {
+        $crate::parse_env!(@chars $var_name as $typ (" -~") ("printable"))
+    };
+
+    ($var_name:literal as $typ:ident printable else $default:expr) => {
+        $crate::parse_env!(@chars $var_name as $typ (" -~") ("printable") else $default)
+    };
+
+    (@chars $var_name:literal as $typ:ident ($class:literal) ($check:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $crate::__priv::valid_char_class($class),
+                $crate::__priv::core::concat!(
+                    "error: the character class `",
+                    $class,
+                    "` is empty or has a reversed range.",
+                ),
+            );
+            let v = match $crate::__priv::parsers::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Some(v) => v,
+                // Neither string type can fail to parse.
+                $crate::__priv::None => $crate::__priv::core::unreachable!(),
+            };
+            if let $crate::__priv::Some(pos) = $crate::parse::find_not_in_class(v.as_bytes(), $class.as_bytes()) {
+                $crate::parse_env!(@fail $crate::__priv::chars_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $check,
+                    v,
+                    pos,
+                ));
+            }
+            v
+        }
+    }};
+
+    (@chars $var_name:literal as $typ:ident ($class:literal) ($check:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $crate::__priv::valid_char_class($class),
+                $crate::__priv::core::concat!(
+                    "error: the character class `",
+                    $class,
+                    "` is empty or has a reversed range.",
+                ),
+            );
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            if let $crate::__priv::Some(pos) = $crate::parse::find_not_in_class(
+                __ENVPARSE_DEFAULT.as_bytes(),
+                $class.as_bytes(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::chars_message(
+                    $crate::__priv::core::concat!(
+                        "error: the default `",
+                        $crate::__priv::core::stringify!($default),
+                        "`",
+                    ),
+                    $check,
+                    __ENVPARSE_DEFAULT,
+                    pos,
+                ));
+            }
+            let v = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(
+                    s.as_bytes(),
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                ) {
+                    $crate::__priv::Some(v) => v,
+                    // Neither string type can fail to parse.
+                    $crate::__priv::None => $crate::__priv::core::unreachable!(),
+                },
+            };
+            if let $crate::__priv::Some(pos) = $crate::parse::find_not_in_class(v.as_bytes(), $class.as_bytes()) {
+                $crate::parse_env!(@fail $crate::__priv::chars_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $check,
+                    v,
+                    pos,
+                ));
+            }
+            v
+        }
+    }};
+
+    ($var_name:literal as $typ:ident starts_with $pat:literal) => {
+        $crate::parse_env!(@affix $var_name as $typ starts_with $pat)
+    };
+
+    ($var_name:literal as $typ:ident starts_with $pat:literal else $default:expr) => {
+        $crate::parse_env!(@affix $var_name as $typ starts_with $pat else $default)
+    };
+
+    ($var_name:literal as $typ:ident ends_with $pat:literal) => {
+        $crate::parse_env!(@affix $var_name as $typ ends_with $pat)
+    };
+
+    ($var_name:literal as $typ:ident ends_with $pat:literal else $default:expr) => {
+        $crate::parse_env!(@affix $var_name as $typ ends_with $pat else $default)
+    };
+
+    ($var_name:literal as $typ:ident starts_with_ignore_case $pat:literal) => {
+        $crate::parse_env!(@affix $var_name as $typ starts_with_ignore_case $pat)
+    };
+
+    ($var_name:literal as $typ:ident starts_with_ignore_case $pat:literal else $default:expr) => {
+        $crate::parse_env!(@affix $var_name as $typ starts_with_ignore_case $pat else $default)
+    };
+
+    ($var_name:literal as $typ:ident ends_with_ignore_case $pat:literal) => {
+        $crate::parse_env!(@affix $var_name as $typ ends_with_ignore_case $pat)
+    };
+
+    ($var_name:literal as $typ:ident ends_with_ignore_case $pat:literal else $default:expr) => {
+        $crate::parse_env!(@affix $var_name as $typ ends_with_ignore_case $pat else $default)
+    };
+
+    (@affix $var_name:literal as $typ:ident $check:ident $pat:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            let v = match $crate::__priv::parsers::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Some(v) => v,
+                // Neither string type can fail to parse.
+                $crate::__priv::None => $crate::__priv::core::unreachable!(),
+            };
+            $crate::__priv::core::assert!(
+                $crate::__priv::affix::$check(v, $pat),
+                $crate::__priv::core::concat!(
+                    "error: the value in `",
+                    $var_name,
+                    "` doesn't satisfy `",
+                    $crate::__priv::core::stringify!($check $pat),
+                    "`.",
+                ),
+            );
+            v
+        }
+    }};
+
+    (@affix $var_name:literal as $typ:ident $check:ident $pat:literal else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::affix::$check(__ENVPARSE_DEFAULT, $pat),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` doesn't satisfy `",
+                    $crate::__priv::core::stringify!($check $pat),
+                    "`.",
+                ),
+            );
+            let v = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(
+                    s.as_bytes(),
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                ) {
+                    $crate::__priv::Some(v) => v,
+                    // Neither string type can fail to parse.
+                    $crate::__priv::None => $crate::__priv::core::unreachable!(),
+                },
+            };
+            $crate::__priv::core::assert!(
+                $crate::__priv::affix::$check(v, $pat),
+                $crate::__priv::core::concat!(
+                    "error: the value in `",
+                    $var_name,
+                    "` doesn't satisfy `",
+                    $crate::__priv::core::stringify!($check $pat),
+                    "`.",
+                ),
+            );
+            v
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex digits $n:tt) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $n != 0,
+                $crate::__priv::core::concat!(
+                    "error: the digit count `",
+                    $crate::__priv::core::stringify!($n),
+                    "` is zero.",
+                ),
+            );
+            match $crate::__priv::hex_digits::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $n,
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ hex digits $n),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as $typ:ident hex digits $n:tt else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $n != 0,
+                $crate::__priv::core::concat!(
+                    "error: the digit count `",
+                    $crate::__priv::core::stringify!($n),
+                    "` is zero.",
+                ),
+            );
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::hex_digits::$typ(s.as_bytes(), $n, $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ hex digits $n),
+                                "`",
+                            ),
+                            e,
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as bool_at_least $n:tt) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_N: u128 = $n;
+            match $crate::__priv::parse_bounded::bool_at_least(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+                __ENVPARSE_N,
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(bool_at_least $n),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::bool_at_least($crate::__priv::core::env!($var_name).as_bytes()),
+                    ": expected a non-negative integer.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as bool_at_least $n:tt else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_N: u128 = $n;
+            const __ENVPARSE_DEFAULT: bool = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::parse_bounded::bool_at_least(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                        __ENVPARSE_N,
+                    ) {
+                        $crate::__priv::Some(v) => v,
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!(bool_at_least $n),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::bool_at_least(s.as_bytes()),
+                            ": expected a non-negative integer.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as pages($page:expr)) => {
+        $crate::parse_env!($var_name as pages($page) in ..)
+    };
+
+    ($var_name:literal as pages($page:expr) else $default:expr) => {
+        $crate::parse_env!($var_name as pages($page) (in ..) else $default)
+    };
+
+    ($var_name:literal as pages($page:expr) in $range:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $page != 0,
+                $crate::__priv::core::concat!(
+                    "error: the page size `",
+                    $crate::__priv::core::stringify!($page),
+                    "` is zero.",
+                ),
+            );
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::usize(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    "usize",
+                    problem,
+                ));
+            }
+            match $crate::__priv::parse_bounded::pages(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+                $page,
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .start(),
+                ),
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .end_incl(),
+                ),
+                false, // clamp
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(pages($page)),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::pages($crate::__priv::core::env!($var_name).as_bytes(), $page),
+                    $crate::__priv::core::concat!(
+                        ", or the size is outside of the range `",
+                        $crate::__priv::core::stringify!($range),
+                        "`.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as pages($page:expr) (in $range:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $page != 0,
+                $crate::__priv::core::concat!(
+                    "error: the page size `",
+                    $crate::__priv::core::stringify!($page),
+                    "` is zero.",
+                ),
+            );
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::usize(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    "usize",
+                    problem,
+                ));
+            }
+            const __ENVPARSE_DEFAULT: usize = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::in_range::usize(
+                    __ENVPARSE_DEFAULT,
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .start(),
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .end_incl(),
+                ),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is outside of the range `",
+                    $crate::__priv::core::stringify!($range),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::parse_bounded::pages(
+                    s.as_bytes(),
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    $page,
+                    $crate::__priv::Some(
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                        )
+                        .start(),
+                    ),
+                    $crate::__priv::Some(
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                        )
+                        .end_incl(),
+                    ),
+                    false, // clamp
+                ) {
+                    $crate::__priv::Some(v) => v,
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!(pages($page)),
+                            "`",
+                        ),
+                        $crate::__priv::diagnose::pages(s.as_bytes(), $page),
+                        $crate::__priv::core::concat!(
+                            ", or the size is outside of the range `",
+                            $crate::__priv::core::stringify!($range),
+                            "`.",
+                        ),
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal as self_bounded($typ:ident)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::self_bounded::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::triple_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as self_bounded($typ:ident) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::self_bounded::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::triple_message(
+                            $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                            $crate::__priv::core::stringify!($typ),
+                            e,
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as flag($typ:ident; on $on:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_ON: $crate::__priv::types::$typ = $on;
+            match $crate::__priv::flag::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                __ENVPARSE_ON,
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(flag($typ; on $on)),
+                        "`",
+                    ),
+                    e,
+                    ": expected something like `off`, `on`, or `on:N` with `N` in range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as flag($typ:ident; on $on:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_ON: $crate::__priv::types::$typ = $on;
+            const __ENVPARSE_DEFAULT: $crate::__priv::Option<$crate::__priv::types::$typ> = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::flag::$typ(
+                        s.as_bytes(),
+                        __ENVPARSE_ON,
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    ) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!(flag($typ; on $on)),
+                                "`",
+                            ),
+                            e,
+                            ": expected something like `off`, `on`, or `on:N` with `N` in range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as limit($typ:ident)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::limit::$typ($crate::__priv::core::env!($var_name).as_bytes(), $crate::__priv::None) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` isn't `auto` or `max`, and doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as limit($typ:ident) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::Limit<$crate::__priv::types::$typ> = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::limit::$typ(
+                    s.as_bytes(),
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                ) {
+                    $crate::__priv::Ok(v) => v,
+                    $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` isn't `auto` or `max`, and doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        e,
+                        ", or is out of range.",
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal as auto_or($typ:ident)) => {
+        $crate::parse_env!($var_name as auto_or($typ) in ..)
+    };
+
+    ($var_name:literal as auto_or($typ:ident) else $default:expr) => {
+        $crate::parse_env!($var_name as auto_or($typ) (in ..) else $default)
+    };
+
+    ($var_name:literal as auto_or($typ:ident) in $range:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            match $crate::__priv::auto_or::$typ(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` isn't `auto`, and doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    e,
+                    $crate::__priv::core::concat!(
+                        ", or is outside of the range `",
+                        $crate::__priv::core::stringify!($range),
+                        "`.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as auto_or($typ:ident) (in $range:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            const __ENVPARSE_DEFAULT: $crate::__priv::Option<$crate::__priv::types::$typ> = $default;
+            $crate::__priv::core::assert!(
+                match __ENVPARSE_DEFAULT {
+                    $crate::__priv::Some(d) => $crate::__priv::in_range::$typ(
+                        d,
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .start(),
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .end_incl(),
+                    ),
+                    $crate::__priv::None => true,
+                },
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is outside of the range `",
+                    $crate::__priv::core::stringify!($range),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::auto_or::$typ(
+                        s.as_bytes(),
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .start(),
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .end_incl(),
+                    ) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` isn't `auto`, and doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            e,
+                            $crate::__priv::core::concat!(
+                                ", or is outside of the range `",
+                                $crate::__priv::core::stringify!($range),
+                                "`.",
+                            ),
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as Wrapping<$typ:ident>) => {
+        $crate::__priv::core::num::Wrapping::<$crate::__priv::types::$typ>($crate::parse_env!($var_name as $typ))
+    };
+
+    ($var_name:literal as Wrapping<$typ:ident> else $default:expr) => {
+        $crate::__priv::core::num::Wrapping::<$crate::__priv::types::$typ>(
+            $crate::parse_env!($var_name as $typ else ($default).0),
+        )
+    };
+
+    ($var_name:literal as Wrapping<$typ:ident> in $range:expr) => {
+        $crate::__priv::core::num::Wrapping::<$crate::__priv::types::$typ>(
+            $crate::parse_env!($var_name as $typ in $range),
+        )
+    };
+
+    ($var_name:literal as Wrapping<$typ:ident> (in $range:expr) else $default:expr) => {
+        $crate::__priv::core::num::Wrapping::<$crate::__priv::types::$typ>(
+            $crate::parse_env!($var_name as $typ (in $range) else ($default).0),
+        )
+    };
+
+    ($var_name:literal as [[$typ:ident; $c:expr]; $r:expr]) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::grid_parsers::$typ::<{ $c }, { $r }>(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::grid_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!([[$typ; $c]; $r]),
+                    $crate::__priv::core::stringify!([$typ; $c]),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as [[$typ:ident; $c:expr]; $r:expr] else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: [[$crate::__priv::types::$typ; $c]; $r] = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::grid_parsers::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::grid_message(
+                            $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                            $crate::__priv::core::stringify!([[$typ; $c]; $r]),
+                            $crate::__priv::core::stringify!([$typ; $c]),
+                            $crate::__priv::core::stringify!($typ),
+                            e,
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as strbuf[$n:expr]) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::strbuf::<{ $n }>(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        "strbuf[", $crate::__priv::core::stringify!($n), "]",
+                        "`",
+                    ),
+                    e,
+                    $crate::__priv::core::concat!(
+                        ": it's longer than ",
+                        $crate::__priv::core::stringify!($n),
+                        " bytes.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as strbuf[$n:expr] else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: &str = $default;
+            $crate::__priv::core::assert!(
+                __ENVPARSE_DEFAULT.len() <= $n,
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is longer than ",
+                    $crate::__priv::core::stringify!($n),
+                    " bytes.",
+                ),
+            );
+            let s = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => s,
+                $crate::__priv::None => "",
+            };
+            match $crate::__priv::strbuf::<{ $n }>(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        "strbuf[", $crate::__priv::core::stringify!($n), "]",
+                        "`",
+                    ),
+                    e,
+                    $crate::__priv::core::concat!(
+                        ": it's longer than ",
+                        $crate::__priv::core::stringify!($n),
+                        " bytes.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as [u8; $n:expr] fill) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::fill::<{ $n }>(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!([u8; $n] fill),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as [u8; $n:expr] fill else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: [u8; $n] = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::fill(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "error: the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!([u8; $n] fill),
+                                "`",
+                            ),
+                            e,
+                            ", or is out of range.",
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal as [$typ:ident; $n:expr]) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (false) brackets (false))
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] else $default:expr) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (false) brackets (false) else $default)
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] loose) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (true) brackets (false))
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] loose else $default:expr) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (true) brackets (false) else $default)
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] bracketed) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (false) brackets (true))
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] bracketed else $default:expr) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (false) brackets (true) else $default)
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] loose bracketed) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (true) brackets (true))
+    };
+
+    ($var_name:literal as [$typ:ident; $n:expr] loose bracketed else $default:expr) => {
+        $crate::parse_env!(@array $var_name as [$typ; $n] loose (true) brackets (true) else $default)
+    };
+
+    ($var_name:literal as [Option<$typ:ident>; $n:expr]) => {
+        $crate::parse_env!(@option_array $var_name as [Option<$typ>; $n] brackets (false))
+    };
+
+    ($var_name:literal as [Option<$typ:ident>; $n:expr] else $default:expr) => {
+        $crate::parse_env!(@option_array $var_name as [Option<$typ>; $n] brackets (false) else $default)
+    };
+
+    ($var_name:literal as [Option<$typ:ident>; $n:expr] bracketed) => {
+        $crate::parse_env!(@option_array $var_name as [Option<$typ>; $n] brackets (true))
+    };
+
+    ($var_name:literal as [Option<$typ:ident>; $n:expr] bracketed else $default:expr) => {
+        $crate::parse_env!(@option_array $var_name as [Option<$typ>; $n] brackets (true) else $default)
+    };
+
+    (@array $var_name:literal as [$typ:ident; $n:expr] loose ($loose:expr) brackets ($b:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::array_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) $crate::__priv::core::env!($var_name).as_bytes()),
+                $crate::__priv::None,
+                $loose,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!([$typ; $n]),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    (@array $var_name:literal as [$typ:ident; $n:expr] loose ($loose:expr) brackets ($b:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: [$crate::__priv::types::$typ; $n] = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => {
+                    match $crate::__priv::array_parsers::$typ(
+                        $crate::parse_env!(@brackets $var_name ($b) s.as_bytes()),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                        $loose,
+                    ) {
+                        $crate::__priv::Ok(v) => v,
+                        $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                            $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                            $crate::__priv::core::stringify!([$typ; $n]),
+                            $crate::__priv::core::stringify!($typ),
+                            e,
+                        )),
+                    }
+                }
+            }
+        }
+    }};
+
+    (@option_array $var_name:literal as [Option<$typ:ident>; $n:expr] brackets ($b:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::option_array_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) $crate::__priv::core::env!($var_name).as_bytes()),
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!([Option<$typ>; $n]),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    (@option_array $var_name:literal as [Option<$typ:ident>; $n:expr] brackets ($b:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: [$crate::__priv::Option<$crate::__priv::types::$typ>; $n] = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::option_array_parsers::$typ::<{ $n }>(
+                    $crate::parse_env!(@brackets $var_name ($b) s.as_bytes()),
+                ) {
+                    $crate::__priv::Ok(v) => v,
+                    $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                        $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                        $crate::__priv::core::stringify!([Option<$typ>; $n]),
+                        $crate::__priv::core::stringify!($typ),
+                        e,
+                    )),
+                },
+            }
+        }
+    }};
+
+    (@brackets $var_name:literal ($b:expr) $s:expr) => {
+        match $s {
+            s if $b => match $crate::parse::strip_brackets(s) {
+                $crate::__priv::Some(s) => s,
+                $crate::__priv::None => $crate::__priv::core::panic!(
+                    "{}",
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` has brackets that don't match (expected `[...]`, `(...)` or `{...}`).",
+                    ),
+                ),
+            },
+            s => s,
+        }
+    };
+
+    (@list $var_name:literal as list($typ:ident; max $n:expr) fill ($fill:expr) loose ($loose:expr)
+        brackets ($b:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::list_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) $crate::__priv::core::env!($var_name).as_bytes()),
+                $fill,
+                $crate::__priv::None,
+                $loose,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!(list($typ; max $n)),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    (@list $var_name:literal as list($typ:ident; max $n:expr) fill ($fill:expr) loose ($loose:expr) brackets ($b:expr)
+        else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: &[$crate::__priv::types::$typ] = $default;
+            $crate::__priv::core::assert!(
+                __ENVPARSE_DEFAULT.len() <= $n,
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` has more than ",
+                    $crate::__priv::core::stringify!($n),
+                    " items.",
+                ),
+            );
+            let s = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => s,
+                $crate::__priv::None => "",
+            };
+            match $crate::__priv::list_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) s.as_bytes()),
+                $fill,
+                $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                $loose,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!(list($typ; max $n)),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    (@unique $var_name:literal as list($typ:ident; max $n:expr) loose ($loose:expr) brackets ($b:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::unique_list_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) $crate::__priv::core::env!($var_name).as_bytes()),
+                $crate::__priv::None,
+                $loose,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!(list($typ; max $n) unique),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    (@unique $var_name:literal as list($typ:ident; max $n:expr) loose ($loose:expr) brackets ($b:expr)
+        else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: &[$crate::__priv::types::$typ] = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::unique_list_parsers::$typ::<{ $n }>(
+                    b"",
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    false,
+                ).is_ok(),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` has more than ",
+                    $crate::__priv::core::stringify!($n),
+                    " distinct items.",
+                ),
+            );
+            let s = match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => s,
+                $crate::__priv::None => "",
+            };
+            match $crate::__priv::unique_list_parsers::$typ::<{ $n }>(
+                $crate::parse_env!(@brackets $var_name ($b) s.as_bytes()),
+                $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                $loose,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::array_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!(list($typ; max $n) unique),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
+            }
+        }
+    }};
+
+    ($var_name:literal as list($typ:ident; max $n:expr) unique) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (false) brackets (false))
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) unique else $default:expr) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (false) brackets (false) else $default)
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) unique bracketed) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (false) brackets (true))
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) unique bracketed else $default:expr) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (false) brackets (true) else $default)
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose unique) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (true) brackets (false))
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose unique else $default:expr) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (true) brackets (false) else $default)
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose unique bracketed) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (true) brackets (true))
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose unique bracketed else $default:expr) => {
+        $crate::parse_env!(@unique $var_name as list($typ; max $n) loose (true) brackets (true) else $default)
+    };
+
+    (@q $var_name:literal as q($i:expr, $f:expr) clamp ($clamp:expr)) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $crate::__priv::qfixed_valid($i, $f),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!(q($i, $f)),
+                    "` must have from 1 to 128 bits in total.",
+                ),
+            );
+            match $crate::__priv::qfixed(
+                $crate::__priv::core::env!($var_name).as_bytes(),
+                $i,
+                $f,
+                $clamp,
+                $crate::__priv::None,
+            ) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!(q($i, $f)),
+                        "`",
+                    ),
+                    e,
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    (@q $var_name:literal as q($i:expr, $f:expr) clamp ($clamp:expr) else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::assert!(
+                $crate::__priv::qfixed_valid($i, $f),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!(q($i, $f)),
+                    "` must have from 1 to 128 bits in total.",
+                ),
+            );
+            const __ENVPARSE_DEFAULT: i128 = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::qfixed_fits(__ENVPARSE_DEFAULT, $i, $f),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` doesn't fit in a `",
+                    $crate::__priv::core::stringify!(q($i, $f)),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::__priv::qfixed(
+                    s.as_bytes(),
+                    $i,
+                    $f,
+                    $clamp,
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                ) {
+                    $crate::__priv::Ok(v) => v,
+                    $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!(q($i, $f)),
+                            "`",
+                        ),
+                        e,
+                        ", or is out of range.",
+                    )),
+                },
+            }
+        }
+    }};
+
+    ($var_name:literal as q($i:expr, $f:expr)) => {
+        $crate::parse_env!(@q $var_name as q($i, $f) clamp (false))
+    };
+
+    ($var_name:literal as q($i:expr, $f:expr) else $default:expr) => {
+        $crate::parse_env!(@q $var_name as q($i, $f) clamp (false) else $default)
+    };
+
+    ($var_name:literal as q($i:expr, $f:expr) clamp) => {
+        $crate::parse_env!(@q $var_name as q($i, $f) clamp (true))
+    };
+
+    ($var_name:literal as q($i:expr, $f:expr) clamp else $default:expr) => {
+        $crate::parse_env!(@q $var_name as q($i, $f) clamp (true) else $default)
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr)) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (false) brackets (false)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (false) brackets (false)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (true) brackets (false)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (true) brackets (false)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) bracketed) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (false) brackets (true)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) bracketed else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (false) brackets (true)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose bracketed) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (true) brackets (true)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr) loose bracketed else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::None) loose (true) brackets (true) else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr)) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (false) brackets (false)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (false) brackets (false)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) loose) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (true) brackets (false)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) loose else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (true) brackets (false)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) bracketed) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (false) brackets (true)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) bracketed else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (false) brackets (true)
+                else $default
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) loose bracketed) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (true) brackets (true)
+        )
+    };
+
+    ($var_name:literal as list($typ:ident; max $n:expr, fill $fill:expr) loose bracketed else $default:expr) => {
+        $crate::parse_env!(
+            @list $var_name as list($typ; max $n) fill ($crate::__priv::Some($fill)) loose (true) brackets (true)
+                else $default
+        )
+    };
+
+    ($var_name:literal as (str $sep:literal $typ:ident)) => {
+        $crate::parse_env!($var_name as (str (in ..) $sep $typ))
+    };
+
+    ($var_name:literal as (str $sep:literal $typ:ident) else $default:expr) => {
+        $crate::parse_env!($var_name as (str (in ..) $sep $typ) else $default)
+    };
+
+    ($var_name:literal as (str (in $len:expr) $sep:literal $typ:ident)) => {{
         const {
-            match $crate::__priv::parsers::$typ($crate::__priv::core::env!($var_name).as_bytes(), $crate::__priv::None)
-            {
-                $crate::__priv::Some(v) => v,
-                $crate::__priv::None => {
-                    $crate::__priv::core::panic!($crate::__priv::core::concat!(
-                        "error: the value in `",
-                        $crate::__priv::core::stringify!($s),
-                        "` doesn't parse as a `",
-                        $crate::__priv::core::stringify!($typ),
-                        "`, or is out of range.",
-                    ));
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::split_pair(
+                $crate::__priv::core::env!($var_name),
+                $sep,
+                $crate::__priv::RangeWrap(
+                    $len,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $len,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                )
+                .end_incl(),
+            ) {
+                $crate::__priv::Ok((key, value)) => {
+                    match $crate::__priv::parsers::$typ(value, $crate::__priv::None) {
+                        $crate::__priv::Some(v) => (key, v),
+                        $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::pair_message(
+                            $crate::__priv::core::concat!("error: `", $var_name, "`"),
+                            $crate::__priv::core::stringify!($sep),
+                            $crate::__priv::core::stringify!($len),
+                            $crate::__priv::core::stringify!($typ),
+                            $crate::__priv::PairError::Value($crate::__priv::diagnose::$typ(value)),
+                        )),
+                    }
                 }
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::pair_message(
+                    $crate::__priv::core::concat!("error: `", $var_name, "`"),
+                    $crate::__priv::core::stringify!($sep),
+                    $crate::__priv::core::stringify!($len),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
             }
         }
     }};
 
-    ($var_name:literal as $typ:ident else $default:expr) => {{
+    ($var_name:literal as (str (in $len:expr) $sep:literal $typ:ident) else $default:expr) => {{
         const {
-            const __ENVPARSE_DEFAULT: $typ = $default;
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: (&'static str, $crate::__priv::types::$typ) = $default;
             match $crate::__priv::core::option_env!($var_name) {
                 $crate::__priv::None => __ENVPARSE_DEFAULT,
-                $crate::__priv::Some(s) => {
-                    match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
-                        $crate::__priv::Some(v) => v,
-                        $crate::__priv::None => {
-                            $crate::__priv::core::panic!($crate::__priv::core::concat!(
-                                "error: the value in `",
-                                $crate::__priv::core::stringify!($s),
-                                "` doesn't parse as a `",
+                $crate::__priv::Some(s) => match $crate::__priv::split_pair(
+                    s,
+                    $sep,
+                    $crate::__priv::RangeWrap(
+                        $len,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .start(),
+                    $crate::__priv::RangeWrap(
+                        $len,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::usize>,
+                    )
+                    .end_incl(),
+                ) {
+                    $crate::__priv::Ok((key, value)) => {
+                        match $crate::__priv::parsers::$typ(value, $crate::__priv::None) {
+                            $crate::__priv::Some(v) => (key, v),
+                            $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::pair_message(
+                                $crate::__priv::core::concat!("error: `", $var_name, "`"),
+                                $crate::__priv::core::stringify!($sep),
+                                $crate::__priv::core::stringify!($len),
                                 $crate::__priv::core::stringify!($typ),
-                                "`, or is out of range.",
-                            ));
+                                $crate::__priv::PairError::Value($crate::__priv::diagnose::$typ(value)),
+                            )),
                         }
                     }
-                }
+                    $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::pair_message(
+                        $crate::__priv::core::concat!("error: `", $var_name, "`"),
+                        $crate::__priv::core::stringify!($sep),
+                        $crate::__priv::core::stringify!($len),
+                        $crate::__priv::core::stringify!($typ),
+                        e,
+                    )),
+                },
             }
         }
     }};
 
-    ($var_name:literal as $typ:ident in $range:expr) => {{
+    ($var_name:literal as pairs(str $sep:literal $typ:ident; $n:expr)) => {{
         const {
-            match $crate::__priv::parse_bounded::$typ(
-                $crate::__priv::core::env!($var_name).as_bytes(),
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::pairs_parsers::$typ::<{ $n }>(
+                $crate::__priv::core::env!($var_name),
+                $sep,
                 $crate::__priv::None,
-                $crate::__priv::Some(
-                    $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>).start(),
-                ),
-                $crate::__priv::Some(
-                    $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>).end_incl(),
-                ),
-                false, // clamp
             ) {
-                $crate::__priv::Some(v) => v,
-                $crate::__priv::None => {
-                    $crate::__priv::core::panic!($crate::__priv::core::concat!(
-                        "error: the value in ",
-                        $crate::__priv::core::stringify!($s),
-                        " doesn't parse as a `",
-                        $crate::__priv::core::stringify!($typ),
-                        "`, or is outside of the range `",
-                        $crate::__priv::core::stringify!($range),
-                        "`."
-                    ));
-                }
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::pairs_message(
+                    $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                    $crate::__priv::core::stringify!(pairs(str $sep $typ; $n)),
+                    $crate::__priv::core::stringify!($sep),
+                    $crate::__priv::core::stringify!($typ),
+                    e,
+                )),
             }
         }
     }};
 
-    ($var_name:literal as $typ:ident (in $range:expr) else $default:expr) => {{
+    ($var_name:literal as pairs(str $sep:literal $typ:ident; $n:expr) else $default:expr) => {{
         const {
-            const __ENVPARSE_DEFAULT: $typ = $default;
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: ([&'static str; $n], [$crate::__priv::types::$typ; $n]) = $default;
             match $crate::__priv::core::option_env!($var_name) {
                 $crate::__priv::None => __ENVPARSE_DEFAULT,
-                $crate::__priv::Some(s) => {
-                    match $crate::__priv::parse_bounded::$typ(
-                        s.as_bytes(),
-                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
-                        $crate::__priv::Some(
-                            $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>)
-                                .start(),
-                        ),
-                        $crate::__priv::Some(
-                            $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>)
-                                .end_incl(),
-                        ),
-                        false, // clamp
-                    ) {
-                        $crate::__priv::Some(v) => v,
-                        $crate::__priv::None => {
-                            $crate::__priv::core::panic!($crate::__priv::core::concat!(
-                                "error: the value in ",
-                                $crate::__priv::core::stringify!($s),
-                                " doesn't parse as a `",
-                                $crate::__priv::core::stringify!($typ),
-                                "`, or is outside of the range`",
-                                $crate::__priv::core::stringify!($range),
-                                "`."
-                            ));
-                        }
-                    }
-                }
+                $crate::__priv::Some(s) => match $crate::__priv::pairs_parsers::$typ::<{ $n }>(
+                    s,
+                    $sep,
+                    $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                ) {
+                    $crate::__priv::Ok(v) => v,
+                    $crate::__priv::Err(e) => $crate::parse_env!(@fail $crate::__priv::pairs_message(
+                        $crate::__priv::core::concat!("error: the value in `", $var_name, "`"),
+                        $crate::__priv::core::stringify!(pairs(str $sep $typ; $n)),
+                        $crate::__priv::core::stringify!($sep),
+                        $crate::__priv::core::stringify!($typ),
+                        e,
+                    )),
+                },
+            }
+        }
+    }};
+
+    (absent $var_name:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            $crate::__priv::core::option_env!($var_name).is_none()
+        }
+    }};
+
+    (contains $var_name:literal $word:literal) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::Some(s) => $crate::parse::has_item(s.as_bytes(), $word.as_bytes()),
+                $crate::__priv::None => false,
             }
         }
     }};
 
     (try $var_name:literal as $typ:ident) => {{
         const {
+            $crate::parse_env!(@check_name $var_name);
             match $crate::__priv::core::option_env!($var_name) {
                 $crate::__priv::None => $crate::__priv::None,
                 $crate::__priv::Some(s) if s.is_empty() => $crate::__priv::None,
                 $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::None) {
-                    $crate::__priv::None => {
-                        $crate::__priv::core::panic!($crate::__priv::core::concat!(
-                            "error: the value in ",
-                            $crate::__priv::core::stringify!($s),
-                            " doesn't parse as a `",
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
                             $crate::__priv::core::stringify!($typ),
-                            "`, or is out of range.",
-                        ));
-                    }
+                            "`",
+                        ),
+                        $crate::__priv::diagnose::$typ(s.as_bytes()),
+                        ", or is out of range.",
+                    )),
                     opt => opt,
                 },
             }
@@ -290,36 +4284,637 @@ macro_rules! parse_env {
 
     (try $var_name:literal as $typ:ident in $range:expr) => {{
         const {
-            match ::core::option_env!($var_name) {
+            $crate::parse_env!(@check_name $var_name);
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            match $crate::__priv::core::option_env!($var_name) {
                 $crate::__priv::None => $crate::__priv::None,
                 $crate::__priv::Some(s) if s.is_empty() => $crate::__priv::None,
                 $crate::__priv::Some(s) => match $crate::__priv::parse_bounded::$typ(
                     s.as_bytes(),
                     $crate::__priv::None,
                     $crate::__priv::Some(
-                        $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>).start(),
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .start(),
                     ),
                     $crate::__priv::Some(
-                        $crate::__priv::RangeWrap($range, $crate::__priv::core::marker::PhantomData::<$typ>).end_incl(),
+                        $crate::__priv::RangeWrap(
+                            $range,
+                            $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                        )
+                        .end_incl(),
                     ),
                     false, // clamp
                 ) {
-                    $crate::__priv::None => {
-                        ::core::panic!(::core::concat!(
-                            "error: the value in ",
-                            ::core::stringify!($s),
-                            " doesn't parse as a `",
-                            ::core::stringify!($typ),
-                            "`, or is outside of the range `",
-                            ::core::stringify!($range),
+                    $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        $crate::__priv::diagnose::$typ(s.as_bytes()),
+                        $crate::__priv::core::concat!(
+                            ", or is outside of the range `",
+                            $crate::__priv::core::stringify!($range),
                             "`.",
+                        ),
+                    )),
+                    opt => opt,
+                },
+            }
+        }
+    }};
+
+    (try $var_name:literal as $typ:ident else $default:expr) => {{
+        const {
+            const __ENVPARSE_DEFAULT: $crate::__priv::Option<$crate::__priv::types::$typ> = $default;
+            match $crate::parse_env!(try $var_name as $typ) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                v => v,
+            }
+        }
+    }};
+
+    (lit $s:literal as $typ:ident) => {{
+        const {
+            match $crate::__priv::parsers::$typ($s.as_bytes(), $crate::__priv::None) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value `",
+                        $s,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($s.as_bytes()),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    (lit $s:literal as $typ:ident else $default:expr) => {{
+        const {
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            match $crate::__priv::parsers::$typ($s.as_bytes(), $crate::__priv::Some(__ENVPARSE_DEFAULT)) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value `",
+                        $s,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($s.as_bytes()),
+                    ", or is out of range.",
+                )),
+            }
+        }
+    }};
+
+    (lit $s:literal as $typ:ident in $range:expr) => {{
+        const {
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            match $crate::__priv::parse_bounded::$typ(
+                $s.as_bytes(),
+                $crate::__priv::None,
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .start(),
+                ),
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .end_incl(),
+                ),
+                false, // clamp
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value `",
+                        $s,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($s.as_bytes()),
+                    $crate::__priv::core::concat!(
+                        ", or is outside of the range `",
+                        $crate::__priv::core::stringify!($range),
+                        "`.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    (lit $s:literal as $typ:ident (in $range:expr) else $default:expr) => {{
+        const {
+            if let $crate::__priv::Some(problem) = $crate::__priv::range_problem::$typ(
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .start(),
+                $crate::__priv::RangeWrap(
+                    $range,
+                    $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                )
+                .end_incl(),
+            ) {
+                $crate::parse_env!(@fail $crate::__priv::range_message(
+                    $crate::__priv::core::concat!("error: the range `", $crate::__priv::core::stringify!($range), "`"),
+                    $crate::__priv::core::stringify!($typ),
+                    problem,
+                ));
+            }
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            $crate::__priv::core::assert!(
+                $crate::__priv::in_range::$typ(
+                    __ENVPARSE_DEFAULT,
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .start(),
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .end_incl(),
+                ),
+                $crate::__priv::core::concat!(
+                    "error: the default `",
+                    $crate::__priv::core::stringify!($default),
+                    "` is outside of the range `",
+                    $crate::__priv::core::stringify!($range),
+                    "`.",
+                ),
+            );
+            match $crate::__priv::parse_bounded::$typ(
+                $s.as_bytes(),
+                $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .start(),
+                ),
+                $crate::__priv::Some(
+                    $crate::__priv::RangeWrap(
+                        $range,
+                        $crate::__priv::core::marker::PhantomData::<$crate::__priv::types::$typ>,
+                    )
+                    .end_incl(),
+                ),
+                false, // clamp
+            ) {
+                $crate::__priv::Some(v) => v,
+                $crate::__priv::None => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                    $crate::__priv::core::concat!(
+                        "error: the value `",
+                        $s,
+                        "` doesn't parse as a `",
+                        $crate::__priv::core::stringify!($typ),
+                        "`",
+                    ),
+                    $crate::__priv::diagnose::$typ($s.as_bytes()),
+                    $crate::__priv::core::concat!(
+                        ", or is outside of the range `",
+                        $crate::__priv::core::stringify!($range),
+                        "`.",
+                    ),
+                )),
+            }
+        }
+    }};
+
+    (checked $var_name:literal as $typ:ident) => {
+        $crate::parse_env!(@checked $crate::parse_env!(result $var_name as $typ))
+    };
+
+    (checked $var_name:literal as $typ:ident else $default:expr) => {
+        $crate::parse_env!(@checked $crate::parse_env!(result $var_name as $typ else $default))
+    };
+
+    (@checked $result:expr) => {
+        const {
+            match $result {
+                $crate::__priv::Ok(v) => $crate::Checked::Value(v),
+                $crate::__priv::Err(msg) => $crate::Checked::Invalid(msg),
+            }
+        }
+    };
+
+    (result $var_name:literal as $typ:ident) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            // Built in a const item, so that `&__ENVPARSE_RESULT` below is
+            // promoted, and the message can be a `&'static str`.
+            const __ENVPARSE_RESULT: $crate::__priv::Result<$crate::__priv::types::$typ, $crate::__priv::Message> =
+                match $crate::__priv::core::option_env!($var_name) {
+                    $crate::__priv::None => $crate::__priv::Err($crate::__priv::Message::new().push(
+                        $crate::__priv::core::concat!("`", $var_name, "` isn't set."),
+                    )),
+                    $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::None) {
+                        $crate::__priv::Some(v) => $crate::__priv::Ok(v),
+                        $crate::__priv::None => $crate::__priv::Err($crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            ", or is out of range.",
+                        )),
+                    },
+                };
+            match &__ENVPARSE_RESULT {
+                $crate::__priv::Ok(v) => $crate::__priv::Ok(*v),
+                $crate::__priv::Err(msg) => $crate::__priv::Err(msg.as_str()),
+            }
+        }
+    }};
+
+    (result $var_name:literal as $typ:ident else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: $crate::__priv::types::$typ = $default;
+            // Built in a const item, so that `&__ENVPARSE_RESULT` below is
+            // promoted, and the message can be a `&'static str`.
+            const __ENVPARSE_RESULT: $crate::__priv::Result<$crate::__priv::types::$typ, $crate::__priv::Message> =
+                match $crate::__priv::core::option_env!($var_name) {
+                    $crate::__priv::None => $crate::__priv::Ok(__ENVPARSE_DEFAULT),
+                    $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(
+                        s.as_bytes(),
+                        $crate::__priv::Some(__ENVPARSE_DEFAULT),
+                    ) {
+                        $crate::__priv::Some(v) => $crate::__priv::Ok(v),
+                        $crate::__priv::None => $crate::__priv::Err($crate::__priv::fail_message(
+                            $crate::__priv::core::concat!(
+                                "the value in `",
+                                $var_name,
+                                "` doesn't parse as a `",
+                                $crate::__priv::core::stringify!($typ),
+                                "`",
+                            ),
+                            $crate::__priv::diagnose::$typ(s.as_bytes()),
+                            ", or is out of range.",
+                        )),
+                    },
+                };
+            match &__ENVPARSE_RESULT {
+                $crate::__priv::Ok(v) => $crate::__priv::Ok(*v),
+                $crate::__priv::Err(msg) => $crate::__priv::Err(msg.as_str()),
+            }
+        }
+    }};
+}
+
+/// Parse an environment variable holding a comma-separated list of words
+/// into a `u64` bitmask, with bit `i` set if the `i`th word in the vocabulary
+/// was present.
+///
+/// Matching is case-insensitive and ignores whitespace around each item.
+/// Duplicates are allowed (they have no additional effect), and empty items
+/// are skipped, so an empty value produces `0`. Words that aren't in the
+/// vocabulary are a compile error. At most 64 words may be provided. See
+/// [`parse::parse_set`] for the underlying parser.
+///
+/// ```
+/// // With `ENABLED=alpha,gamma`, this would be `0b101`.
+/// const ENABLED: u64 = envparse::parse_env_set!("MYCRATE_ENABLED" in ["alpha", "beta", "gamma"] else 0);
+/// const GAMMA_ENABLED: bool = ENABLED & (1 << 2) != 0;
+/// ```
+///
+/// Like [`parse_env!`], omitting the `else` requires the variable be set.
+///
+/// ```compile_fail
+/// const ENABLED: u64 = envparse::parse_env_set!("MUST_BE_USER_PROVIDED" in ["alpha", "beta"]);
+/// ```
+#[macro_export]
+macro_rules! parse_env_set {
+    ($var_name:literal in [$($word:literal),* $(,)?]) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            match $crate::parse::parse_set($crate::__priv::core::env!($var_name).as_bytes(), &[$($word),*]) {
+                $crate::__priv::Ok(v) => v,
+                $crate::__priv::Err(_) => {
+                    $crate::__priv::core::panic!($crate::__priv::core::concat!(
+                        "error: the value in `",
+                        $var_name,
+                        "` contains an item which is not one of ",
+                        $crate::__priv::core::stringify!([$($word),*]),
+                        ".",
+                    ));
+                }
+            }
+        }
+    }};
+
+    ($var_name:literal in [$($word:literal),* $(,)?] else $default:expr) => {{
+        const {
+            $crate::parse_env!(@check_name $var_name);
+            const __ENVPARSE_DEFAULT: u64 = $default;
+            match $crate::__priv::core::option_env!($var_name) {
+                $crate::__priv::None => __ENVPARSE_DEFAULT,
+                $crate::__priv::Some(s) => match $crate::parse::parse_set(s.as_bytes(), &[$($word),*]) {
+                    $crate::__priv::Ok(v) => v,
+                    $crate::__priv::Err(_) => {
+                        $crate::__priv::core::panic!($crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` contains an item which is not one of ",
+                            $crate::__priv::core::stringify!([$($word),*]),
+                            ".",
                         ));
                     }
-                    opt => opt,
                 },
             }
         }
     }};
 }
 
+/// Decide whether to use color at compile time, from the `NO_COLOR`,
+/// `CLICOLOR` and `CLICOLOR_FORCE` variables, giving a [`ColorChoice`].
+///
+/// These conventions are easy to get subtly wrong, so this implements them in
+/// one place. See [`ColorChoice::from_vars`] for the precedence rules. The
+/// three variable names can be given (in that order) if a tool uses its own.
+/// None of them are required to be set.
+///
+/// ```
+/// use envparse::ColorChoice;
+/// const COLOR: ColorChoice = envparse::color_choice!();
+/// const MY_COLOR: ColorChoice = envparse::color_choice!("MYCRATE_NO_COLOR", "MYCRATE_COLOR", "MYCRATE_COLOR_FORCE");
+/// # assert_eq!(MY_COLOR, ColorChoice::Auto);
+/// ```
+///
+/// Since this is decided when your crate is built, it's mostly useful for
+/// things like build tools and code generators, rather than programs whose
+/// output goes to whatever terminal they're later run in.
+#[macro_export]
+macro_rules! color_choice {
+    () => {
+        $crate::color_choice!("NO_COLOR", "CLICOLOR", "CLICOLOR_FORCE")
+    };
+
+    ($no_color:literal, $clicolor:literal, $clicolor_force:literal $(,)?) => {{
+        const {
+            $crate::parse_env!(@check_name $no_color);
+            $crate::parse_env!(@check_name $clicolor);
+            $crate::parse_env!(@check_name $clicolor_force);
+            $crate::ColorChoice::from_vars(
+                $crate::__priv::core::option_env!($no_color),
+                $crate::__priv::core::option_env!($clicolor),
+                $crate::__priv::core::option_env!($clicolor_force),
+            )
+        }
+    }};
+}
+
+/// Checks at compile time that a string literal parses as a given type (and
+/// optionally, that it parses to a given value).
+///
+/// This is intended for crates which take configuration through
+/// [`parse_env!`], as a way to check that the examples in their documentation
+/// (or tests) actually parse the way they claim. It expands to an item, so it
+/// can be used at the top level of a module.
+///
+/// The string is parsed the same way as with `parse_env!(lit ...)`.
+///
+/// ```
+/// envparse::assert_parses!("0x7b" as u32 => 123);
+/// envparse::assert_parses!("yes" as bool => true);
+/// envparse::assert_parses!("-3/4" as ratio);
+/// ```
+///
+/// ```compile_fail
+/// envparse::assert_parses!("123" as u32 => 124);
+/// ```
+#[macro_export]
+macro_rules! assert_parses {
+    ($s:literal as $typ:ident) => {
+        const _: () = {
+            let _ = $crate::parse_env!(lit $s as $typ);
+        };
+    };
+
+    ($s:literal as $typ:ident => $expected:expr) => {
+        const _: () = {
+            const __ENVPARSE_EXPECTED: $crate::__priv::types::$typ = $expected;
+            $crate::__priv::core::assert!(
+                $crate::__priv::eq::$typ($crate::parse_env!(lit $s as $typ), __ENVPARSE_EXPECTED),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!($s),
+                    "` parses as a `",
+                    $crate::__priv::core::stringify!($typ),
+                    "`, but not as `",
+                    $crate::__priv::core::stringify!($expected),
+                    "`.",
+                ),
+            );
+        };
+    };
+}
+
+/// Checks at compile time that a string literal fails to parse as a given type
+/// (and optionally, that it fails with a given [`ParseError`](parse::ParseError)
+/// variant).
+///
+/// This is the counterpart to [`assert_parses!`], for documenting the syntax a
+/// crate rejects.
+///
+/// ```
+/// envparse::assert_fails!("abc" as u32 => InvalidDigit);
+/// envparse::assert_fails!("300" as u8 => OutOfRange);
+/// envparse::assert_fails!("maybe" as bool);
+/// ```
+///
+/// ```compile_fail
+/// envparse::assert_fails!("123" as u32);
+/// ```
+#[macro_export]
+macro_rules! assert_fails {
+    ($s:literal as $typ:ident) => {
+        const _: () = {
+            $crate::__priv::core::assert!(
+                $crate::__priv::parsers::$typ($s.as_bytes(), $crate::__priv::None).is_none(),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!($s),
+                    "` parses as a `",
+                    $crate::__priv::core::stringify!($typ),
+                    "`, but was expected to fail.",
+                ),
+            );
+        };
+    };
+
+    ($s:literal as $typ:ident => $err:ident) => {
+        $crate::assert_fails!($s as $typ);
+        const _: () = {
+            $crate::__priv::core::assert!(
+                $crate::__priv::core::matches!(
+                    $crate::__priv::diagnose::$typ($s.as_bytes()),
+                    $crate::__priv::Some($crate::parse::ParseError::$err { .. }),
+                ),
+                $crate::__priv::core::concat!(
+                    "error: `",
+                    $crate::__priv::core::stringify!($s),
+                    "` fails to parse as a `",
+                    $crate::__priv::core::stringify!($typ),
+                    "`, but not with `",
+                    $crate::__priv::core::stringify!($err),
+                    "`.",
+                ),
+            );
+        };
+    };
+}
+
+/// Checks at compile time that the values of two environment variables satisfy
+/// a relation, like `MIN <= MAX`, for configuration where one value constrains
+/// another. Both are parsed as the given integer type, and the relation can be
+/// any of `<`, `<=`, `==`, `!=`, `>=` or `>`. Like [`assert_parses!`], it
+/// expands to an item.
+///
+/// The check only happens if both variables are set (and not blank), so it
+/// works alongside `parse_env!` with `else` defaults. A value that fails to
+/// parse is an error either way.
+///
+/// ```
+/// envparse::assert_env_relation!("MYCRATE_MIN_THREADS" <= "MYCRATE_MAX_THREADS" as u32);
+/// ```
+///
+/// To check a relation that involves defaults, use `parse_env!` for both
+/// values, and compare the constants with an `assert!` in a `const _: () = ...`
+/// item instead.
+#[macro_export]
+macro_rules! assert_env_relation {
+    ($a:literal < $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, <);
+    };
+
+    ($a:literal <= $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, <=);
+    };
+
+    ($a:literal == $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, ==);
+    };
+
+    ($a:literal != $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, !=);
+    };
+
+    ($a:literal >= $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, >=);
+    };
+
+    ($a:literal > $b:literal as $typ:ident) => {
+        $crate::assert_env_relation!(@check $a, $b, $typ, >);
+    };
+
+    (@check $a:literal, $b:literal, $typ:ident, $op:tt) => {
+        const _: () = {
+            let a = $crate::assert_env_relation!(@parse $a, $typ);
+            let b = $crate::assert_env_relation!(@parse $b, $typ);
+            if let ($crate::__priv::Some(a), $crate::__priv::Some(b)) = (a, b) {
+                $crate::__priv::core::assert!(
+                    a $op b,
+                    $crate::__priv::core::concat!(
+                        "error: the values in `",
+                        $a,
+                        "` and `",
+                        $b,
+                        "` don't satisfy `",
+                        $a,
+                        " ",
+                        $crate::__priv::core::stringify!($op),
+                        " ",
+                        $b,
+                        "`.",
+                    ),
+                );
+            }
+        };
+    };
+
+    // Gives `None` if the variable is unset or blank.
+    (@parse $var_name:literal, $typ:ident) => {{
+        $crate::parse_env!(@check_name $var_name);
+        match $crate::__priv::core::option_env!($var_name) {
+            $crate::__priv::None => $crate::__priv::None,
+            $crate::__priv::Some(s) => match $crate::__priv::parsers::$typ(s.as_bytes(), $crate::__priv::None) {
+                $crate::__priv::Some(v) => $crate::__priv::Some(v),
+                $crate::__priv::None => match $crate::__priv::diagnose::$typ(s.as_bytes()) {
+                    $crate::__priv::Some($crate::parse::ParseError::Empty) => $crate::__priv::None,
+                    e => $crate::parse_env!(@fail $crate::__priv::fail_message(
+                        $crate::__priv::core::concat!(
+                            "error: the value in `",
+                            $var_name,
+                            "` doesn't parse as a `",
+                            $crate::__priv::core::stringify!($typ),
+                            "`",
+                        ),
+                        e,
+                        ", or is out of range.",
+                    )),
+                },
+            },
+        }
+    }};
+}
+
 pub mod parse;
@@ -1,10 +1,45 @@
 //! The raw parsers, provided for convenience, since perhaps you need a to use a
 //! const-compatible integer (or boolean, I suppose) parser for something other
 //! than environment variables.
+//!
+//! The integer parsers take their bounds as `u128` or `i128`. When given a
+//! type with no range, [`parse_env!`](crate::parse_env) uses that type's
+//! `MIN` and `MAX` widened to those, which are available in [`bounds`], so
+//! calling the parsers with them behaves exactly like the macro does.
+//!
+//! ```
+//! use envparse::parse::{bounds, parse_unsigned};
+//! let (min, max) = bounds::U8;
+//! assert_eq!(parse_unsigned(b"300", min, max, true), Ok(255));
+//! ```
+
+/// The inclusive `(min, max)` bounds used for each integer type, widened to
+/// the `u128` or `i128` that [`parse_unsigned`] and [`parse_signed`] take.
+///
+/// These are just `T::MIN` and `T::MAX`. A range given to
+/// [`parse_env!`](crate::parse_env) narrows them further.
+pub mod bounds {
+    macro_rules! def_bounds {
+        ($($name:ident = $t:ident as $wide:ident),* $(,)?) => {$(
+            #[doc = concat!("The bounds of `", stringify!($t), "`.")]
+            pub const $name: ($wide, $wide) = ($t::MIN as $wide, $t::MAX as $wide);
+        )*};
+    }
+
+    def_bounds! {
+        USIZE = usize as u128, U8 = u8 as u128, U16 = u16 as u128,
+        U32 = u32 as u128, U64 = u64 as u128, U128 = u128 as u128,
+        ISIZE = isize as i128, I8 = i8 as i128, I16 = i16 as i128,
+        I32 = i32 as i128, I64 = i64 as i128, I128 = i128 as i128,
+    }
+}
 
 /// Indicates failure to parse something. Because the parsers are generally
 /// parsing integers numbers, that's what these errors focus on. See
 /// [`parse_unsigned`] and [`parse_signed`] for more information.
+///
+/// New variants may be added in minor releases. If you need to match on these
+/// without a wildcard arm, use [`ParseError::kind`] instead.
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ParseError {
@@ -15,7 +50,12 @@ pub enum ParseError {
     UnexpectedSign,
 
     /// Got an invalid digit for the base.
-    InvalidDigit,
+    InvalidDigit {
+        /// The offending byte, as it appeared in the input.
+        digit: u8,
+        /// The base that was in effect (2, 8, 10 or 16).
+        radix: u32,
+    },
 
     /// Got no digits, e.g. a string like `"-"`, `"0x"`, etc.
     NoDigits,
@@ -29,6 +69,189 @@ pub enum ParseError {
 
     /// Got something that doesn't seem to indicate a boolean.
     UnknownBoolValue,
+
+    /// Got a word (or list item) that isn't in the expected vocabulary.
+    UnknownToken,
+
+    /// Got a ratio with a denominator of zero.
+    ZeroDenominator,
+
+    /// Expected a single character, but got more than one (or invalid UTF-8).
+    NotAChar,
+
+    /// Got something that isn't four `.`-separated numbers from 0 to 255.
+    InvalidIpv4Addr,
+
+    /// Got something that isn't three `.`-separated numbers (see
+    /// [`parse_version`]).
+    InvalidVersion,
+
+    /// Got a rate without a `/` and a time unit after the size.
+    MissingRateUnit,
+
+    /// Got a rate whose time unit isn't one of the ones we accept (see
+    /// [`parse_rate`]).
+    UnknownRateUnit,
+
+    /// Got a duration without a time unit where one was needed.
+    MissingTimeUnit,
+
+    /// Got a duration with a time unit that isn't one of the ones we accept
+    /// (see [`parse_duration`]).
+    UnknownTimeUnit,
+
+    /// Got a number without the `0x` prefix, where one was required.
+    MissingHexPrefix,
+
+    /// Got a different number of something than required, like the digits in
+    /// [`parse_hex_digits`] or the repeat count in [`parse_fill`].
+    WrongCount {
+        /// The count required.
+        expected: usize,
+        /// The count found.
+        found: usize,
+    },
+
+    /// Got an unknown escape sequence in a quoted string, where `byte` is the
+    /// one after the `\`.
+    InvalidEscape {
+        /// The offending byte, as it appeared in the input.
+        byte: u8,
+    },
+
+    /// Got a quoted string which doesn't end with its closing `"`.
+    UnclosedQuote,
+
+    /// Got something other than whitespace after the closing `"` of a quoted
+    /// string.
+    TextAfterQuote,
+
+    /// Got a code point in the surrogate range (`U+D800` to `U+DFFF`), which
+    /// isn't a valid `char` (see [`parse_char_code`]).
+    SurrogateCodePoint,
+
+    /// Got an argument after a value that doesn't take one, like `off:5` (see
+    /// [`split_flag`]).
+    UnexpectedArgument,
+
+    /// Got something other than a non-empty range like `10..100` (see
+    /// [`split_range`]).
+    InvalidRange,
+
+    /// Got more digits after the decimal point than can be represented (see
+    /// [`parse_basis_points`]).
+    TooManyDecimals {
+        /// The most digits allowed after the decimal point.
+        max: usize,
+    },
+
+    /// Got an operator that isn't supported (see [`parse_relative`]).
+    UnknownOperator {
+        /// The offending byte, as it appeared in the input.
+        op: u8,
+    },
+}
+
+/// A coarse, stable classification of a [`ParseError`], from
+/// [`ParseError::kind`].
+///
+/// Unlike `ParseError`, this enum is exhaustive, and we won't add variants to
+/// it outside of a semver-breaking release, so it's safe to `match` on without
+/// a wildcard arm. New `ParseError` variants will be mapped to one of these.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The value was empty or whitespace-only.
+    Empty,
+
+    /// The value isn't in the expected format (bad digits, unknown words, a
+    /// missing prefix or unit, and so on).
+    Invalid,
+
+    /// The value is well-formed, but too large or small for what was asked.
+    OutOfRange,
+}
+
+impl ParseError {
+    /// Returns the [`ErrorKind`] for this error, for code that wants to handle
+    /// broad classes of failure without matching on every variant.
+    ///
+    /// ```
+    /// use envparse::parse::{parse_unsigned, ErrorKind};
+    /// assert_eq!(parse_unsigned(b"300", 0, 255, false).unwrap_err().kind(), ErrorKind::OutOfRange);
+    /// assert_eq!(parse_unsigned(b"x", 0, 255, false).unwrap_err().kind(), ErrorKind::Invalid);
+    /// ```
+    pub const fn kind(&self) -> ErrorKind {
+        match *self {
+            ParseError::Empty => ErrorKind::Empty,
+            ParseError::IntOverflow | ParseError::OutOfRange => ErrorKind::OutOfRange,
+            ParseError::UnexpectedSign
+            | ParseError::InvalidDigit { .. }
+            | ParseError::NoDigits
+            | ParseError::UnknownBoolValue
+            | ParseError::UnknownToken
+            | ParseError::ZeroDenominator
+            | ParseError::NotAChar
+            | ParseError::InvalidIpv4Addr
+            | ParseError::InvalidVersion
+            | ParseError::MissingRateUnit
+            | ParseError::UnknownRateUnit
+            | ParseError::MissingTimeUnit
+            | ParseError::UnknownTimeUnit
+            | ParseError::MissingHexPrefix
+            | ParseError::WrongCount { .. }
+            | ParseError::InvalidEscape { .. }
+            | ParseError::UnclosedQuote
+            | ParseError::TextAfterQuote
+            | ParseError::SurrogateCodePoint
+            | ParseError::UnexpectedArgument
+            | ParseError::TooManyDecimals { .. }
+            | ParseError::InvalidRange
+            | ParseError::UnknownOperator { .. } => ErrorKind::Invalid,
+        }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            ParseError::Empty => f.write_str("empty or whitespace-only value"),
+            ParseError::UnexpectedSign => f.write_str("unexpected `-` sign on an unsigned number"),
+            ParseError::InvalidDigit { digit, radix } => {
+                write!(f, "invalid digit `{}` for base {}", core::ascii::escape_default(digit), radix)
+            }
+            ParseError::NoDigits => f.write_str("no digits found"),
+            ParseError::IntOverflow => f.write_str("integer overflow"),
+            ParseError::OutOfRange => f.write_str("value is out of range"),
+            ParseError::UnknownBoolValue => f.write_str("unrecognized boolean value"),
+            ParseError::UnknownToken => f.write_str("unrecognized word"),
+            ParseError::ZeroDenominator => f.write_str("denominator is zero"),
+            ParseError::NotAChar => f.write_str("expected a single character"),
+            ParseError::InvalidIpv4Addr => f.write_str("invalid IPv4 address"),
+            ParseError::InvalidVersion => f.write_str("invalid version"),
+            ParseError::MissingRateUnit => f.write_str("missing `/` and time unit"),
+            ParseError::UnknownRateUnit => f.write_str("unrecognized time unit in rate"),
+            ParseError::MissingTimeUnit => f.write_str("missing time unit"),
+            ParseError::UnknownTimeUnit => f.write_str("unrecognized time unit"),
+            ParseError::MissingHexPrefix => f.write_str("missing `0x` prefix"),
+            ParseError::WrongCount { expected, found } => {
+                write!(f, "expected a count of {}, found {}", expected, found)
+            }
+            ParseError::InvalidEscape { byte } => {
+                write!(f, "invalid escape `\\{}`", core::ascii::escape_default(byte))
+            }
+            ParseError::UnclosedQuote => f.write_str("missing closing `\"`"),
+            ParseError::TextAfterQuote => f.write_str("unexpected text after closing `\"`"),
+            ParseError::SurrogateCodePoint => f.write_str("surrogate code point"),
+            ParseError::UnexpectedArgument => f.write_str("unexpected argument"),
+            ParseError::InvalidRange => f.write_str("invalid range"),
+            ParseError::TooManyDecimals { max } => {
+                write!(f, "more than {} digits after the decimal point", max)
+            }
+            ParseError::UnknownOperator { op } => {
+                write!(f, "unknown operator `{}`", core::ascii::escape_default(op))
+            }
+        }
+    }
 }
 
 pub(crate) const fn number_parse(s: &[u8], skip_sign: bool) -> Result<(u128, bool), ParseError> {
@@ -65,12 +288,12 @@ pub(crate) const fn number_parse(s: &[u8], skip_sign: bool) -> Result<(u128, boo
     while pos < end {
         let d = s[pos];
         pos += 1;
-        let value = match (d, radix) {
-            (b'0'..=b'1', 2) | (b'0'..=b'7', 8) | (b'0'..=b'9', 10 | 16) => (d - b'0') as u128,
-            (b'a'..=b'f', 16) => (d - b'a') as u128 + 10,
-            (b'A'..=b'F', 16) => (d - b'A') as u128 + 10,
-            (b'_', _) => continue,
-            _ => return Err(ParseError::InvalidDigit),
+        if d == b'_' {
+            continue;
+        }
+        let value = match hex_digit(d) {
+            Some(v) if (v as u128) < radix => v as u128,
+            _ => return Err(ParseError::InvalidDigit { digit: d, radix: radix as u32 }),
         };
         ever_saw_digits = true;
         match accum.checked_mul(radix) {
@@ -88,7 +311,7 @@ pub(crate) const fn number_parse(s: &[u8], skip_sign: bool) -> Result<(u128, boo
     }
 }
 
-const fn trim_ws(s: &[u8]) -> Option<(usize, usize)> {
+pub(crate) const fn trim_ws(s: &[u8]) -> Option<(usize, usize)> {
     let mut start = 0;
     if s.is_empty() || s.len() <= start {
         return None;
@@ -111,6 +334,192 @@ const fn trim_ws(s: &[u8]) -> Option<(usize, usize)> {
     }
 }
 
+/// Checks that the number in `s` is written in hex, with a `0x` (or `0X`)
+/// prefix after any sign, without checking the digits themselves.
+///
+/// This is for values like addresses and masks, where `deadbeef` or `123`
+/// without the prefix is more likely a mistake than something to read in
+/// decimal. Returns [`ParseError::MissingHexPrefix`] if the prefix is absent.
+pub const fn require_hex_prefix(s: &[u8]) -> Result<(), ParseError> {
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    if s[pos] == b'-' || s[pos] == b'+' {
+        pos += 1;
+    }
+    if pos + 2 <= end && s[pos] == b'0' && (s[pos + 1] == b'x' || s[pos + 1] == b'X') {
+        Ok(())
+    } else {
+        Err(ParseError::MissingHexPrefix)
+    }
+}
+
+/// Parses exactly `digits` hex digits, optionally preceded by `0x` (or `0X`),
+/// for fixed-width values like short commit hashes.
+///
+/// Unlike [`parse_unsigned`], underscores and signs aren't allowed, since they
+/// would make the digit count ambiguous. Returns [`ParseError::WrongCount`] if
+/// there are more or fewer digits than requested, and [`ParseError::OutOfRange`] if the value is above `incl_max`.
+pub const fn parse_hex_digits(s: &[u8], digits: usize, incl_max: u128) -> Result<u128, ParseError> {
+    let (mut start, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    if start + 2 <= end && s[start] == b'0' && (s[start + 1] == b'x' || s[start + 1] == b'X') {
+        start += 2;
+    }
+    if end - start != digits {
+        return Err(ParseError::WrongCount { expected: digits, found: end - start });
+    }
+    let mut accum = 0u128;
+    let mut pos = start;
+    while pos < end {
+        let d = s[pos];
+        let value = match hex_digit(d) {
+            Some(v) => v,
+            None => return Err(ParseError::InvalidDigit { digit: d, radix: 16 }),
+        };
+        accum = match accum.checked_mul(16) {
+            Some(v) => v | value as u128,
+            None => return Err(ParseError::IntOverflow),
+        };
+        pos += 1;
+    }
+    if accum > incl_max {
+        return Err(ParseError::OutOfRange);
+    }
+    Ok(accum)
+}
+
+/// Parses `value*count` into an array of `count` copies of the byte `value`,
+/// for filling buffers with a test pattern or poison value, as in `0xAA*16`.
+///
+/// Both operands are parsed like [`parse_unsigned`], and whitespace around
+/// either is ignored. A `value` on its own has a count of one. Returns
+/// [`ParseError::WrongCount`] if the count isn't `N`, and
+/// [`ParseError::OutOfRange`] if the value doesn't fit in a `u8`.
+///
+/// ```
+/// use envparse::parse::{parse_fill, ParseError};
+/// assert_eq!(parse_fill::<4>(b"0xAA*4"), Ok([0xaa; 4]));
+/// assert_eq!(parse_fill::<4>(b"7 * 0b100"), Ok([7; 4]));
+/// assert_eq!(parse_fill::<4>(b"0xAA*3"), Err(ParseError::WrongCount { expected: 4, found: 3 }));
+/// ```
+pub const fn parse_fill<const N: usize>(s: &[u8]) -> Result<[u8; N], ParseError> {
+    let (value, count) = match split_once(s, b'*') {
+        Some(tup) => tup,
+        None => (s, b"1".as_slice()),
+    };
+    let value = match number_parse(value, false) {
+        Ok((v, _)) if v <= u8::MAX as u128 => v as u8,
+        Ok(_) => return Err(ParseError::OutOfRange),
+        Err(e) => return Err(e),
+    };
+    let count = match number_parse(count, false) {
+        Ok((c, _)) => c,
+        Err(e) => return Err(e),
+    };
+    if count != N as u128 {
+        let found = if count > usize::MAX as u128 { usize::MAX } else { count as usize };
+        return Err(ParseError::WrongCount { expected: N, found });
+    }
+    Ok([value; N])
+}
+
+/// The value of a single hex digit, ignoring case.
+const fn hex_digit(d: u8) -> Option<u8> {
+    match d {
+        b'0'..=b'9' => Some(d - b'0'),
+        b'a'..=b'f' => Some(d - b'a' + 10),
+        b'A'..=b'F' => Some(d - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses a string into a zero-padded `[u8; N]`, returning the buffer and the
+/// number of bytes used, for embedding short strings where there's no heap.
+/// The bytes are the UTF-8 of the string, as-is.
+///
+/// If the first non-whitespace byte is `"`, the value is a quoted string: the
+/// whitespace outside of the quotes is ignored, and inside them, `\n`, `\r`,
+/// `\t`, `\0`, `\\`, `\"`, `\'` and `\xNN` (up to `\x7F`) escapes are
+/// allowed, as in Rust. Otherwise, the value is used unchanged, including any
+/// whitespace.
+///
+/// Returns [`ParseError::OutOfRange`] if the string is longer than `N` bytes,
+/// [`ParseError::InvalidEscape`] for an unknown escape,
+/// [`ParseError::UnclosedQuote`] if a quoted string doesn't end with a `"`, and
+/// [`ParseError::TextAfterQuote`] if anything but whitespace follows it.
+///
+/// ```
+/// use envparse::parse::{parse_strbuf, ParseError};
+/// assert_eq!(parse_strbuf::<4>(b"hi"), Ok((*b"hi\0\0", 2)));
+/// assert_eq!(parse_strbuf::<4>(br#" "a\tb" "#), Ok((*b"a\tb\0", 3)));
+/// assert_eq!(parse_strbuf::<4>(b"hello"), Err(ParseError::OutOfRange));
+/// ```
+pub const fn parse_strbuf<const N: usize>(s: &[u8]) -> Result<([u8; N], usize), ParseError> {
+    let mut out = [0u8; N];
+    let (mut pos, end) = match trim_ws(s) {
+        Some((start, end)) if s[start] == b'"' => (start + 1, end),
+        _ => {
+            if s.len() > N {
+                return Err(ParseError::OutOfRange);
+            }
+            let mut i = 0;
+            while i < s.len() {
+                out[i] = s[i];
+                i += 1;
+            }
+            return Ok((out, s.len()));
+        }
+    };
+    let mut len = 0;
+    loop {
+        if pos == end {
+            return Err(ParseError::UnclosedQuote);
+        }
+        let mut b = s[pos];
+        pos += 1;
+        if b == b'"' {
+            if pos != end {
+                return Err(ParseError::TextAfterQuote);
+            }
+            break;
+        }
+        if b == b'\\' {
+            if pos == end {
+                return Err(ParseError::UnclosedQuote);
+            }
+            let e = s[pos];
+            pos += 1;
+            b = match e {
+                b'n' => b'\n',
+                b'r' => b'\r',
+                b't' => b'\t',
+                b'0' => 0,
+                b'\\' | b'"' | b'\'' => e,
+                b'x' if pos + 2 <= end => match (hex_digit(s[pos]), hex_digit(s[pos + 1])) {
+                    (Some(hi), Some(lo)) if hi < 8 => {
+                        pos += 2;
+                        hi << 4 | lo
+                    }
+                    _ => return Err(ParseError::InvalidEscape { byte: e }),
+                },
+                _ => return Err(ParseError::InvalidEscape { byte: e }),
+            };
+        }
+        if len < N {
+            out[len] = b;
+        }
+        len += 1;
+    }
+    if len > N {
+        return Err(ParseError::OutOfRange);
+    }
+    Ok((out, len))
+}
+
 /// Parse a `u128` from a byte slice in const.
 ///
 /// Case-insensitive, ignores leading and trailing whitespace, supports internal
@@ -124,7 +533,12 @@ pub const fn parse_unsigned(s: &[u8], incl_min: u128, incl_max: u128, clamp: boo
         Ok((n, _)) => n,
         Err(e) => match e {
             ParseError::IntOverflow if clamp => incl_max,
-            ParseError::UnexpectedSign if clamp => incl_min,
+            // Only clamp things which are otherwise numbers, so `-five` is
+            // still an error.
+            ParseError::UnexpectedSign if clamp => match number_parse(s, true) {
+                Ok(_) | Err(ParseError::IntOverflow) => incl_min,
+                Err(e) => return Err(e),
+            },
             e => return Err(e),
         },
     };
@@ -137,20 +551,22 @@ pub const fn parse_unsigned(s: &[u8], incl_min: u128, incl_max: u128, clamp: boo
     Ok(val)
 }
 
-/// Like [`parse_unsigned`] but for signed numbers, returning a `i128`.
-///
-/// See [Syntax](mod@super#syntax) for information on what strings this
-/// function accepts.
-pub const fn parse_signed(s: &[u8], incl_min: i128, incl_max: i128, clamp: bool) -> Result<i128, ParseError> {
-    const I128_MIN_MAGNITUDE: u128 = (i128::MAX as u128) + 1;
-    let val = match number_parse(s, true) {
-        Ok((n, true)) if n == I128_MIN_MAGNITUDE => i128::MIN,
-        Ok((n, true)) if n <= (i128::MAX as u128) => -(n as i128),
-        Ok((_, true)) if clamp => incl_min,
-        Ok((n, false)) if n <= (i128::MAX as u128) => n as i128,
-        Ok((_, false)) if clamp => incl_max,
-        Ok((_, _)) => return Err(ParseError::OutOfRange),
-        // Err(ParseError::IntOverflow) =>
+/// Parses a count of pages of `page_size` bytes each, using the same syntax
+/// as [`parse_unsigned`], and returns the number of bytes. The bounds apply to
+/// the byte count, rather than the number of pages.
+pub const fn parse_pages(
+    s: &[u8],
+    page_size: u128,
+    incl_min: u128,
+    incl_max: u128,
+    clamp: bool,
+) -> Result<u128, ParseError> {
+    let val = match parse_unsigned(s, 0, u128::MAX, clamp) {
+        Ok(n) => match n.checked_mul(page_size) {
+            Some(v) => v,
+            None if clamp => incl_max,
+            None => return Err(ParseError::IntOverflow),
+        },
         Err(e) => return Err(e),
     };
     if val < incl_min {
@@ -162,35 +578,751 @@ pub const fn parse_signed(s: &[u8], incl_min: i128, incl_max: i128, clamp: bool)
     Ok(val)
 }
 
-/// Parses a boolean from a byte slice.
+/// Parses a number relative to `base`, written as `N`, or `N` followed by one
+/// of `+`, `-`, `*` or `/` and an unsigned integer, as in `N-1` or `N/2`
+/// (ignoring case and whitespace). Anything else is parsed like
+/// [`parse_unsigned`], as a plain number.
 ///
-/// Case-insensitive, ignores leading and trailing whitespace, and accepts
-/// `"0"`, `"f"`, `"n"`, `"no"`, `"off"`, and `"false"` for `false`, and `"1"`,
-/// `"t"`, `"y"`, `"on"`, `"yes"`, and `"true"` for `true`.
+/// The arithmetic saturates, so `N-8` with a `base` of 4 is 0, and the result
+/// is at most `incl_max`, to fit the type it's for. Dividing by zero returns
+/// [`ParseError::ZeroDenominator`], and another operator returns
+/// [`ParseError::UnknownOperator`]. A plain number above `incl_max` is an
+/// error, as usual.
 ///
-/// See [Syntax](mod@super#syntax) for information on what strings this
-/// function accepts.
-pub const fn parse_bool(s: &[u8]) -> Result<bool, ParseError> {
-    let (i, e) = match trim_ws(s) {
+/// ```
+/// use envparse::parse::{parse_relative, ParseError};
+/// assert_eq!(parse_relative(b"N-1", 8, u32::MAX as u128), Ok(7));
+/// assert_eq!(parse_relative(b"n / 2", 8, u32::MAX as u128), Ok(4));
+/// assert_eq!(parse_relative(b"3", 8, u32::MAX as u128), Ok(3));
+/// assert_eq!(parse_relative(b"N%2", 8, u32::MAX as u128), Err(ParseError::UnknownOperator { op: b'%' }));
+/// ```
+pub const fn parse_relative(s: &[u8], base: u128, incl_max: u128) -> Result<u128, ParseError> {
+    let (mut pos, end) = match trim_ws(s) {
         Some(tup) => tup,
         None => return Err(ParseError::Empty),
     };
-    let len = e.saturating_sub(i);
-    match len {
-        0 => Err(ParseError::Empty),
-        // All these are case insensitive.
-        //
-        // The bool syntax accepted is similar to what `rustc` accepts for `-C`
-        // and `-Z` flags, although a few single-char values are allowed ("1" |
-        // "t" | "y" for true, and "0" | "n" | "f" for false)
-        1 => match s[i] {
-            // "1"/"0" | "t"/"f" | "y/n"
-            b'1' | b't' | b'T' | b'y' | b'Y' => Ok(true),
-            b'0' | b'f' | b'F' | b'n' | b'N' => Ok(false),
-            _ => Err(ParseError::UnknownBoolValue),
+    if s[pos] != b'N' && s[pos] != b'n' {
+        return parse_unsigned(s, 0, incl_max, false);
+    }
+    pos += 1;
+    while pos < end && s[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    let val = if pos == end {
+        base
+    } else {
+        let op = s[pos];
+        if !matches!(op, b'+' | b'-' | b'*' | b'/') {
+            return Err(ParseError::UnknownOperator { op });
+        }
+        // The operand can't have a sign of its own, and saturates like the
+        // result does.
+        let rest = subslice(s, pos + 1, end);
+        let k = match number_parse(rest, false) {
+            Ok((k, _)) => k,
+            Err(ParseError::IntOverflow) => u128::MAX,
+            Err(ParseError::Empty) => return Err(ParseError::NoDigits),
+            Err(e) => return Err(e),
+        };
+        match op {
+            b'+' => base.saturating_add(k),
+            b'-' => base.saturating_sub(k),
+            b'*' => base.saturating_mul(k),
+            _ if k == 0 => return Err(ParseError::ZeroDenominator),
+            _ => base / k,
+        }
+    };
+    Ok(if val > incl_max { incl_max } else { val })
+}
+
+/// Parses a file permission mode like `"644"` or `"0o755"` as octal, even
+/// without a prefix, as `chmod` does. An optional leading `0` or `0o` (or
+/// `0O`) is allowed, as are `_` separators, and the result must be at most
+/// `0o7777` (otherwise [`ParseError::OutOfRange`] is returned). A digit that
+/// isn't octal is reported as [`ParseError::InvalidDigit`] with a radix of 8.
+///
+/// ```
+/// use envparse::parse::{parse_mode, ParseError};
+/// assert_eq!(parse_mode(b"644"), Ok(0o644));
+/// assert_eq!(parse_mode(b"0755"), Ok(0o755));
+/// assert_eq!(parse_mode(b"0o1777"), Ok(0o1777));
+/// assert_eq!(parse_mode(b"0o999"), Err(ParseError::InvalidDigit { digit: b'9', radix: 8 }));
+/// assert_eq!(parse_mode(b"17777"), Err(ParseError::OutOfRange));
+/// ```
+pub const fn parse_mode(s: &[u8]) -> Result<u32, ParseError> {
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    if pos + 2 <= end && s[pos] == b'0' && (s[pos + 1] == b'o' || s[pos + 1] == b'O') {
+        pos += 2;
+    }
+    let mut val = 0u32;
+    let mut saw_digits = false;
+    while pos < end {
+        let d = s[pos];
+        pos += 1;
+        match d {
+            b'0'..=b'7' => {
+                saw_digits = true;
+                val = val * 8 + (d - b'0') as u32;
+                if val > 0o7777 {
+                    return Err(ParseError::OutOfRange);
+                }
+            }
+            b'_' => {}
+            _ => return Err(ParseError::InvalidDigit { digit: d, radix: 8 }),
+        }
+    }
+    if !saw_digits {
+        return Err(ParseError::NoDigits);
+    }
+    Ok(val)
+}
+
+/// Like [`parse_signed`], but accepts an optional `deg` (case-insensitive) or
+/// `°` suffix, e.g. `"90deg"` or `"-40°"`. The suffix is purely decorative: the
+/// result is the number in front of it.
+pub const fn parse_degrees(s: &[u8], incl_min: i128, incl_max: i128, clamp: bool) -> Result<i128, ParseError> {
+    let (start, mut end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    if end - start >= 3 && eq_ignore_case(s, end - 3, end, b"deg") {
+        end -= 3;
+    } else if end - start >= 2 && s[end - 2] == 0xc2 && s[end - 1] == 0xb0 {
+        // "°" in UTF-8.
+        end -= 2;
+    }
+    match parse_signed(subslice(s, start, end), incl_min, incl_max, clamp) {
+        Err(ParseError::Empty) => Err(ParseError::NoDigits),
+        res => res,
+    }
+}
+
+/// Parses a decimal fraction like `"0.0325"` into basis points (hundredths of a
+/// percent), so the result is the value times 10000, e.g. `325`.
+///
+/// The number is in base 10 with an optional sign, and at most 4 digits after
+/// the `.` (otherwise [`ParseError::TooManyDecimals`] is returned, unless the
+/// extra digits are all zeros). Either side of the `.` may be left out, but not
+/// both, so `"1"`, `"1."` and `".5"` are all fine. The bounds apply to the
+/// result, in basis points.
+///
+/// ```
+/// use envparse::parse::{parse_basis_points, ParseError};
+/// assert_eq!(parse_basis_points(b"0.0325", i64::MIN as i128, i64::MAX as i128, false), Ok(325));
+/// assert_eq!(parse_basis_points(b"-1.5", i64::MIN as i128, i64::MAX as i128, false), Ok(-15000));
+/// assert_eq!(
+///     parse_basis_points(b"0.03255", i64::MIN as i128, i64::MAX as i128, false),
+///     Err(ParseError::TooManyDecimals { max: 4 }),
+/// );
+/// ```
+pub const fn parse_basis_points(s: &[u8], incl_min: i128, incl_max: i128, clamp: bool) -> Result<i128, ParseError> {
+    const SCALE: u128 = 10_000;
+    const DECIMALS: usize = 4;
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let neg = s[pos] == b'-';
+    if s[pos] == b'-' || s[pos] == b'+' {
+        pos += 1;
+    }
+    let (mut whole, mut frac, mut scale) = (0u128, 0u128, SCALE);
+    let (mut digits, mut decimals, mut overflow, mut seen_dot) = (0, 0, false, false);
+    while pos < end {
+        let c = s[pos];
+        if c == b'.' && !seen_dot {
+            seen_dot = true;
+        } else if !c.is_ascii_digit() {
+            return Err(ParseError::InvalidDigit { digit: c, radix: 10 });
+        } else if !seen_dot {
+            digits += 1;
+            match whole.checked_mul(10) {
+                Some(v) if v <= u128::MAX / SCALE - 9 => whole = v + (c - b'0') as u128,
+                _ => overflow = true,
+            }
+        } else {
+            digits += 1;
+            decimals += 1;
+            if decimals <= DECIMALS {
+                scale /= 10;
+                frac += (c - b'0') as u128 * scale;
+            } else if c != b'0' {
+                return Err(ParseError::TooManyDecimals { max: DECIMALS });
+            }
+        }
+        pos += 1;
+    }
+    if digits == 0 {
+        return Err(ParseError::NoDigits);
+    }
+    let mag = whole * SCALE + frac;
+    let val = if overflow || mag > i128::MAX as u128 {
+        if !clamp {
+            return Err(ParseError::IntOverflow);
+        }
+        if neg {
+            incl_min
+        } else {
+            incl_max
+        }
+    } else if neg {
+        -(mag as i128)
+    } else {
+        mag as i128
+    };
+    if val < incl_min {
+        return if clamp { Ok(incl_min) } else { Err(ParseError::OutOfRange) };
+    }
+    if val > incl_max {
+        return if clamp { Ok(incl_max) } else { Err(ParseError::OutOfRange) };
+    }
+    Ok(val)
+}
+
+/// Parses a decimal number like `"1.5"` into a signed fixed-point integer in Q
+/// format with `int_bits` integer bits (including the sign bit) and
+/// `frac_bits` fractional bits, so the result is the value times
+/// `2^frac_bits`, e.g. `1.5` in Q16.16 is `0x18000`.
+///
+/// This is computed exactly with integer math, then rounded to the nearest
+/// representable value, with ties rounded away from zero. The number is in
+/// base 10 with an optional sign, and either side of the `.` may be left out,
+/// but not both. Up to 38 digits after the `.` are used, and more than that
+/// gives [`ParseError::TooManyDecimals`] (unless the extra digits are zeros).
+///
+/// The result must fit in a signed integer of `int_bits + frac_bits` bits, or
+/// [`ParseError::OutOfRange`] is returned. See [`parse_qfixed_clamp`] for a
+/// version which saturates instead.
+///
+/// # Panics
+///
+/// If `int_bits + frac_bits` is 0 or more than 128.
+///
+/// ```
+/// use envparse::parse::{parse_qfixed, ParseError};
+/// assert_eq!(parse_qfixed(b"1.5", 16, 16), Ok(0x0001_8000));
+/// assert_eq!(parse_qfixed(b"-0.25", 1, 15), Ok(-0x2000));
+/// assert_eq!(parse_qfixed(b"1", 1, 15), Err(ParseError::OutOfRange));
+/// ```
+pub const fn parse_qfixed(s: &[u8], int_bits: u32, frac_bits: u32) -> Result<i128, ParseError> {
+    parse_qfixed_impl(s, int_bits, frac_bits, false)
+}
+
+/// Like [`parse_qfixed`], but saturates to the most negative or most positive
+/// value of the format when the result doesn't fit, rather than failing.
+///
+/// ```
+/// use envparse::parse::parse_qfixed_clamp;
+/// assert_eq!(parse_qfixed_clamp(b"1", 1, 15), Ok(0x7fff));
+/// assert_eq!(parse_qfixed_clamp(b"-3", 1, 15), Ok(-0x8000));
+/// ```
+pub const fn parse_qfixed_clamp(s: &[u8], int_bits: u32, frac_bits: u32) -> Result<i128, ParseError> {
+    parse_qfixed_impl(s, int_bits, frac_bits, true)
+}
+
+const fn parse_qfixed_impl(s: &[u8], int_bits: u32, frac_bits: u32, clamp: bool) -> Result<i128, ParseError> {
+    // The most fractional digits we keep, so that `10^DECIMALS` (and twice
+    // the remainder below it) fits in a `u128`.
+    const DECIMALS: usize = 38;
+    let total = int_bits as u64 + frac_bits as u64;
+    assert!(total >= 1 && total <= 128, "the Q format must have from 1 to 128 bits");
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let neg = s[pos] == b'-';
+    if s[pos] == b'-' || s[pos] == b'+' {
+        pos += 1;
+    }
+    // The value is `whole + num / den`.
+    let (mut whole, mut num, mut den) = (0u128, 0u128, 1u128);
+    let (mut digits, mut decimals, mut overflow, mut seen_dot) = (0, 0, false, false);
+    while pos < end {
+        let c = s[pos];
+        if c == b'.' && !seen_dot {
+            seen_dot = true;
+        } else if !c.is_ascii_digit() {
+            return Err(ParseError::InvalidDigit { digit: c, radix: 10 });
+        } else if !seen_dot {
+            digits += 1;
+            match whole.checked_mul(10) {
+                Some(v) if v <= u128::MAX - 9 => whole = v + (c - b'0') as u128,
+                _ => overflow = true,
+            }
+        } else {
+            digits += 1;
+            decimals += 1;
+            if decimals <= DECIMALS {
+                num = num * 10 + (c - b'0') as u128;
+                den *= 10;
+            } else if c != b'0' {
+                return Err(ParseError::TooManyDecimals { max: DECIMALS });
+            }
+        }
+        pos += 1;
+    }
+    if digits == 0 {
+        return Err(ParseError::NoDigits);
+    }
+    // Binary long division of `num / den`, one fractional bit at a time, then
+    // round to nearest with ties away from zero, based on what's left.
+    let mut frac = 0u128;
+    let mut i = 0;
+    while i < frac_bits {
+        num *= 2;
+        frac <<= 1;
+        if num >= den {
+            num -= den;
+            frac |= 1;
+        }
+        i += 1;
+    }
+    let round_up = num * 2 >= den;
+    // The largest magnitude for each sign: `2^(total - 1)` when negative, and
+    // one less when positive.
+    let max_mag = (1u128 << (total - 1)) - if neg { 0 } else { 1 };
+    let mag = if overflow || frac_bits >= 128 && whole != 0 || frac_bits < 128 && whole > u128::MAX >> frac_bits {
+        None
+    } else {
+        let shifted = if frac_bits >= 128 { 0 } else { whole << frac_bits };
+        shifted.checked_add(frac + round_up as u128)
+    };
+    let mag = match mag {
+        Some(m) if m <= max_mag => m,
+        _ if clamp => max_mag,
+        _ => return Err(ParseError::OutOfRange),
+    };
+    Ok(if neg { (mag as i128).wrapping_neg() } else { mag as i128 })
+}
+
+/// Like [`parse_unsigned`], but accepts an optional case-sensitive suffix where
+/// lowercase letters are SI (powers of 1000) and uppercase letters are binary
+/// (powers of 1024) multipliers, e.g. `"4k"` is 4000 and `"4K"` is 4096.
+///
+/// This deliberately breaks with the case-insensitivity used everywhere else,
+/// for compatibility with tools that use this convention. The bounds apply to
+/// the value after multiplying.
+///
+/// See [Syntax](mod@super#syntax) for the full
+/// table of suffixes.
+pub const fn parse_cased_size(s: &[u8], incl_min: u128, incl_max: u128, clamp: bool) -> Result<u128, ParseError> {
+    let (start, mut end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let mult: u128 = match s[end - 1] {
+        b'k' => 1_000,
+        b'm' => 1_000_000,
+        b'g' => 1_000_000_000,
+        b't' => 1_000_000_000_000,
+        b'p' => 1_000_000_000_000_000,
+        b'K' => 1 << 10,
+        b'M' => 1 << 20,
+        b'G' => 1 << 30,
+        b'T' => 1 << 40,
+        b'P' => 1 << 50,
+        _ => 1,
+    };
+    if mult != 1 {
+        end -= 1;
+    }
+    let val = match parse_unsigned(subslice(s, start, end), 0, u128::MAX, clamp) {
+        Ok(n) => match n.checked_mul(mult) {
+            Some(v) => v,
+            None if clamp => incl_max,
+            None => return Err(ParseError::IntOverflow),
         },
-        2 => match (s[i], s[i + 1]) {
-            // "no"
+        Err(ParseError::Empty) => return Err(ParseError::NoDigits),
+        Err(e) => return Err(e),
+    };
+    if val < incl_min {
+        return if clamp { Ok(incl_min) } else { Err(ParseError::OutOfRange) };
+    }
+    if val > incl_max {
+        return if clamp { Ok(incl_max) } else { Err(ParseError::OutOfRange) };
+    }
+    Ok(val)
+}
+
+/// Parses a throughput like `"10MB/s"`, returning it in bytes per second.
+///
+/// The size before the `/` uses the syntax of [`parse_cased_size`], optionally
+/// followed by `B`, so `"10MB/s"` and `"10M/s"` are both `10 << 20`. After the
+/// `/` comes one of the time units `ms`, `s`, `min` or `h` (case-insensitive).
+/// Note that minutes are `min` here, where [`parse_duration`] uses Go's `m`.
+/// Rates per minute or hour are rounded down to whole bytes per second. The
+/// bounds apply to the value in bytes per second.
+///
+/// Returns [`ParseError::MissingRateUnit`] if there's no `/`, and
+/// [`ParseError::UnknownRateUnit`] if the unit after it isn't recognized.
+pub const fn parse_rate(s: &[u8], incl_min: u128, incl_max: u128, clamp: bool) -> Result<u128, ParseError> {
+    let (size, unit) = match split_once(s, b'/') {
+        Some(halves) => halves,
+        None if trim_ws(s).is_none() => return Err(ParseError::Empty),
+        None => return Err(ParseError::MissingRateUnit),
+    };
+    let (mul, div): (u128, u128) = match match_word(unit, &["ms", "s", "min", "h"]) {
+        Ok(0) => (1000, 1),
+        Ok(1) => (1, 1),
+        Ok(2) => (1, 60),
+        Ok(_) => (1, 3600),
+        Err(_) => return Err(ParseError::UnknownRateUnit),
+    };
+    let size = match trim_ws(size) {
+        Some((start, end)) if size[end - 1] == b'B' => subslice(size, start, end - 1),
+        _ => size,
+    };
+    let val = match parse_cased_size(size, 0, u128::MAX, clamp) {
+        Ok(n) => match n.checked_mul(mul) {
+            Some(v) => v / div,
+            None if clamp => incl_max,
+            None => return Err(ParseError::IntOverflow),
+        },
+        Err(ParseError::Empty) => return Err(ParseError::NoDigits),
+        Err(e) => return Err(e),
+    };
+    if val < incl_min {
+        return if clamp { Ok(incl_min) } else { Err(ParseError::OutOfRange) };
+    }
+    if val > incl_max {
+        return if clamp { Ok(incl_max) } else { Err(ParseError::OutOfRange) };
+    }
+    Ok(val)
+}
+
+/// Parses a duration in the format used by Go's `time.ParseDuration`, like
+/// `"1h30m"`, `"1.5h"` or `"250ms"`.
+///
+/// This is a sequence of numbers (each of which may have a fraction, like
+/// `1.5` or `.5`), each directly followed by one of the (case-sensitive) units
+/// `ns`, `us` (or `µs`/`μs`), `ms`, `s`, `m` or `h`. The units may appear in
+/// any order, and repeat. A lone `0` is also accepted. Surrounding whitespace
+/// is ignored, but whitespace between the parts isn't allowed. Minutes are `m`,
+/// as in Go, rather than the `min` that [`parse_rate`] uses.
+///
+/// This differs from Go in a few ways:
+///
+/// - A `-` sign is rejected with [`ParseError::UnexpectedSign`], since
+///   [`Duration`](core::time::Duration) can't be negative (a `+` is fine).
+/// - Durations up to [`Duration::MAX`](core::time::Duration::MAX) are allowed,
+///   rather than stopping at about 292 years like Go's `int64` of
+///   nanoseconds. Beyond that is [`ParseError::IntOverflow`].
+/// - Underscores and prefixes like `0x` aren't accepted, in keeping with Go.
+///
+/// - Fractions are computed exactly, and then truncated to whole nanoseconds.
+///   Go uses floating point for this step, so it occasionally comes out a
+///   nanosecond higher (e.g. `"0.3333333333333333333h"` is `20m` in Go, but a
+///   nanosecond less here).
+///
+/// ```txt
+/// duration: '+'? ('0' | (number unit)+)
+/// number: digit_dec+ ('.' digit_dec*)? | '.' digit_dec+
+/// unit: 'ns' | 'us' | 'µs' | 'μs' | 'ms' | 's' | 'm' | 'h'
+/// ```
+pub const fn parse_duration(s: &[u8]) -> Result<core::time::Duration, ParseError> {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    // Fraction digits past this are ignored, keeping `frac * unit` in range.
+    const MAX_SCALE: u128 = 1_000_000_000_000_000_000_000_000;
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    match s[pos] {
+        b'+' => pos += 1,
+        b'-' => return Err(ParseError::UnexpectedSign),
+        _ => {}
+    }
+    if pos + 1 == end && s[pos] == b'0' {
+        return Ok(core::time::Duration::ZERO);
+    }
+    if pos == end {
+        return Err(ParseError::NoDigits);
+    }
+    let mut total = 0u128;
+    while pos < end {
+        let mut whole = 0u128;
+        let mut saw_digits = false;
+        while pos < end && s[pos].is_ascii_digit() {
+            whole = match whole.checked_mul(10) {
+                Some(v) => match v.checked_add((s[pos] - b'0') as u128) {
+                    Some(v) => v,
+                    None => return Err(ParseError::IntOverflow),
+                },
+                None => return Err(ParseError::IntOverflow),
+            };
+            saw_digits = true;
+            pos += 1;
+        }
+        let (mut frac, mut scale) = (0u128, 1u128);
+        if pos < end && s[pos] == b'.' {
+            pos += 1;
+            while pos < end && s[pos].is_ascii_digit() {
+                if scale < MAX_SCALE {
+                    frac = frac * 10 + (s[pos] - b'0') as u128;
+                    scale *= 10;
+                }
+                saw_digits = true;
+                pos += 1;
+            }
+        }
+        if !saw_digits {
+            return Err(ParseError::NoDigits);
+        }
+        let unit_start = pos;
+        while pos < end && !s[pos].is_ascii_digit() && s[pos] != b'.' {
+            pos += 1;
+        }
+        let unit: u128 = match subslice(s, unit_start, pos) {
+            b"" => return Err(ParseError::MissingTimeUnit),
+            b"ns" => 1,
+            // "µs" (micro sign) and "μs" (Greek mu) in UTF-8.
+            b"us" | b"\xc2\xb5s" | b"\xce\xbcs" => 1_000,
+            b"ms" => 1_000_000,
+            b"s" => NANOS_PER_SEC,
+            b"m" => 60 * NANOS_PER_SEC,
+            b"h" => 60 * 60 * NANOS_PER_SEC,
+            _ => return Err(ParseError::UnknownTimeUnit),
+        };
+        let part = match whole.checked_mul(unit) {
+            Some(v) => match v.checked_add(frac * unit / scale) {
+                Some(v) => v,
+                None => return Err(ParseError::IntOverflow),
+            },
+            None => return Err(ParseError::IntOverflow),
+        };
+        total = match total.checked_add(part) {
+            Some(v) => v,
+            None => return Err(ParseError::IntOverflow),
+        };
+    }
+    if total / NANOS_PER_SEC > u64::MAX as u128 {
+        return Err(ParseError::IntOverflow);
+    }
+    Ok(core::time::Duration::new((total / NANOS_PER_SEC) as u64, (total % NANOS_PER_SEC) as u32))
+}
+
+/// Like [`parse_duration`], but gives the whole seconds and the nanoseconds
+/// past them separately, the way [`Duration::new`](core::time::Duration::new)
+/// takes them.
+///
+/// Keeping the seconds separate means nothing is lost for durations too long
+/// for a `u64` of nanoseconds (about 584 years). The longest duration this
+/// accepts is [`Duration::MAX`](core::time::Duration::MAX), which is
+/// `(u64::MAX, 999_999_999)`, or about 585 billion years.
+///
+/// ```
+/// use envparse::parse::parse_duration_parts;
+/// assert_eq!(parse_duration_parts(b"1m30.25s"), Ok((90, 250_000_000)));
+/// assert_eq!(parse_duration_parts(b"1000000h"), Ok((3_600_000_000, 0)));
+/// ```
+pub const fn parse_duration_parts(s: &[u8]) -> Result<(u64, u32), ParseError> {
+    match parse_duration(s) {
+        Ok(d) => Ok((d.as_secs(), d.subsec_nanos())),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a range of durations, like `100ms..500ms`, into the inclusive
+/// bounds in nanoseconds, for things like jittered backoff windows.
+///
+/// Each side is a duration as accepted by [`parse_duration`], with its own
+/// units, so `500ms..2s` is fine. A unit on one side doesn't carry over to the
+/// other, so `100..500ms` is [`ParseError::MissingTimeUnit`], rather than
+/// guessing which unit was meant. As with [`split_range`], `..` excludes the
+/// end (so `100ms..500ms` ends a nanosecond before `500ms`) and `..=` includes
+/// it. A blank start is zero, and a blank end is `u64::MAX`.
+///
+/// Returns [`ParseError::InvalidRange`] if the range is empty or reversed, and
+/// [`ParseError::IntOverflow`] if either side doesn't fit in a `u64` of
+/// nanoseconds (about 584 years).
+///
+/// ```
+/// use envparse::parse::{parse_duration_range, ParseError};
+/// assert_eq!(parse_duration_range(b"100ms..=500ms"), Ok((100_000_000, 500_000_000)));
+/// assert_eq!(parse_duration_range(b"1s..2s"), Ok((1_000_000_000, 1_999_999_999)));
+/// assert_eq!(parse_duration_range(b"2s..=1s"), Err(ParseError::InvalidRange));
+/// ```
+pub const fn parse_duration_range(s: &[u8]) -> Result<(u64, u64), ParseError> {
+    const fn nanos(s: &[u8], blank: u64) -> Result<u64, ParseError> {
+        if trim_ws(s).is_none() {
+            return Ok(blank);
+        }
+        match parse_duration(s) {
+            Ok(d) if d.as_nanos() <= u64::MAX as u128 => Ok(d.as_nanos() as u64),
+            Ok(_) => Err(ParseError::IntOverflow),
+            Err(e) => Err(e),
+        }
+    }
+    let (start, end, incl) = match split_range(s) {
+        Ok(tup) => tup,
+        Err(e) => return Err(e),
+    };
+    let lo = match nanos(start, 0) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    let hi = match nanos(end, u64::MAX) {
+        // A blank end is unbounded, even if it's exclusive.
+        Ok(v) if incl || trim_ws(end).is_none() => v,
+        Ok(0) => return Err(ParseError::InvalidRange),
+        Ok(v) => v - 1,
+        Err(e) => return Err(e),
+    };
+    if lo > hi {
+        return Err(ParseError::InvalidRange);
+    }
+    Ok((lo, hi))
+}
+
+/// Like [`parse_unsigned`] but for signed numbers, returning a `i128`.
+///
+/// See [Syntax](mod@super#syntax) for information on what strings this
+/// function accepts.
+pub const fn parse_signed(s: &[u8], incl_min: i128, incl_max: i128, clamp: bool) -> Result<i128, ParseError> {
+    const I128_MIN_MAGNITUDE: u128 = (i128::MAX as u128) + 1;
+    let val = match number_parse(s, true) {
+        Ok((n, true)) if n == I128_MIN_MAGNITUDE => i128::MIN,
+        Ok((n, true)) if n <= (i128::MAX as u128) => -(n as i128),
+        Ok((_, true)) if clamp => incl_min,
+        Ok((n, false)) if n <= (i128::MAX as u128) => n as i128,
+        Ok((_, false)) if clamp => incl_max,
+        Ok((_, _)) => return Err(ParseError::OutOfRange),
+        // Err(ParseError::IntOverflow) =>
+        Err(e) => return Err(e),
+    };
+    if val < incl_min {
+        return if clamp { Ok(incl_min) } else { Err(ParseError::OutOfRange) };
+    }
+    if val > incl_max {
+        return if clamp { Ok(incl_max) } else { Err(ParseError::OutOfRange) };
+    }
+    Ok(val)
+}
+
+/// Parses a boolean from a byte slice.
+///
+/// Case-insensitive, ignores leading and trailing whitespace, and accepts
+/// `"0"`, `"f"`, `"n"`, `"no"`, `"off"`, and `"false"` for `false`, and `"1"`,
+/// `"t"`, `"y"`, `"on"`, `"yes"`, and `"true"` for `true`.
+///
+/// See [Syntax](mod@super#syntax) for information on what strings this
+/// function accepts. With the `bool-i18n` feature, the words in
+/// `BOOL_I18N_TOKENS` are accepted as well, and with the `bool-emoji` feature,
+/// the symbols in `BOOL_EMOJI_TOKENS`.
+pub const fn parse_bool(s: &[u8]) -> Result<bool, ParseError> {
+    match parse_bool_ascii(s) {
+        #[cfg(any(feature = "bool-i18n", feature = "bool-emoji"))]
+        Err(ParseError::UnknownBoolValue) => parse_bool_extra(s),
+        res => res,
+    }
+}
+
+/// The fallback for [`parse_bool`] when the value isn't one of the usual ASCII
+/// words, so that the optional features don't slow those down.
+#[cfg(any(feature = "bool-i18n", feature = "bool-emoji"))]
+const fn parse_bool_extra(s: &[u8]) -> Result<bool, ParseError> {
+    #[cfg(feature = "bool-i18n")]
+    if let Ok(b) = parse_bool_i18n(s) {
+        return Ok(b);
+    }
+    #[cfg(feature = "bool-emoji")]
+    if let Ok(b) = parse_bool_emoji(s) {
+        return Ok(b);
+    }
+    Err(ParseError::UnknownBoolValue)
+}
+
+/// The check marks and crosses that [`parse_bool`] accepts with the
+/// `bool-emoji` feature, as `(true_symbols, false_symbols)`. Each must be the
+/// whole value (apart from surrounding whitespace), exactly as listed here,
+/// so no variation selectors or skin tones.
+///
+/// | `bool` value | symbols                                         |
+/// | :--          | :--                                             |
+/// | `true`       | `✓` (U+2713), `✔` (U+2714), `✅` (U+2705)       |
+/// | `false`      | `✗` (U+2717), `✘` (U+2718), `❌` (U+274C)       |
+#[cfg(feature = "bool-emoji")]
+pub const BOOL_EMOJI_TOKENS: (&[&str], &[&str]) =
+    (&["\u{2713}", "\u{2714}", "\u{2705}"], &["\u{2717}", "\u{2718}", "\u{274c}"]);
+
+#[cfg(feature = "bool-emoji")]
+const fn parse_bool_emoji(s: &[u8]) -> Result<bool, ParseError> {
+    if match_word(s, BOOL_EMOJI_TOKENS.0).is_ok() {
+        Ok(true)
+    } else if match_word(s, BOOL_EMOJI_TOKENS.1).is_ok() {
+        Ok(false)
+    } else {
+        Err(ParseError::UnknownBoolValue)
+    }
+}
+
+/// The words for `true` and `false` in other languages that [`parse_bool`]
+/// accepts with the `bool-i18n` feature, as `(language, true_words,
+/// false_words)`, with languages given by their ISO 639-1 code.
+///
+/// These are matched ignoring ASCII case (so `OUI` works, but `SÍ` doesn't)
+/// and surrounding whitespace. Where a word is commonly written without its
+/// accent, both spellings are listed.
+///
+/// | language | `true`                  | `false`                |
+/// | :--      | :--                     | :--                    |
+/// | `de`     | `ja`, `wahr`            | `nein`, `falsch`       |
+/// | `es`     | `sí`, `si`, `verdadero` | `no`, `falso`          |
+/// | `fr`     | `oui`, `vrai`           | `non`, `faux`          |
+/// | `it`     | `sì`, `si`, `vero`      | `no`, `falso`          |
+/// | `nl`     | `ja`, `waar`            | `nee`, `onwaar`        |
+/// | `pt`     | `sim`, `verdadeiro`     | `não`, `nao`, `falso`  |
+#[cfg(feature = "bool-i18n")]
+#[allow(clippy::type_complexity)]
+pub const BOOL_I18N_TOKENS: &[(&str, &[&str], &[&str])] = &[
+    ("de", &["ja", "wahr"], &["nein", "falsch"]),
+    ("es", &["sí", "si", "verdadero"], &["no", "falso"]),
+    ("fr", &["oui", "vrai"], &["non", "faux"]),
+    ("it", &["sì", "si", "vero"], &["no", "falso"]),
+    ("nl", &["ja", "waar"], &["nee", "onwaar"]),
+    ("pt", &["sim", "verdadeiro"], &["não", "nao", "falso"]),
+];
+
+#[cfg(feature = "bool-i18n")]
+const fn parse_bool_i18n(s: &[u8]) -> Result<bool, ParseError> {
+    let mut i = 0;
+    while i < BOOL_I18N_TOKENS.len() {
+        let (_, yes, no) = BOOL_I18N_TOKENS[i];
+        if match_word(s, yes).is_ok() {
+            return Ok(true);
+        }
+        if match_word(s, no).is_ok() {
+            return Ok(false);
+        }
+        i += 1;
+    }
+    Err(ParseError::UnknownBoolValue)
+}
+
+const fn parse_bool_ascii(s: &[u8]) -> Result<bool, ParseError> {
+    let (i, e) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let len = e.saturating_sub(i);
+    match len {
+        0 => Err(ParseError::Empty),
+        // All these are case insensitive.
+        //
+        // The bool syntax accepted is similar to what `rustc` accepts for `-C`
+        // and `-Z` flags, although a few single-char values are allowed ("1" |
+        // "t" | "y" for true, and "0" | "n" | "f" for false)
+        1 => match s[i] {
+            // "1"/"0" | "t"/"f" | "y/n"
+            b'1' | b't' | b'T' | b'y' | b'Y' => Ok(true),
+            b'0' | b'f' | b'F' | b'n' | b'N' => Ok(false),
+            _ => Err(ParseError::UnknownBoolValue),
+        },
+        2 => match (s[i], s[i + 1]) {
+            // "no"
             (b'n' | b'N', b'o' | b'O') => Ok(false),
             // "on"
             (b'o' | b'O', b'n' | b'N') => Ok(true),
@@ -213,10 +1345,1295 @@ pub const fn parse_bool(s: &[u8]) -> Result<bool, ParseError> {
             (b'f' | b'F', b'a' | b'A', b'l' | b'L', b's' | b'S', b'e' | b'E') => Ok(false),
             _ => Err(ParseError::UnknownBoolValue),
         },
+        // "enable" / "enabled" and "disable" / "disabled". Some configs use the
+        // imperative form, some the adjective, so we take both.
+        _ if eq_ignore_case(s, i, e, b"enable") || eq_ignore_case(s, i, e, b"enabled") => Ok(true),
+        _ if eq_ignore_case(s, i, e, b"disable") || eq_ignore_case(s, i, e, b"disabled") => Ok(false),
         _ => Err(ParseError::UnknownBoolValue),
     }
 }
 
+/// Parses a boolean which must be exactly `T` or `F`, for matching a wire
+/// format. Unlike [`parse_bool`], this is case-sensitive, doesn't ignore
+/// whitespace, and rejects every other spelling (including `1` and `0`).
+pub const fn parse_bool_tf(s: &[u8]) -> Result<bool, ParseError> {
+    match s {
+        [] => Err(ParseError::Empty),
+        [b'T'] => Ok(true),
+        [b'F'] => Ok(false),
+        _ => Err(ParseError::UnknownBoolValue),
+    }
+}
+
+/// Parses a boolean from only the first non-whitespace character, ignoring
+/// the rest, for compatibility with legacy tools that read booleans this way:
+/// `t`, `T` or `1` is `true`, and `f`, `F` or `0` is `false`.
+///
+/// This is lossy, since typos and unrelated words are accepted as long as they
+/// start with the right character (`truthy`, `1000` and `fals` are all fine),
+/// so prefer [`parse_bool`] unless the upstream config really needs this.
+///
+/// ```
+/// use envparse::parse::{parse_bool_first_char, ParseError};
+/// assert_eq!(parse_bool_first_char(b"truthy"), Ok(true));
+/// assert_eq!(parse_bool_first_char(b" falsey"), Ok(false));
+/// assert_eq!(parse_bool_first_char(b"yes"), Err(ParseError::UnknownBoolValue));
+/// ```
+pub const fn parse_bool_first_char(s: &[u8]) -> Result<bool, ParseError> {
+    match trim_ws(s) {
+        None => Err(ParseError::Empty),
+        Some((i, _)) => match s[i] {
+            b't' | b'T' | b'1' => Ok(true),
+            b'f' | b'F' | b'0' => Ok(false),
+            _ => Err(ParseError::UnknownBoolValue),
+        },
+    }
+}
+
+/// Parses a boolean given as a sign, in the style of `set -e` and `set +e`:
+/// `+` is `true`, and `-` is `false`. Surrounding whitespace is ignored, but
+/// nothing else may appear. See [`parse_bool_sign_named`] to also accept the
+/// name of the flag after the sign.
+///
+/// ```txt
+/// bool_sign: ('+' | '-')
+/// ```
+pub const fn parse_bool_sign(s: &[u8]) -> Result<bool, ParseError> {
+    parse_bool_sign_named(s, "")
+}
+
+/// Like [`parse_bool_sign`], but the sign may also be followed by `name`, so
+/// with a `name` of `"e"`, each of `+`, `+e`, `-` and `-e` is accepted.
+///
+/// The name is case-sensitive (as flags like these usually are), and must
+/// follow the sign directly. Anything else, like `+E`, `+ e`, `++` or `e`, is
+/// rejected with [`ParseError::UnknownBoolValue`], rather than guessed at.
+///
+/// ```txt
+/// bool_sign_named: ('+' | '-') name?
+/// ```
+pub const fn parse_bool_sign_named(s: &[u8], name: &str) -> Result<bool, ParseError> {
+    let (start, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let val = match s[start] {
+        b'+' => true,
+        b'-' => false,
+        _ => return Err(ParseError::UnknownBoolValue),
+    };
+    let rest = end - start - 1;
+    if rest == 0 || (rest == name.len() && starts_with_at(s, start + 1, name.as_bytes())) {
+        Ok(val)
+    } else {
+        Err(ParseError::UnknownBoolValue)
+    }
+}
+
+/// Decodes `s[start..end]` if it's exactly one UTF-8 encoded `char`. Assumes
+/// `s` is valid UTF-8.
+const fn single_char(s: &[u8], start: usize, end: usize) -> Option<char> {
+    if start >= end {
+        return None;
+    }
+    let b = s[start];
+    let (len, mut c) = match b {
+        0x00..=0x7f => (1, b as u32),
+        0xc0..=0xdf => (2, (b & 0x1f) as u32),
+        0xe0..=0xef => (3, (b & 0x0f) as u32),
+        _ => (4, (b & 0x07) as u32),
+    };
+    if end - start != len {
+        return None;
+    }
+    let mut i = 1;
+    while i < len {
+        c = (c << 6) | (s[start + i] & 0x3f) as u32;
+        i += 1;
+    }
+    char::from_u32(c)
+}
+
+/// Parses a single `char` from a byte slice.
+///
+/// The input must be valid UTF-8 and contain exactly one character, ignoring
+/// leading and trailing whitespace. A lone whitespace character is accepted
+/// as-is, so that `" "` parses as `' '`.
+pub const fn parse_char(s: &[u8]) -> Result<char, ParseError> {
+    if core::str::from_utf8(s).is_err() {
+        return Err(ParseError::NotAChar);
+    }
+    if let Some(c) = single_char(s, 0, s.len()) {
+        return Ok(c);
+    }
+    match trim_ws(s) {
+        None => Err(ParseError::Empty),
+        Some((start, end)) => match single_char(s, start, end) {
+            Some(c) => Ok(c),
+            None => Err(ParseError::NotAChar),
+        },
+    }
+}
+
+/// Splits a range like `10..100` or `10..=99` at the first `..`, returning the
+/// bytes of the start and the end (as-is, since the number parsers trim them
+/// themselves), and whether the end is inclusive. Either side may be blank, as
+/// in `10..` or `..100`, for a range with no bound on that side.
+///
+/// Returns [`ParseError::InvalidRange`] if there's no `..`, and
+/// [`ParseError::Empty`] if `s` is blank.
+///
+/// ```
+/// use envparse::parse::{split_range, ParseError};
+/// assert_eq!(split_range(b"10..100"), Ok((&b"10"[..], &b"100"[..], false)));
+/// assert_eq!(split_range(b" 10 ..= 99 "), Ok((&b" 10 "[..], &b" 99 "[..], true)));
+/// assert_eq!(split_range(b"..100"), Ok((&b""[..], &b"100"[..], false)));
+/// assert_eq!(split_range(b"10-100"), Err(ParseError::InvalidRange));
+/// ```
+pub const fn split_range(s: &[u8]) -> Result<(&[u8], &[u8], bool), ParseError> {
+    if trim_ws(s).is_none() {
+        return Err(ParseError::Empty);
+    }
+    let mut dots = 0;
+    while dots + 1 < s.len() && !(s[dots] == b'.' && s[dots + 1] == b'.') {
+        dots += 1;
+    }
+    if dots + 1 >= s.len() {
+        return Err(ParseError::InvalidRange);
+    }
+    let start = subslice(s, 0, dots);
+    if dots + 2 < s.len() && s[dots + 2] == b'=' {
+        Ok((start, subslice(s, dots + 3, s.len()), true))
+    } else {
+        Ok((start, subslice(s, dots + 2, s.len()), false))
+    }
+}
+
+/// Splits a flag with an optional argument, like `on:256` or `off`, into the
+/// flag (parsed like [`parse_bool`]) and the bytes of the argument after the
+/// first `:`, if there is one.
+///
+/// Only a true flag can have an argument, so `off:256` returns
+/// [`ParseError::UnexpectedArgument`].
+///
+/// ```
+/// use envparse::parse::{split_flag, ParseError};
+/// assert_eq!(split_flag(b"on:256"), Ok((true, Some(&b"256"[..]))));
+/// assert_eq!(split_flag(b" yes "), Ok((true, None)));
+/// assert_eq!(split_flag(b"off"), Ok((false, None)));
+/// assert_eq!(split_flag(b"off:256"), Err(ParseError::UnexpectedArgument));
+/// ```
+pub const fn split_flag(s: &[u8]) -> Result<(bool, Option<&[u8]>), ParseError> {
+    let mut colon = 0;
+    while colon < s.len() && s[colon] != b':' {
+        colon += 1;
+    }
+    let flag = match parse_bool(subslice(s, 0, colon)) {
+        Ok(b) => b,
+        Err(ParseError::Empty) if colon < s.len() => return Err(ParseError::UnknownBoolValue),
+        Err(e) => return Err(e),
+    };
+    if colon == s.len() {
+        Ok((flag, None))
+    } else if flag {
+        Ok((true, Some(subslice(s, colon + 1, s.len()))))
+    } else {
+        Err(ParseError::UnexpectedArgument)
+    }
+}
+
+/// Parses a flag with an optional percentage, like `"true:50"`, for weighted
+/// rollouts, returning the flag and the percentage.
+///
+/// The flag is parsed like [`parse_bool`], and the percentage after the `:`
+/// like [`parse_unsigned`], which must be in `0..=100` (otherwise
+/// [`ParseError::OutOfRange`] is returned). Without a percentage, a true flag
+/// is `100`, and a false one is `0`. A false flag can't have a percentage (see
+/// [`split_flag`]).
+///
+/// ```
+/// use envparse::parse::{parse_weighted_flag, ParseError};
+/// assert_eq!(parse_weighted_flag(b"true:50"), Ok((true, 50)));
+/// assert_eq!(parse_weighted_flag(b"true"), Ok((true, 100)));
+/// assert_eq!(parse_weighted_flag(b"false"), Ok((false, 0)));
+/// assert_eq!(parse_weighted_flag(b"true:150"), Err(ParseError::OutOfRange));
+/// ```
+pub const fn parse_weighted_flag(s: &[u8]) -> Result<(bool, u8), ParseError> {
+    match split_flag(s) {
+        Ok((false, _)) => Ok((false, 0)),
+        Ok((true, None)) => Ok((true, 100)),
+        Ok((true, Some(arg))) => match parse_unsigned(arg, 0, 100, false) {
+            Ok(pct) => Ok((true, pct as u8)),
+            Err(ParseError::Empty) => Err(ParseError::NoDigits),
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a Unicode code point number into a `char`, for characters that are
+/// hard to type (or to put in an environment variable) as themselves.
+///
+/// The number is written as `U+` followed by hex digits (as in `U+003B`), or
+/// with the same syntax as [`parse_unsigned`] (as in `0x3B` or `59`). Leading
+/// and trailing whitespace is ignored. A surrogate code point returns
+/// [`ParseError::SurrogateCodePoint`], and one above `0x10FFFF` returns
+/// [`ParseError::OutOfRange`].
+///
+/// ```
+/// use envparse::parse::{parse_char_code, ParseError};
+/// assert_eq!(parse_char_code(b"U+003B"), Ok(';'));
+/// assert_eq!(parse_char_code(b"0x1F600"), Ok('😀'));
+/// assert_eq!(parse_char_code(b"0xD800"), Err(ParseError::SurrogateCodePoint));
+/// ```
+pub const fn parse_char_code(s: &[u8]) -> Result<char, ParseError> {
+    let (start, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let code = if end - start >= 2 && (s[start] == b'U' || s[start] == b'u') && s[start + 1] == b'+' {
+        if end - start == 2 {
+            return Err(ParseError::NoDigits);
+        }
+        let mut code = 0u32;
+        let mut i = start + 2;
+        while i < end {
+            let d = match hex_digit(s[i]) {
+                Some(d) => d,
+                None => return Err(ParseError::InvalidDigit { digit: s[i], radix: 16 }),
+            };
+            // Saturate, since anything over 0x10FFFF is out of range anyway.
+            code = if code > 0x10FFFF { code } else { code << 4 | d as u32 };
+            i += 1;
+        }
+        code as u128
+    } else {
+        match parse_unsigned(subslice(s, start, end), 0, u128::MAX, false) {
+            Ok(v) => v,
+            Err(ParseError::IntOverflow) => return Err(ParseError::OutOfRange),
+            Err(e) => return Err(e),
+        }
+    };
+    if code > 0x10FFFF {
+        return Err(ParseError::OutOfRange);
+    }
+    match char::from_u32(code as u32) {
+        Some(c) => Ok(c),
+        None => Err(ParseError::SurrogateCodePoint),
+    }
+}
+
+/// Parses a ratio like `"-3/4"` into a `(numerator, denominator)` pair.
+///
+/// Both halves use the same syntax as [`parse_signed`], although only the
+/// numerator may have a sign, and the denominator must be nonzero (otherwise
+/// [`ParseError::ZeroDenominator`] is returned). If there's no `/`, the
+/// denominator is 1. If `reduce` is true, the result is divided through by the
+/// GCD of the two halves.
+///
+/// See [Syntax](mod@super#syntax) for information on what strings this
+/// function accepts.
+pub const fn parse_ratio(s: &[u8], reduce: bool) -> Result<(i128, i128), ParseError> {
+    if trim_ws(s).is_none() {
+        return Err(ParseError::Empty);
+    }
+    let mut slash = 0;
+    while slash < s.len() && s[slash] != b'/' {
+        slash += 1;
+    }
+    let num = match parse_signed(subslice(s, 0, slash), i128::MIN, i128::MAX, false) {
+        Ok(n) => n,
+        Err(ParseError::Empty) => return Err(ParseError::NoDigits),
+        Err(e) => return Err(e),
+    };
+    if slash == s.len() {
+        return Ok((num, 1));
+    }
+    let den = match parse_unsigned(subslice(s, slash + 1, s.len()), 0, i128::MAX as u128, false) {
+        Ok(0) => return Err(ParseError::ZeroDenominator),
+        Ok(d) => d as i128,
+        Err(ParseError::Empty) => return Err(ParseError::NoDigits),
+        Err(e) => return Err(e),
+    };
+    if !reduce {
+        return Ok((num, den));
+    }
+    let (mut a, mut b) = (num.unsigned_abs(), den as u128);
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    // `a` is the GCD, and divides `den`, so it fits in an `i128`.
+    Ok((num / a as i128, den / a as i128))
+}
+
+/// Parses a dotted-decimal IPv4 address like `"192.168.0.1"`, ignoring
+/// surrounding whitespace.
+///
+/// As with `std`, each of the four parts must be a decimal number from 0 to 255
+/// without leading zeros (which some tools would read as octal). An unexpected
+/// character is reported as [`ParseError::InvalidDigit`], and anything else
+/// wrong as [`ParseError::InvalidIpv4Addr`].
+pub const fn parse_ipv4(s: &[u8]) -> Result<core::net::Ipv4Addr, ParseError> {
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    // The error to return when `s[pos]` isn't what we wanted.
+    const fn unexpected(s: &[u8], pos: usize, end: usize) -> ParseError {
+        if pos < end && s[pos] != b'.' && !s[pos].is_ascii_digit() {
+            ParseError::InvalidDigit { digit: s[pos], radix: 10 }
+        } else {
+            ParseError::InvalidIpv4Addr
+        }
+    }
+    let mut octets = [0u8; 4];
+    let mut i = 0;
+    while i < octets.len() {
+        if i != 0 {
+            if pos == end || s[pos] != b'.' {
+                return Err(unexpected(s, pos, end));
+            }
+            pos += 1;
+        }
+        let start = pos;
+        let mut n = 0u32;
+        while pos < end && s[pos].is_ascii_digit() {
+            n = n * 10 + (s[pos] - b'0') as u32;
+            if n > 255 {
+                return Err(ParseError::InvalidIpv4Addr);
+            }
+            pos += 1;
+        }
+        if pos == start {
+            return Err(unexpected(s, pos, end));
+        }
+        if s[start] == b'0' && pos - start > 1 {
+            return Err(ParseError::InvalidIpv4Addr);
+        }
+        octets[i] = n as u8;
+        i += 1;
+    }
+    if pos != end {
+        return Err(unexpected(s, pos, end));
+    }
+    Ok(core::net::Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+}
+
+/// Parses a version like `"1.2.3"` into its `(major, minor, patch)` numbers,
+/// ignoring surrounding whitespace. A single leading `v` or `V` is allowed, so
+/// git tag names like `"v1.2.3"` work as-is.
+///
+/// As in SemVer, each part is a decimal number without leading zeros. The
+/// pre-release and build metadata parts of SemVer (`-rc.1`, `+build.5`) aren't
+/// supported. An unexpected character is reported as
+/// [`ParseError::InvalidDigit`], a part over `u64::MAX` as
+/// [`ParseError::IntOverflow`], and anything else wrong as
+/// [`ParseError::InvalidVersion`].
+///
+/// ```
+/// use envparse::parse::parse_version;
+/// assert_eq!(parse_version(b"1.2.3"), Ok((1, 2, 3)));
+/// assert_eq!(parse_version(b"v1.2.3"), Ok((1, 2, 3)));
+/// assert!(parse_version(b"vv1.2.3").is_err());
+/// ```
+pub const fn parse_version(s: &[u8]) -> Result<(u64, u64, u64), ParseError> {
+    let (mut pos, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    if s[pos] == b'v' || s[pos] == b'V' {
+        pos += 1;
+    }
+    // The error to return when `s[pos]` isn't what we wanted.
+    const fn unexpected(s: &[u8], pos: usize, end: usize) -> ParseError {
+        if pos < end && s[pos] != b'.' && !s[pos].is_ascii_digit() {
+            ParseError::InvalidDigit { digit: s[pos], radix: 10 }
+        } else {
+            ParseError::InvalidVersion
+        }
+    }
+    let mut parts = [0u64; 3];
+    let mut i = 0;
+    while i < parts.len() {
+        if i != 0 {
+            if pos == end || s[pos] != b'.' {
+                return Err(unexpected(s, pos, end));
+            }
+            pos += 1;
+        }
+        let start = pos;
+        let mut n = 0u64;
+        while pos < end && s[pos].is_ascii_digit() {
+            n = match n.checked_mul(10) {
+                Some(n) => match n.checked_add((s[pos] - b'0') as u64) {
+                    Some(n) => n,
+                    None => return Err(ParseError::IntOverflow),
+                },
+                None => return Err(ParseError::IntOverflow),
+            };
+            pos += 1;
+        }
+        if pos == start {
+            return Err(unexpected(s, pos, end));
+        }
+        if s[start] == b'0' && pos - start > 1 {
+            return Err(ParseError::InvalidVersion);
+        }
+        parts[i] = n;
+        i += 1;
+    }
+    if pos != end {
+        return Err(unexpected(s, pos, end));
+    }
+    Ok((parts[0], parts[1], parts[2]))
+}
+
+/// Returns whether `version` matches the requirement `req`, which is a
+/// comparison operator (`=`, `>`, `>=`, `<` or `<=`) followed by a version of
+/// one to three parts, like `>=1.70` or `<2`, with optional whitespace between
+/// them. This is a subset of Cargo's version requirements.
+///
+/// As in Cargo, only the parts given are compared, so `=1.70` matches any
+/// `1.70.x`, `<=1.70` matches `1.70.5`, and `>1.70` needs at least `1.71.0`.
+/// An unknown or missing operator gives [`ParseError::UnknownOperator`], and a
+/// bad version is reported like in [`parse_version`].
+///
+/// ```
+/// use envparse::parse::version_matches;
+/// assert_eq!(version_matches(b">=1.70", (1, 75, 0)), Ok(true));
+/// assert_eq!(version_matches(b">=1.70", (1, 69, 9)), Ok(false));
+/// assert_eq!(version_matches(b"=1.70", (1, 70, 3)), Ok(true));
+/// ```
+pub const fn version_matches(req: &[u8], version: (u64, u64, u64)) -> Result<bool, ParseError> {
+    let (mut pos, end) = match trim_ws(req) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    // Which orderings of `version` relative to the requirement are accepted,
+    // as (less, equal, greater).
+    let (accept, len) = match (req[pos], if pos + 1 < end { req[pos + 1] } else { 0 }) {
+        (b'>', b'=') => ((false, true, true), 2),
+        (b'<', b'=') => ((true, true, false), 2),
+        (b'>', _) => ((false, false, true), 1),
+        (b'<', _) => ((true, false, false), 1),
+        (b'=', _) => ((false, true, false), 1),
+        (op, _) => return Err(ParseError::UnknownOperator { op }),
+    };
+    pos += len;
+    while pos < end && req[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    let have = [version.0, version.1, version.2];
+    // The ordering of `version` relative to the requirement so far, as -1, 0
+    // or 1, looking only at the parts given.
+    let mut ord = 0;
+    let mut i = 0;
+    while i == 0 || pos < end {
+        if i == 3 || (i != 0 && req[pos] != b'.') {
+            return Err(if pos < end && req[pos] != b'.' && !req[pos].is_ascii_digit() {
+                ParseError::InvalidDigit { digit: req[pos], radix: 10 }
+            } else {
+                ParseError::InvalidVersion
+            });
+        }
+        if i != 0 {
+            pos += 1;
+        }
+        let start = pos;
+        let mut n = 0u64;
+        while pos < end && req[pos].is_ascii_digit() {
+            n = match n.checked_mul(10) {
+                Some(n) => match n.checked_add((req[pos] - b'0') as u64) {
+                    Some(n) => n,
+                    None => return Err(ParseError::IntOverflow),
+                },
+                None => return Err(ParseError::IntOverflow),
+            };
+            pos += 1;
+        }
+        if pos == start {
+            return Err(if pos < end && req[pos] != b'.' {
+                ParseError::InvalidDigit { digit: req[pos], radix: 10 }
+            } else {
+                ParseError::InvalidVersion
+            });
+        }
+        if req[start] == b'0' && pos - start > 1 {
+            return Err(ParseError::InvalidVersion);
+        }
+        if ord == 0 && have[i] != n {
+            ord = if have[i] < n { -1 } else { 1 };
+        }
+        i += 1;
+    }
+    Ok(match ord {
+        -1 => accept.0,
+        0 => accept.1,
+        _ => accept.2,
+    })
+}
+
+/// A cursor over a list of unsigned numbers, for parsing lists by hand in const
+/// code (where iterators aren't available).
+///
+/// Numbers are separated by a comma, whitespace, or both (so `"1 2 3"`,
+/// `"1,2,3"` and `"1, 2, 3"` are all equivalent). Runs of whitespace count as a
+/// single separator, but there may only be one comma between two numbers, and
+/// a comma may not come before the first one. A single trailing comma is
+/// ignored. Each number uses the same syntax as [`parse_unsigned`].
+///
+/// This is only a helper for your own const code. The arrays and lists parsed
+/// by [`parse_env!`](crate::parse_env) don't use it, and split their items
+/// differently (for example, they reject a trailing comma).
+///
+/// ```
+/// use envparse::parse::NumberCursor;
+/// const SUM: u128 = {
+///     let mut sum = 0;
+///     let mut cursor = NumberCursor::new(b"1, 2 0x3");
+///     while let Some((n, next)) = cursor.next() {
+///         sum += n;
+///         cursor = next;
+///     }
+///     sum
+/// };
+/// assert_eq!(SUM, 6);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NumberCursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NumberCursor<'a> {
+    /// Creates a cursor positioned at the start of `s`.
+    pub const fn new(s: &'a [u8]) -> Self {
+        Self { s, pos: 0 }
+    }
+
+    /// Returns the next number and the advanced cursor, or `None` if there are
+    /// no numbers left.
+    ///
+    /// # Panics
+    ///
+    /// Panics (at compile time, if used in a const context) if the next item
+    /// isn't a valid number. Use [`NumberCursor::try_next`] to handle this
+    /// instead.
+    pub const fn next(self) -> Option<(u128, Self)> {
+        match self.try_next() {
+            Ok(next) => next,
+            Err(_) => panic!("invalid number in list"),
+        }
+    }
+
+    /// Like [`NumberCursor::next`], but returns an error if the next item
+    /// isn't a valid number, rather than panicking.
+    pub const fn try_next(self) -> Result<Option<(u128, Self)>, ParseError> {
+        let s = self.s;
+        let mut pos = self.pos;
+        while pos < s.len() && s[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos == s.len() {
+            return Ok(None);
+        }
+        let start = pos;
+        while pos < s.len() && s[pos] != b',' && !s[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if start == pos {
+            return Err(ParseError::NoDigits);
+        }
+        let n = match parse_unsigned(subslice(s, start, pos), 0, u128::MAX, false) {
+            Ok(n) => n,
+            Err(e) => return Err(e),
+        };
+        while pos < s.len() && s[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if pos < s.len() && s[pos] == b',' {
+            pos += 1;
+        }
+        Ok(Some((n, Self { s, pos })))
+    }
+}
+
+/// Removes a trailing comment from `s`, which starts at the first `#` and runs
+/// to the end. Nothing else is changed, so whitespace in front of the `#` is
+/// left for the parser to trim as usual.
+///
+/// ```
+/// use envparse::parse::{parse_bool, strip_comment};
+/// assert_eq!(strip_comment(b"on # enabled for staging"), b"on ");
+/// assert_eq!(parse_bool(strip_comment(b"on#why")), Ok(true));
+/// assert_eq!(strip_comment(b"# all comment"), b"");
+/// ```
+pub const fn strip_comment(s: &[u8]) -> &[u8] {
+    let mut i = 0;
+    while i < s.len() && s[i] != b'#' {
+        i += 1;
+    }
+    subslice(s, 0, i)
+}
+
+/// Removes a trailing `?` from `s` (ignoring trailing whitespace), which marks
+/// a value as optional, returning the rest and whether it was there.
+///
+/// ```
+/// use envparse::parse::strip_try_marker;
+/// assert_eq!(strip_try_marker(b"8k? "), (&b"8k"[..], true));
+/// assert_eq!(strip_try_marker(b"8k"), (&b"8k"[..], false));
+/// ```
+pub const fn strip_try_marker(s: &[u8]) -> (&[u8], bool) {
+    let mut end = s.len();
+    while end > 0 && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if end > 0 && s[end - 1] == b'?' {
+        (subslice(s, 0, end - 1), true)
+    } else {
+        (s, false)
+    }
+}
+
+/// If `s` starts with `prefix` (ignoring leading whitespace and ASCII case),
+/// returns the rest of it, and otherwise returns `None`.
+///
+/// This is for building conventions where a value may have a marker in front
+/// of it, like `default:30`, on top of the other parsers.
+///
+/// ```
+/// use envparse::parse::{parse_unsigned, strip_prefix};
+/// assert_eq!(strip_prefix(b" Default:30", b"default:"), Some(&b"30"[..]));
+/// assert_eq!(strip_prefix(b"30", b"default:"), None);
+/// const TIMEOUT: u128 = match strip_prefix(b"default:30", b"default:") {
+///     Some(rest) => match parse_unsigned(rest, 0, u32::MAX as u128, false) {
+///         Ok(v) => v,
+///         Err(_) => panic!("bad timeout"),
+///     },
+///     None => 0,
+/// };
+/// assert_eq!(TIMEOUT, 30);
+/// ```
+pub const fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    let mut start = 0;
+    while start < s.len() && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    if s.len() - start >= prefix.len() && eq_ignore_case(s, start, start + prefix.len(), prefix) {
+        Some(subslice(s, start + prefix.len(), s.len()))
+    } else {
+        None
+    }
+}
+
+/// Returns true if `word` is one of the items in the comma-separated list `s`,
+/// ignoring ASCII case and the whitespace around each item.
+///
+/// ```
+/// use envparse::parse::has_item;
+/// assert!(has_item(b"a, B ,c", b"b"));
+/// assert!(!has_item(b"a,bc", b"b"));
+/// ```
+pub const fn has_item(s: &[u8], word: &[u8]) -> bool {
+    let mut pos = 0;
+    while pos < s.len() {
+        let (start, end, next) = next_item(s, pos);
+        if eq_ignore_case(s, start, end, word) {
+            return true;
+        }
+        pos = next;
+    }
+    false
+}
+
+/// Splits off the first whitespace-separated word in `s`, returning it and the
+/// rest of `s` after it, or `None` if `s` is blank. Leading whitespace (any
+/// amount of spaces, tabs and newlines) is skipped, so calling this again on
+/// the rest gives the next word.
+///
+/// ```
+/// use envparse::parse::next_word;
+/// let (word, rest) = next_word(b"  fast\t\tsafe ").unwrap();
+/// assert_eq!((word, rest), (&b"fast"[..], &b"\t\tsafe "[..]));
+/// assert_eq!(next_word(rest), Some((&b"safe"[..], &b" "[..])));
+/// assert_eq!(next_word(b" \t "), None);
+/// ```
+pub const fn next_word(s: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut start = 0;
+    while start < s.len() && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    if start == s.len() {
+        return None;
+    }
+    let mut end = start;
+    while end < s.len() && !s[end].is_ascii_whitespace() {
+        end += 1;
+    }
+    Some((subslice(s, start, end), subslice(s, end, s.len())))
+}
+
+/// Splits a `KEY=VALUE` assignment, like a line of a dotenv file, at the first
+/// `=`, returning the key (with surrounding whitespace trimmed) and the value
+/// (as-is, since the other parsers trim it themselves).
+///
+/// Returns `None` if there's no `=`, or if the key isn't a plausible variable
+/// name: an ASCII letter or `_`, followed by ASCII letters, digits and `_`, as
+/// in a shell.
+///
+/// ```
+/// use envparse::parse::parse_env_assignment;
+/// assert_eq!(parse_env_assignment(b" MAX_LEN =32"), Some((&b"MAX_LEN"[..], &b"32"[..])));
+/// assert_eq!(parse_env_assignment(b"URL=a=b"), Some((&b"URL"[..], &b"a=b"[..])));
+/// assert_eq!(parse_env_assignment(b"2FAST=1"), None);
+/// ```
+pub const fn parse_env_assignment(s: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut eq = 0;
+    while eq < s.len() && s[eq] != b'=' {
+        eq += 1;
+    }
+    if eq == s.len() {
+        return None;
+    }
+    let (start, end) = match trim_ws(subslice(s, 0, eq)) {
+        Some(tup) => tup,
+        None => return None,
+    };
+    if s[start].is_ascii_digit() {
+        return None;
+    }
+    let mut i = start;
+    while i < end {
+        if !s[i].is_ascii_alphanumeric() && s[i] != b'_' {
+            return None;
+        }
+        i += 1;
+    }
+    Some((subslice(s, start, end), subslice(s, eq + 1, s.len())))
+}
+
+/// Finds the comma-separated item in `s` starting at `pos`, returning its
+/// bounds (with surrounding whitespace trimmed), and the position just past
+/// the comma that ended it.
+pub(crate) const fn next_item(s: &[u8], pos: usize) -> (usize, usize, usize) {
+    next_split(s, pos, b',')
+}
+
+/// Like [`next_item`], but for items separated by `sep` rather than a comma.
+pub(crate) const fn next_split(s: &[u8], pos: usize, sep: u8) -> (usize, usize, usize) {
+    let mut start = pos;
+    let mut end = pos;
+    while end < s.len() && s[end] != sep {
+        end += 1;
+    }
+    let next = end + 1;
+    while start < end && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (start, end, next)
+}
+
+/// Counts the items in `s` separated by `sep`, the same way the (non-`loose`)
+/// array and list parsers split their input (with `sep` being `b','` for
+/// them), so this can be used to pick an array size or check a count.
+///
+/// A blank `s` has no items. Otherwise, every separator starts a new item,
+/// even an empty one, so `1,,2` and `1,2,` both have three items. Those
+/// parsers reject empty items (including the one after a trailing comma), so
+/// they accept `s` as a `[T; N]` only if this returns `N`, but not always then.
+///
+/// ```
+/// use envparse::parse::count_delimited;
+/// assert_eq!(count_delimited(b"a:b:c", b':'), 3);
+/// assert_eq!(count_delimited(b"1,2,", b','), 3);
+/// assert_eq!(count_delimited(b"  ", b','), 0);
+/// ```
+pub const fn count_delimited(s: &[u8], sep: u8) -> usize {
+    if trim_ws(s).is_none() {
+        return 0;
+    }
+    let mut n = 1;
+    let mut pos = 0;
+    while pos < s.len() {
+        if s[pos] == sep {
+            n += 1;
+        }
+        pos += 1;
+    }
+    n
+}
+
+/// Like [`next_item`], but for lists where any run of commas and whitespace
+/// separates items, so `1,2`, `1 2` and `1 , 2` are all the same. `pos` must
+/// be at the start of an item (see [`skip_loose_seps`]), and the returned
+/// position is at the start of the next one (or the end of `s`), so there are
+/// never any empty items.
+pub(crate) const fn next_item_loose(s: &[u8], pos: usize) -> (usize, usize, usize) {
+    let mut end = pos;
+    while end < s.len() && !is_loose_sep(s[end]) {
+        end += 1;
+    }
+    (pos, end, skip_loose_seps(s, end))
+}
+
+/// Skips the commas and whitespace at `pos`, for [`next_item_loose`].
+pub(crate) const fn skip_loose_seps(s: &[u8], mut pos: usize) -> usize {
+    while pos < s.len() && is_loose_sep(s[pos]) {
+        pos += 1;
+    }
+    pos
+}
+
+const fn is_loose_sep(b: u8) -> bool {
+    b == b',' || b.is_ascii_whitespace()
+}
+
+/// `&s[start..end]`, but usable in const.
+pub(crate) const fn subslice(s: &[u8], start: usize, end: usize) -> &[u8] {
+    s.split_at(end).0.split_at(start).1
+}
+
+/// Returns true if `s[at..]` starts with `prefix`.
+pub(crate) const fn starts_with_at(s: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if at + prefix.len() > s.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Compares `s[start..end]` against `word`, ignoring ASCII case.
+pub(crate) const fn eq_ignore_case(s: &[u8], start: usize, end: usize, word: &[u8]) -> bool {
+    if end - start != word.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < word.len() {
+        if !s[start + i].eq_ignore_ascii_case(&word[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the index of the word in `words` which `s` matches (ignoring case and
+/// surrounding whitespace).
+pub(crate) const fn match_word(s: &[u8], words: &[&str]) -> Result<usize, ParseError> {
+    let (start, end) = match trim_ws(s) {
+        Some(tup) => tup,
+        None => return Err(ParseError::Empty),
+    };
+    let mut i = 0;
+    while i < words.len() {
+        if eq_ignore_case(s, start, end, words[i].as_bytes()) {
+            return Ok(i);
+        }
+        i += 1;
+    }
+    Err(ParseError::UnknownToken)
+}
+
+/// Parses exactly `on`, `off` or `auto` (ignoring case and surrounding
+/// whitespace).
+///
+/// Unlike [`parse_bool`], this doesn't accept synonyms like `yes` or `1`. See
+/// [`OnOffAuto`](crate::OnOffAuto) for why.
+pub const fn parse_on_off_auto(s: &[u8]) -> Result<crate::OnOffAuto, ParseError> {
+    match match_word(s, &["on", "off", "auto"]) {
+        Ok(0) => Ok(crate::OnOffAuto::On),
+        Ok(1) => Ok(crate::OnOffAuto::Off),
+        Ok(_) => Ok(crate::OnOffAuto::Auto),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a lint level: `allow`, `warn`, `deny` or `forbid` for the
+/// [`LintLevel`](crate::LintLevel) of the same name, ignoring case and
+/// surrounding whitespace.
+pub const fn parse_lint_level(s: &[u8]) -> Result<crate::LintLevel, ParseError> {
+    match match_word(s, &["allow", "warn", "deny", "forbid"]) {
+        Ok(0) => Ok(crate::LintLevel::Allow),
+        Ok(1) => Ok(crate::LintLevel::Warn),
+        Ok(2) => Ok(crate::LintLevel::Deny),
+        Ok(_) => Ok(crate::LintLevel::Forbid),
+        Err(e) => Err(e),
+    }
+}
+
+/// The log level names accepted by [`parse_rust_log`], in order, so a level's
+/// index is its number: `off` is 0, `error` is 1, and so on up to `trace`,
+/// which is 5. These are the same as the values of `log::LevelFilter`.
+pub const LOG_LEVELS: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+/// Parses the global log level from a `RUST_LOG`-style value, as a number from
+/// 0 (`off`) to 5 (`trace`), as listed in [`LOG_LEVELS`]. This is the level
+/// for modules that no directive names, so it's the same as
+/// [`parse_rust_log_for`] with an empty module path.
+///
+/// Only a simple subset of `env_logger`'s syntax is supported:
+///
+/// - The value is a comma-separated list of directives, and empty ones are
+///   skipped.
+/// - A directive that's just a level name (ignoring case and surrounding
+///   whitespace) sets the global level. If there are several, the last wins.
+///   If there are none, the global level is `off`.
+/// - `path=level` sets the level for the module `path` and its submodules.
+///   The level must be one of the names in [`LOG_LEVELS`].
+/// - Any other word is a module path, which gets the level `trace`.
+/// - Anything after a `/` (a filter regex for `env_logger`) is ignored.
+///
+/// Numeric levels and `path=` with no level aren't supported, and give
+/// [`ParseError::UnknownToken`].
+///
+/// ```
+/// use envparse::parse::parse_rust_log;
+/// assert_eq!(parse_rust_log(b"info"), Ok(3));
+/// assert_eq!(parse_rust_log(b"warn,my_crate::net=trace"), Ok(2));
+/// assert_eq!(parse_rust_log(b"my_crate=debug"), Ok(0));
+/// ```
+pub const fn parse_rust_log(s: &[u8]) -> Result<u8, ParseError> {
+    parse_rust_log_for(s, b"")
+}
+
+/// Like [`parse_rust_log`], but gives the level for the module `path` (like
+/// `my_crate::net`), for the same subset of the syntax.
+///
+/// As with `env_logger`, the directive with the longest path that's either
+/// `path` itself or one of its parent modules wins, with later directives
+/// winning ties. If none of them match, the global level is used.
+///
+/// ```
+/// use envparse::parse::parse_rust_log_for;
+/// let spec = b"warn,my_crate=info,my_crate::net=trace";
+/// assert_eq!(parse_rust_log_for(spec, b"my_crate::net::tcp"), Ok(5));
+/// assert_eq!(parse_rust_log_for(spec, b"my_crate::db"), Ok(3));
+/// assert_eq!(parse_rust_log_for(spec, b"my_crate_ext"), Ok(2));
+/// ```
+pub const fn parse_rust_log_for(s: &[u8], path: &[u8]) -> Result<u8, ParseError> {
+    if trim_ws(s).is_none() {
+        return Err(ParseError::Empty);
+    }
+    let s = match split_once(s, b'/') {
+        Some((directives, _filter)) => directives,
+        None => s,
+    };
+    // The level of the best match so far, and the length of its path (plus
+    // one, so that the global level, with an empty path, beats nothing).
+    let (mut level, mut best) = (0, 0);
+    let mut pos = 0;
+    while pos < s.len() {
+        let (start, end, next) = next_item(s, pos);
+        pos = next;
+        if start == end {
+            continue;
+        }
+        let item = subslice(s, start, end);
+        let (name, lvl) = match split_once(item, b'=') {
+            Some((name, lvl)) => match match_word(lvl, LOG_LEVELS) {
+                Ok(l) => (name, l as u8),
+                Err(_) => return Err(ParseError::UnknownToken),
+            },
+            None => match match_word(item, LOG_LEVELS) {
+                Ok(l) => (&[] as &[u8], l as u8),
+                Err(_) => (item, 5),
+            },
+        };
+        let name = match trim_ws(name) {
+            Some((start, end)) => subslice(name, start, end),
+            None => name,
+        };
+        if name.len() + 1 >= best && is_module_or_parent(path, name) {
+            level = lvl;
+            best = name.len() + 1;
+        }
+    }
+    Ok(level)
+}
+
+/// Returns true if the module `parent` is `path` or one of its parents.
+const fn is_module_or_parent(path: &[u8], parent: &[u8]) -> bool {
+    if parent.is_empty() {
+        return true;
+    }
+    starts_with(path, parent) && (path.len() == parent.len() || starts_with_at(path, parent.len(), b"::"))
+}
+
+/// Parses a sort direction: `asc`, `ascending` or `<` for
+/// [`SortDir::Ascending`](crate::SortDir::Ascending), and `desc`, `descending`
+/// or `>` for [`SortDir::Descending`](crate::SortDir::Descending), ignoring
+/// case and surrounding whitespace.
+pub const fn parse_sort_dir(s: &[u8]) -> Result<crate::SortDir, ParseError> {
+    match match_word(s, &["asc", "ascending", "<", "desc", "descending", ">"]) {
+        Ok(0..=2) => Ok(crate::SortDir::Ascending),
+        Ok(_) => Ok(crate::SortDir::Descending),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a prompt policy: `yes` or `always` for
+/// [`PromptPolicy::Always`](crate::PromptPolicy::Always), `no` or `never` for
+/// [`PromptPolicy::Never`](crate::PromptPolicy::Never), and `ask` or `prompt`
+/// for [`PromptPolicy::Ask`](crate::PromptPolicy::Ask), ignoring case and
+/// surrounding whitespace.
+pub const fn parse_prompt_policy(s: &[u8]) -> Result<crate::PromptPolicy, ParseError> {
+    match match_word(s, &["yes", "always", "no", "never", "ask", "prompt"]) {
+        Ok(0 | 1) => Ok(crate::PromptPolicy::Always),
+        Ok(2 | 3) => Ok(crate::PromptPolicy::Never),
+        Ok(_) => Ok(crate::PromptPolicy::Ask),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a comma-separated list of words into a bitmask of which entries in
+/// `vocab` were present.
+///
+/// Bit `i` of the result is set if `vocab[i]` appears in the list. Matching is
+/// case-insensitive, and whitespace around each item is ignored. Repeating an
+/// item is allowed and has no additional effect, and empty items (e.g. from a
+/// trailing comma) are skipped, so an empty or whitespace-only string parses
+/// as the empty set.
+///
+/// Returns [`ParseError::UnknownToken`] if an item isn't in `vocab`.
+///
+/// # Panics
+///
+/// Panics (at compile time, if used in a const context) if `vocab` has more
+/// than 64 entries.
+pub const fn parse_set(s: &[u8], vocab: &[&str]) -> Result<u64, ParseError> {
+    assert!(vocab.len() <= 64, "at most 64 words may be used with `parse_set`");
+    let mut set = 0u64;
+    let mut pos = 0;
+    while pos < s.len() {
+        let (start, end, next) = next_item(s, pos);
+        if start != end {
+            let mut i = 0;
+            loop {
+                if i == vocab.len() {
+                    return Err(ParseError::UnknownToken);
+                }
+                if eq_ignore_case(s, start, end, vocab[i].as_bytes()) {
+                    set |= 1 << i;
+                    break;
+                }
+                i += 1;
+            }
+        }
+        pos = next;
+    }
+    Ok(set)
+}
+
+/// Splits `s` at the first occurrence of `b`, returning the bytes before and
+/// after it (neither of which includes `b`), or `None` if `b` doesn't occur.
+///
+/// This is like [`str::split_once`] with a single byte, but usable in const, for
+/// writing your own parsers.
+///
+/// ```
+/// use envparse::parse::split_once;
+/// assert_eq!(split_once(b"a=b=c", b'='), Some((&b"a"[..], &b"b=c"[..])));
+/// assert_eq!(split_once(b"abc", b'='), None);
+/// ```
+pub const fn split_once(s: &[u8], b: u8) -> Option<(&[u8], &[u8])> {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == b {
+            let (head, tail) = s.split_at(i);
+            return Some((head, tail.split_at(1).1));
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Like [`split_once`], but splits at the last occurrence of `b`.
+///
+/// ```
+/// use envparse::parse::rsplit_once;
+/// assert_eq!(rsplit_once(b"a=b=c", b'='), Some((&b"a=b"[..], &b"c"[..])));
+/// ```
+pub const fn rsplit_once(s: &[u8], b: u8) -> Option<(&[u8], &[u8])> {
+    let mut i = s.len();
+    while i > 0 {
+        i -= 1;
+        if s[i] == b {
+            let (head, tail) = s.split_at(i);
+            return Some((head, tail.split_at(1).1));
+        }
+    }
+    None
+}
+
+/// Returns true if `pat` occurs anywhere in `s` (which is always the case if
+/// `pat` is empty). Like [`str::contains`], but usable in const.
+///
+/// ```
+/// use envparse::parse::contains;
+/// assert!(contains(b"key=value", b"="));
+/// assert!(!contains(b"key=value", b"=="));
+/// ```
+pub const fn contains(s: &[u8], pat: &[u8]) -> bool {
+    let mut i = 0;
+    while i + pat.len() <= s.len() {
+        if starts_with_at(s, i, pat) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns true if `haystack` begins with `needle`. Like [`slice::starts_with`],
+/// but usable in const, for writing your own parsers. Unlike [`strip_prefix`],
+/// this doesn't skip leading whitespace or ignore case.
+///
+/// ```
+/// use envparse::parse::starts_with;
+/// assert!(starts_with(b"https://example.com", b"https://"));
+/// assert!(!starts_with(b"http://example.com", b"https://"));
+/// assert!(starts_with(b"anything", b""));
+/// ```
+pub const fn starts_with(haystack: &[u8], needle: &[u8]) -> bool {
+    starts_with_at(haystack, 0, needle)
+}
+
+/// Returns true if `haystack` ends with `needle`. Like [`slice::ends_with`],
+/// but usable in const.
+///
+/// ```
+/// use envparse::parse::ends_with;
+/// assert!(ends_with(b"config.toml", b".toml"));
+/// assert!(!ends_with(b"config.TOML", b".toml"));
+/// ```
+pub const fn ends_with(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.len() >= needle.len() && starts_with_at(haystack, haystack.len() - needle.len(), needle)
+}
+
+/// Like [`starts_with`], but ignoring ASCII case.
+///
+/// ```
+/// use envparse::parse::starts_with_ignore_case;
+/// assert!(starts_with_ignore_case(b"0XFF", b"0x"));
+/// ```
+pub const fn starts_with_ignore_case(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.len() >= needle.len() && eq_ignore_case(haystack, 0, needle.len(), needle)
+}
+
+/// Like [`ends_with`], but ignoring ASCII case.
+///
+/// ```
+/// use envparse::parse::ends_with_ignore_case;
+/// assert!(ends_with_ignore_case(b"config.TOML", b".toml"));
+/// ```
+pub const fn ends_with_ignore_case(haystack: &[u8], needle: &[u8]) -> bool {
+    let len = haystack.len();
+    len >= needle.len() && eq_ignore_case(haystack, len - needle.len(), len, needle)
+}
+
+/// Strips one matching pair of brackets from around `s`, ignoring surrounding
+/// whitespace. The recognized pairs are `[...]`, `(...)` and `{...}`.
+///
+/// Returns `s` unchanged if it has no brackets around it, and `None` if it
+/// opens or closes with a bracket that isn't matched by the other end (like
+/// `[1, 2)` or `1, 2]`). Only the outermost pair is removed, and nothing inside
+/// is checked.
+///
+/// ```
+/// use envparse::parse::strip_brackets;
+/// assert_eq!(strip_brackets(b" [1, 2] "), Some(&b"1, 2"[..]));
+/// assert_eq!(strip_brackets(b"{a}"), Some(&b"a"[..]));
+/// assert_eq!(strip_brackets(b"1, 2"), Some(&b"1, 2"[..]));
+/// assert_eq!(strip_brackets(b"[1, 2)"), None);
+/// ```
+pub const fn strip_brackets(s: &[u8]) -> Option<&[u8]> {
+    let (start, end) = match trim_ws(s) {
+        Some(r) => r,
+        None => return Some(s),
+    };
+    let close = match s[start] {
+        b'[' => b']',
+        b'(' => b')',
+        b'{' => b'}',
+        _ => {
+            return match s[end - 1] {
+                b']' | b')' | b'}' => None,
+                _ => Some(s),
+            };
+        }
+    };
+    if end - start < 2 || s[end - 1] != close {
+        return None;
+    }
+    Some(subslice(s, start + 1, end - 1))
+}
+
+/// Returns true if the byte `b` is in the character class `class`, which is
+/// written like the inside of a regex `[...]`, without the brackets: a
+/// sequence of single bytes and inclusive ranges like `a-z`. A `-` at the
+/// start or end of the class stands for itself, and there's no escaping or
+/// negation. For example, `a-zA-Z0-9_-` matches ASCII letters, digits, `_`
+/// and `-`.
+///
+/// Matching is done byte by byte, so the class should be ASCII.
+///
+/// ```
+/// use envparse::parse::in_char_class;
+/// assert!(in_char_class(b"a-z_", b'q'));
+/// assert!(in_char_class(b"a-z_", b'_'));
+/// assert!(!in_char_class(b"a-z_", b'-'));
+/// assert!(in_char_class(b"a-z-", b'-'));
+/// ```
+pub const fn in_char_class(class: &[u8], b: u8) -> bool {
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == b'-' {
+            if class[i] <= b && b <= class[i + 2] {
+                return true;
+            }
+            i += 3;
+        } else {
+            if class[i] == b {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
+
+/// Returns the index of the first byte in `s` which isn't in the character
+/// class `class` (see [`in_char_class`] for the syntax), or `None` if they
+/// all are.
+///
+/// ```
+/// use envparse::parse::find_not_in_class;
+/// assert_eq!(find_not_in_class(b"my_name", b"a-z_"), None);
+/// assert_eq!(find_not_in_class(b"my-name", b"a-z_"), Some(2));
+/// ```
+pub const fn find_not_in_class(s: &[u8], class: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i < s.len() {
+        if !in_char_class(class, s[i]) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
 #[cfg(test)]
 mod test {
     extern crate alloc;
@@ -287,11 +2704,29 @@ mod test {
             .collect()
     }
 
+    /// The case-mangling in the tests below changes which byte gets reported
+    /// for `InvalidDigit`, so compare those case-insensitively.
+    fn lower_digit<T>(r: Result<T, ParseError>) -> Result<T, ParseError> {
+        match r {
+            Err(InvalidDigit { digit, radix }) => Err(InvalidDigit { digit: digit.to_ascii_lowercase(), radix }),
+            r => r,
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        use alloc::string::ToString;
+        assert_eq!(InvalidDigit { digit: b'2', radix: 2 }.to_string(), "invalid digit `2` for base 2");
+        assert_eq!(InvalidDigit { digit: b'\xff', radix: 10 }.to_string(), "invalid digit `\\xff` for base 10");
+        assert_eq!(parse_unsigned(b"0o19", 0, 100, false).unwrap_err().to_string(), "invalid digit `9` for base 8");
+        assert_eq!(OutOfRange.to_string(), "value is out of range");
+    }
+
     #[test]
     fn test_parse_unsigned() {
         #[track_caller]
         fn check(s: &str, res: Result<u128, ParseError>) {
-            assert_eq!(parse_unsigned(s.as_ref(), 0, u128::MAX, false), res, "input: {:?}", (s, res),);
+            assert_eq!(lower_digit(parse_unsigned(s.as_ref(), 0, u128::MAX, false)), res, "input: {:?}", (s, res),);
         }
 
         #[track_caller]
@@ -379,26 +2814,26 @@ mod test {
         err("-30", UnexpectedSign);
         err("-", UnexpectedSign);
         err("", Empty);
-        err("0x1234g", InvalidDigit);
-        err("0xg1234", InvalidDigit);
-        err("0x1234g1234", InvalidDigit);
-
-        err("123a", InvalidDigit);
-        err("a123", InvalidDigit);
-        err("123a123", InvalidDigit);
-
-        err("0o8", InvalidDigit);
-        err("0o128", InvalidDigit);
-        err("0o12812", InvalidDigit);
-        err("0o12a12", InvalidDigit);
-
-        err("0b2", InvalidDigit);
-        err("0b00200", InvalidDigit);
-        err("0b002", InvalidDigit);
-        err("0b200", InvalidDigit);
-        err("0b121", InvalidDigit);
-        err("0b12", InvalidDigit);
-        err("0b21", InvalidDigit);
+        err("0x1234g", InvalidDigit { digit: b'g', radix: 16 });
+        err("0xg1234", InvalidDigit { digit: b'g', radix: 16 });
+        err("0x1234g1234", InvalidDigit { digit: b'g', radix: 16 });
+
+        err("123a", InvalidDigit { digit: b'a', radix: 10 });
+        err("a123", InvalidDigit { digit: b'a', radix: 10 });
+        err("123a123", InvalidDigit { digit: b'a', radix: 10 });
+
+        err("0o8", InvalidDigit { digit: b'8', radix: 8 });
+        err("0o128", InvalidDigit { digit: b'8', radix: 8 });
+        err("0o12812", InvalidDigit { digit: b'8', radix: 8 });
+        err("0o12a12", InvalidDigit { digit: b'a', radix: 8 });
+
+        err("0b2", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b00200", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b002", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b200", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b121", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b12", InvalidDigit { digit: b'2', radix: 2 });
+        err("0b21", InvalidDigit { digit: b'2', radix: 2 });
 
         err("0o", NoDigits);
         err("0o_", NoDigits);
@@ -409,7 +2844,7 @@ mod test {
         err("0b", NoDigits);
         err("0b_", NoDigits);
         err("0b__", NoDigits);
-        err("_0b", InvalidDigit);
+        err("_0b", InvalidDigit { digit: b'b', radix: 10 });
         err("0o4000000000000000000000000000000000000000000", IntOverflow);
 
         err("0xffffffffffffffffffffffffffffffff0", IntOverflow);
@@ -438,6 +2873,10 @@ mod test {
         assert_eq!(parse_unsigned(b"-1", 0, u128::MAX, true), Ok(0));
 
         assert_eq!(parse_unsigned(b"-1", 0, u128::MAX, true), Ok(0));
+        assert_eq!(parse_unsigned(b" -0x10_000 ", 5, 10, true), Ok(5));
+        assert_eq!(parse_unsigned(b"-999999999999999999999999999999999999999999", 0, 10, true), Ok(0));
+        assert_eq!(parse_unsigned(b"-five", 0, 10, true), Err(InvalidDigit { digit: b'f', radix: 10 }));
+        assert_eq!(parse_unsigned(b"-", 0, 10, true), Err(NoDigits));
 
         assert_eq!(parse_unsigned(b"1000", 1, 255, false), Err(OutOfRange));
         assert_eq!(parse_unsigned(b"1000", 1, 255, true), Ok(255));
@@ -457,7 +2896,12 @@ mod test {
     fn test_parse_signed() {
         #[track_caller]
         fn check(s: &str, res: Result<i128, ParseError>) {
-            assert_eq!(parse_signed(s.as_ref(), i128::MIN, i128::MAX, false), res, "input: {:?}", (s, res),);
+            assert_eq!(
+                lower_digit(parse_signed(s.as_ref(), i128::MIN, i128::MAX, false)),
+                res,
+                "input: {:?}",
+                (s, res),
+            );
         }
 
         #[track_caller]
@@ -563,12 +3007,12 @@ mod test {
         err("1701411834604692317316873037158841057270", IntOverflow);
         err("", Empty);
         err("-", NoDigits);
-        err("0x1234z", InvalidDigit);
-        err("123f", InvalidDigit);
+        err("0x1234z", InvalidDigit { digit: b'z', radix: 16 });
+        err("123f", InvalidDigit { digit: b'f', radix: 10 });
 
-        err("0x1234z", InvalidDigit);
+        err("0x1234z", InvalidDigit { digit: b'z', radix: 16 });
 
-        err("123f", InvalidDigit);
+        err("123f", InvalidDigit { digit: b'f', radix: 10 });
 
         err("0o", NoDigits);
         err("0o_", NoDigits);
@@ -579,7 +3023,7 @@ mod test {
         err("0b", NoDigits);
         err("0b_", NoDigits);
         err("0b__", NoDigits);
-        err("_0b", InvalidDigit);
+        err("_0b", InvalidDigit { digit: b'b', radix: 10 });
         err("0o4000000000000000000000000000000000000000000", IntOverflow);
         err("0x7fffffffffffffffffffffffffffffff0", IntOverflow);
         err("0x70fffffffffffffffffffffffffffffff0", IntOverflow);
@@ -729,6 +3173,11 @@ mod test {
         ok("yes", true);
         ok("no", false);
 
+        ok("enable", true);
+        ok("enabled", true);
+        ok("disable", false);
+        ok("disabled", false);
+
         err("", Empty);
         err("foo", UnknownBoolValue);
 
@@ -738,5 +3187,1189 @@ mod test {
         err("defg", UnknownBoolValue);
         err("true1", UnknownBoolValue);
         err("0true1", UnknownBoolValue);
+        err("enables", UnknownBoolValue);
+        err("disabl", UnknownBoolValue);
+        err("enable d", UnknownBoolValue);
+    }
+
+    #[test]
+    fn test_parse_bool_tf() {
+        assert_eq!(parse_bool_tf(b"T"), Ok(true));
+        assert_eq!(parse_bool_tf(b"F"), Ok(false));
+        assert_eq!(parse_bool_tf(b""), Err(Empty));
+        for s in ["t", "f", " T", "F\n", "TF", "1", "0", "true", "yes", "y"] {
+            assert_eq!(parse_bool_tf(s.as_bytes()), Err(UnknownBoolValue), "input: {:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_parse_cased_size() {
+        #[track_caller]
+        fn check(s: &str, res: Result<u128, ParseError>) {
+            assert_eq!(parse_cased_size(s.as_ref(), 0, u128::MAX, false), res, "input: {:?}", (s, res));
+        }
+        check("4", Ok(4));
+        check("4k", Ok(4_000));
+        check("4K", Ok(4_096));
+        check(" 4 K ", Ok(4_096));
+        check("3m", Ok(3_000_000));
+        check("3M", Ok(3 << 20));
+        check("2g", Ok(2_000_000_000));
+        check("2G", Ok(2 << 30));
+        check("1t", Ok(1_000_000_000_000));
+        check("1T", Ok(1 << 40));
+        check("5p", Ok(5_000_000_000_000_000));
+        check("5P", Ok(5 << 50));
+        check("0x10K", Ok(16 << 10));
+        check("1_000k", Ok(1_000_000));
+
+        check("", Err(Empty));
+        check("k", Err(NoDigits));
+        check(" K", Err(NoDigits));
+        check("-4K", Err(UnexpectedSign));
+        check("4kb", Err(InvalidDigit { digit: b'k', radix: 10 }));
+        check("4Kk", Err(InvalidDigit { digit: b'K', radix: 10 }));
+        check("4x", Err(InvalidDigit { digit: b'x', radix: 10 }));
+        check("340282366920938463463374607431768211455K", Err(IntOverflow));
+
+        assert_eq!(parse_cased_size(b"4K", 0, 4095, false), Err(OutOfRange));
+        assert_eq!(parse_cased_size(b"4K", 0, 4095, true), Ok(4095));
+        assert_eq!(parse_cased_size(b"4k", 4001, 5000, true), Ok(4001));
+        assert_eq!(parse_cased_size(b"340282366920938463463374607431768211455K", 0, 100, true), Ok(100));
+    }
+
+    #[test]
+    fn test_parse_char() {
+        assert_eq!(parse_char(b"a"), Ok('a'));
+        assert_eq!(parse_char(b" a\t"), Ok('a'));
+        assert_eq!(parse_char(b" "), Ok(' '));
+        assert_eq!(parse_char(b"\t"), Ok('\t'));
+        assert_eq!(parse_char(b","), Ok(','));
+        assert_eq!(parse_char("é".as_bytes()), Ok('é'));
+        assert_eq!(parse_char(" \u{2603} ".as_bytes()), Ok('\u{2603}'));
+        assert_eq!(parse_char("\u{10ffff}".as_bytes()), Ok('\u{10ffff}'));
+
+        assert_eq!(parse_char(b""), Err(Empty));
+        assert_eq!(parse_char(b"  "), Err(Empty));
+        assert_eq!(parse_char(b"ab"), Err(NotAChar));
+        assert_eq!(parse_char(b" a b "), Err(NotAChar));
+        assert_eq!(parse_char("éé".as_bytes()), Err(NotAChar));
+        assert_eq!(parse_char(b"\xff"), Err(NotAChar));
+        assert_eq!(parse_char(b"\xc3"), Err(NotAChar));
+    }
+
+    #[test]
+    fn test_parse_ratio() {
+        #[track_caller]
+        fn check(s: &str, reduce: bool, res: Result<(i128, i128), ParseError>) {
+            assert_eq!(parse_ratio(s.as_ref(), reduce), res, "input: {:?}", (s, reduce, res));
+        }
+        check("-3/4", false, Ok((-3, 4)));
+        check(" +3 / 4 ", false, Ok((3, 4)));
+        check("6/8", false, Ok((6, 8)));
+        check("6/8", true, Ok((3, 4)));
+        check("-6/8", true, Ok((-3, 4)));
+        check("0/8", true, Ok((0, 1)));
+        check("0x10/0b100", true, Ok((4, 1)));
+        check("5", false, Ok((5, 1)));
+        check("-170141183460469231731687303715884105728/2", true, Ok((i128::MIN / 2, 1)));
+        check("1/170141183460469231731687303715884105727", true, Ok((1, i128::MAX)));
+
+        check("", false, Err(Empty));
+        check("  ", true, Err(Empty));
+        check("3/0", false, Err(ZeroDenominator));
+        check("3/0x0", true, Err(ZeroDenominator));
+        check("3/-4", false, Err(UnexpectedSign));
+        check("/4", false, Err(NoDigits));
+        check("3/", false, Err(NoDigits));
+        check("3/4/5", false, Err(InvalidDigit { digit: b'/', radix: 10 }));
+        check("1/170141183460469231731687303715884105728", false, Err(OutOfRange));
+    }
+
+    #[test]
+    fn test_parse_pages() {
+        #[track_caller]
+        fn check(s: &str, res: Result<u128, ParseError>) {
+            assert_eq!(parse_pages(s.as_ref(), 4096, 0, u32::MAX as u128, false), res, "input: {:?}", (s, res));
+        }
+        check("16", Ok(65536));
+        check(" 0x10 ", Ok(65536));
+        check("0", Ok(0));
+        check("1048575", Ok(u32::MAX as u128 - 4095));
+        check("1048576", Err(OutOfRange));
+        check("", Err(Empty));
+        check("-1", Err(UnexpectedSign));
+        check("4k", Err(InvalidDigit { digit: b'k', radix: 10 }));
+        assert_eq!(parse_pages(b"1", u128::MAX, 0, u128::MAX, false), Ok(u128::MAX));
+        assert_eq!(parse_pages(b"2", u128::MAX, 0, u128::MAX, false), Err(IntOverflow));
+        assert_eq!(parse_pages(b"2", u128::MAX, 0, 100, true), Ok(100));
+        assert_eq!(parse_pages(b"1", 4096, 8192, 100_000, true), Ok(8192));
+    }
+
+    #[test]
+    fn test_parse_degrees() {
+        #[track_caller]
+        fn check(s: &str, res: Result<i128, ParseError>) {
+            assert_eq!(lower_digit(parse_degrees(s.as_ref(), -360, 360, false)), res, "input: {:?}", (s, res));
+        }
+        check("90", Ok(90));
+        check("90deg", Ok(90));
+        check(" 90 DEG ", Ok(90));
+        check("-40°", Ok(-40));
+        check("-40 °\n", Ok(-40));
+        check("0x10deg", Ok(16));
+        check("360°", Ok(360));
+
+        check("", Err(Empty));
+        check("deg", Err(NoDigits));
+        check("°", Err(NoDigits));
+        check("-", Err(NoDigits));
+        check("361deg", Err(OutOfRange));
+        check("-361°", Err(OutOfRange));
+        check("90degdeg", Err(InvalidDigit { digit: b'd', radix: 10 }));
+        check("90rad", Err(InvalidDigit { digit: b'r', radix: 10 }));
+        check("90º", Err(InvalidDigit { digit: 0xc2, radix: 10 }));
+        assert_eq!(parse_degrees(b"400deg", -360, 360, true), Ok(360));
+    }
+
+    #[test]
+    fn test_parse_ipv4() {
+        use core::net::Ipv4Addr;
+        #[track_caller]
+        fn check(s: &str, res: Result<Ipv4Addr, ParseError>) {
+            assert_eq!(parse_ipv4(s.as_ref()), res, "input: {:?}", (s, res));
+        }
+        check("8.8.8.8", Ok(Ipv4Addr::new(8, 8, 8, 8)));
+        check(" 192.168.0.1\n", Ok(Ipv4Addr::new(192, 168, 0, 1)));
+        check("0.0.0.0", Ok(Ipv4Addr::UNSPECIFIED));
+        check("255.255.255.255", Ok(Ipv4Addr::BROADCAST));
+
+        check("", Err(Empty));
+        check("  ", Err(Empty));
+        check("1.2.3", Err(InvalidIpv4Addr));
+        check("1.2.3.", Err(InvalidIpv4Addr));
+        check("1.2.3.4.", Err(InvalidIpv4Addr));
+        check("1.2.3.4.5", Err(InvalidIpv4Addr));
+        check("1..3.4", Err(InvalidIpv4Addr));
+        check(".1.2.3", Err(InvalidIpv4Addr));
+        check("1.2.3.256", Err(InvalidIpv4Addr));
+        check("1.2.3.1000000000000", Err(InvalidIpv4Addr));
+        check("01.2.3.4", Err(InvalidIpv4Addr));
+        check("1.2.3.00", Err(InvalidIpv4Addr));
+        check("1.2.x.4", Err(InvalidDigit { digit: b'x', radix: 10 }));
+        check("1.2.3.4x", Err(InvalidDigit { digit: b'x', radix: 10 }));
+        check("1.2.3.4 5", Err(InvalidDigit { digit: b' ', radix: 10 }));
+        check("1,2.3.4", Err(InvalidDigit { digit: b',', radix: 10 }));
+        check("-1.2.3.4", Err(InvalidDigit { digit: b'-', radix: 10 }));
+    }
+
+    #[test]
+    fn test_number_cursor() {
+        #[track_caller]
+        fn check(s: &str, res: Result<&[u128], ParseError>) {
+            let mut got = alloc::vec::Vec::new();
+            let mut cursor = NumberCursor::new(s.as_bytes());
+            let got = loop {
+                match cursor.try_next() {
+                    Ok(Some((n, next))) => {
+                        got.push(n);
+                        cursor = next;
+                    }
+                    Ok(None) => break Ok(got),
+                    Err(e) => break Err(e),
+                }
+            };
+            assert_eq!(got.as_deref().map_err(|e| *e), res, "input: {:?}", s);
+        }
+        check("1 2 3", Ok(&[1, 2, 3]));
+        check("1,2,3", Ok(&[1, 2, 3]));
+        check(" 1 , 2\t\n3 ", Ok(&[1, 2, 3]));
+        check("1,2,3,", Ok(&[1, 2, 3]));
+        check("0x10 0b11,0o7", Ok(&[16, 3, 7]));
+        check("1_000", Ok(&[1000]));
+        check("", Ok(&[]));
+        check("   ", Ok(&[]));
+
+        check(",", Err(NoDigits));
+        check(",1", Err(NoDigits));
+        check("1,,2", Err(NoDigits));
+        check("1, ,2", Err(NoDigits));
+        check("1 -2", Err(UnexpectedSign));
+        check("1 2x", Err(InvalidDigit { digit: b'x', radix: 10 }));
+
+        let (a, c) = NumberCursor::new(b"4 5").next().unwrap();
+        let (b, c) = c.next().unwrap();
+        assert_eq!((a, b), (4, 5));
+        assert!(c.next().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_number_cursor_panic() {
+        NumberCursor::new(b"1 x").next().unwrap().1.next();
+    }
+
+    #[test]
+    fn test_parse_on_off_auto() {
+        use crate::OnOffAuto::*;
+        assert_eq!(parse_on_off_auto(b"on"), Ok(On));
+        assert_eq!(parse_on_off_auto(b" OFF "), Ok(Off));
+        assert_eq!(parse_on_off_auto(b"Auto"), Ok(Auto));
+        assert_eq!(parse_on_off_auto(b""), Err(Empty));
+        assert_eq!(parse_on_off_auto(b"  "), Err(Empty));
+        assert_eq!(parse_on_off_auto(b"true"), Err(UnknownToken));
+        assert_eq!(parse_on_off_auto(b"yes"), Err(UnknownToken));
+        assert_eq!(parse_on_off_auto(b"1"), Err(UnknownToken));
+        assert_eq!(parse_on_off_auto(b"automatic"), Err(UnknownToken));
+        assert_eq!(parse_on_off_auto(b"o n"), Err(UnknownToken));
+    }
+
+    #[test]
+    fn test_parse_sort_dir() {
+        use crate::SortDir::*;
+        assert_eq!(parse_sort_dir(b"asc"), Ok(Ascending));
+        assert_eq!(parse_sort_dir(b" Ascending "), Ok(Ascending));
+        assert_eq!(parse_sort_dir(b"<"), Ok(Ascending));
+        assert_eq!(parse_sort_dir(b"DESC"), Ok(Descending));
+        assert_eq!(parse_sort_dir(b"descending\n"), Ok(Descending));
+        assert_eq!(parse_sort_dir(b" > "), Ok(Descending));
+        assert_eq!(parse_sort_dir(b""), Err(Empty));
+        assert_eq!(parse_sort_dir(b"up"), Err(UnknownToken));
+        assert_eq!(parse_sort_dir(b"<="), Err(UnknownToken));
+        assert_eq!(parse_sort_dir(b"as c"), Err(UnknownToken));
+    }
+
+    #[test]
+    fn test_parse_prompt_policy() {
+        use crate::PromptPolicy::*;
+        assert_eq!(parse_prompt_policy(b"yes"), Ok(Always));
+        assert_eq!(parse_prompt_policy(b" ALWAYS "), Ok(Always));
+        assert_eq!(parse_prompt_policy(b"No"), Ok(Never));
+        assert_eq!(parse_prompt_policy(b"never\n"), Ok(Never));
+        assert_eq!(parse_prompt_policy(b"ask"), Ok(Ask));
+        assert_eq!(parse_prompt_policy(b"Prompt"), Ok(Ask));
+        assert_eq!(parse_prompt_policy(b""), Err(Empty));
+        assert_eq!(parse_prompt_policy(b"true"), Err(UnknownToken));
+        assert_eq!(parse_prompt_policy(b"1"), Err(UnknownToken));
+        assert_eq!(parse_prompt_policy(b"y"), Err(UnknownToken));
+    }
+
+    #[test]
+    fn test_parse_set() {
+        const VOCAB: &[&str] = &["alpha", "beta", "gamma"];
+        #[track_caller]
+        fn check(s: &str, res: Result<u64, ParseError>) {
+            assert_eq!(parse_set(s.as_ref(), VOCAB), res, "input: {:?}", (s, res));
+        }
+        check("", Ok(0));
+        check("   ", Ok(0));
+        check(",", Ok(0));
+        check("alpha", Ok(0b001));
+        check("beta", Ok(0b010));
+        check("alpha,gamma", Ok(0b101));
+        check(" gamma , ALPHA ", Ok(0b101));
+        check("alpha,beta,gamma,", Ok(0b111));
+        check("beta,beta,,beta", Ok(0b010));
+        check("delta", Err(UnknownToken));
+        check("alpha,delta", Err(UnknownToken));
+        check("alpha gamma", Err(UnknownToken));
+        check("alph", Err(UnknownToken));
+        check("alphaa", Err(UnknownToken));
+        assert_eq!(parse_set(b"", &[]), Ok(0));
+        assert_eq!(parse_set(b"x", &[]), Err(UnknownToken));
+    }
+
+    #[test]
+    fn test_split_once() {
+        const EMPTY: &[u8] = b"";
+        assert_eq!(split_once(b"", b'='), None);
+        assert_eq!(split_once(b"abc", b'='), None);
+        assert_eq!(split_once(b"a=b", b'='), Some((&b"a"[..], &b"b"[..])));
+        assert_eq!(split_once(b"a=b=c", b'='), Some((&b"a"[..], &b"b=c"[..])));
+        assert_eq!(split_once(b"=abc", b'='), Some((EMPTY, &b"abc"[..])));
+        assert_eq!(split_once(b"abc=", b'='), Some((&b"abc"[..], EMPTY)));
+        assert_eq!(split_once(b"=", b'='), Some((EMPTY, EMPTY)));
+        assert_eq!(split_once(b"==", b'='), Some((EMPTY, &b"="[..])));
+
+        assert_eq!(rsplit_once(b"", b'='), None);
+        assert_eq!(rsplit_once(b"abc", b'='), None);
+        assert_eq!(rsplit_once(b"a=b", b'='), Some((&b"a"[..], &b"b"[..])));
+        assert_eq!(rsplit_once(b"a=b=c", b'='), Some((&b"a=b"[..], &b"c"[..])));
+        assert_eq!(rsplit_once(b"=abc", b'='), Some((EMPTY, &b"abc"[..])));
+        assert_eq!(rsplit_once(b"abc=", b'='), Some((&b"abc"[..], EMPTY)));
+        assert_eq!(rsplit_once(b"=", b'='), Some((EMPTY, EMPTY)));
+        assert_eq!(rsplit_once(b"==", b'='), Some((&b"="[..], EMPTY)));
+
+        const SPLIT: Option<(&[u8], &[u8])> = split_once(b"key:value", b':');
+        assert_eq!(SPLIT, Some((&b"key"[..], &b"value"[..])));
+    }
+
+    #[test]
+    fn test_parse_rate() {
+        #[track_caller]
+        fn check(s: &str, res: Result<u128, ParseError>) {
+            assert_eq!(lower_digit(parse_rate(s.as_ref(), 0, u64::MAX as u128, false)), res, "input: {:?}", (s, res));
+        }
+        check("10MB/s", Ok(10 << 20));
+        check("10M/s", Ok(10 << 20));
+        check("10mB/s", Ok(10_000_000));
+        check("100/s", Ok(100));
+        check(" 4 KB / S ", Ok(4096));
+        check("1k/ms", Ok(1_000_000));
+        check("120/min", Ok(2));
+        check("119/min", Ok(1));
+        check("7200B/h", Ok(2));
+        check("0/s", Ok(0));
+        check("0x10/s", Ok(16));
+
+        check("", Err(Empty));
+        check("  ", Err(Empty));
+        check("10MB", Err(MissingRateUnit));
+        check("10", Err(MissingRateUnit));
+        check("10MB/", Err(UnknownRateUnit));
+        check("10MB/d", Err(UnknownRateUnit));
+        check("10MB/s/s", Err(UnknownRateUnit));
+        check("/s", Err(NoDigits));
+        check("B/s", Err(NoDigits));
+        check("10Mb/s", Err(InvalidDigit { digit: b'm', radix: 10 }));
+        check("-1/s", Err(UnexpectedSign));
+        check("16777216P/s", Err(OutOfRange));
+        assert_eq!(parse_rate(b"16777216P/s", 0, 1000, true), Ok(1000));
+        assert_eq!(parse_rate(b"300000000000000000000000000000000000000/ms", 0, 1000, false), Err(IntOverflow));
+        assert_eq!(parse_rate(b"300000000000000000000000000000000000000/ms", 0, 1000, true), Ok(1000));
+    }
+
+    #[test]
+    fn test_require_hex_prefix() {
+        assert_eq!(require_hex_prefix(b"0xdeadbeef"), Ok(()));
+        assert_eq!(require_hex_prefix(b" 0XFF "), Ok(()));
+        assert_eq!(require_hex_prefix(b"-0x10"), Ok(()));
+        assert_eq!(require_hex_prefix(b"+0x10"), Ok(()));
+        // The digits are left for the number parser to complain about.
+        assert_eq!(require_hex_prefix(b"0x"), Ok(()));
+        assert_eq!(require_hex_prefix(b"0xzz"), Ok(()));
+
+        assert_eq!(require_hex_prefix(b""), Err(Empty));
+        assert_eq!(require_hex_prefix(b"  "), Err(Empty));
+        assert_eq!(require_hex_prefix(b"deadbeef"), Err(MissingHexPrefix));
+        assert_eq!(require_hex_prefix(b"123"), Err(MissingHexPrefix));
+        assert_eq!(require_hex_prefix(b"0"), Err(MissingHexPrefix));
+        assert_eq!(require_hex_prefix(b"0b101"), Err(MissingHexPrefix));
+        assert_eq!(require_hex_prefix(b"x10"), Err(MissingHexPrefix));
+        assert_eq!(require_hex_prefix(b"-"), Err(MissingHexPrefix));
+    }
+
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(Empty.kind(), ErrorKind::Empty);
+        assert_eq!(OutOfRange.kind(), ErrorKind::OutOfRange);
+        assert_eq!(IntOverflow.kind(), ErrorKind::OutOfRange);
+        assert_eq!(InvalidDigit { digit: b'x', radix: 10 }.kind(), ErrorKind::Invalid);
+        assert_eq!(UnexpectedSign.kind(), ErrorKind::Invalid);
+        assert_eq!(MissingHexPrefix.kind(), ErrorKind::Invalid);
+        assert_eq!(parse_signed(b"  ", 0, 10, false).unwrap_err().kind(), ErrorKind::Empty);
+        assert_eq!(parse_signed(b"-11", -10, 10, false).unwrap_err().kind(), ErrorKind::OutOfRange);
+        assert_eq!(parse_ratio(b"1/0", false).unwrap_err().kind(), ErrorKind::Invalid);
+        const KIND: ErrorKind = ParseError::NotAChar.kind();
+        assert_eq!(KIND, ErrorKind::Invalid);
+    }
+
+    #[test]
+    fn test_parse_hex_digits() {
+        #[track_caller]
+        fn check(s: &str, digits: usize, res: Result<u128, ParseError>) {
+            assert_eq!(parse_hex_digits(s.as_ref(), digits, u32::MAX as u128), res, "input: {:?}", (s, digits, res));
+        }
+        check("abc123", 6, Ok(0xabc123));
+        check("0xABC123", 6, Ok(0xabc123));
+        check(" 0X00ff00 ", 6, Ok(0xff00));
+        check("000000", 6, Ok(0));
+        check("deadbeef", 8, Ok(0xdeadbeef));
+
+        check("", 6, Err(Empty));
+        check("abc12", 6, Err(WrongCount { expected: 6, found: 5 }));
+        check("abc1234", 6, Err(WrongCount { expected: 6, found: 7 }));
+        check("0x", 6, Err(WrongCount { expected: 6, found: 0 }));
+        check("0xabc12", 6, Err(WrongCount { expected: 6, found: 5 }));
+        check("ab_c12", 6, Err(InvalidDigit { digit: b'_', radix: 16 }));
+        check("abcx12", 6, Err(InvalidDigit { digit: b'x', radix: 16 }));
+        check("-abc12", 6, Err(InvalidDigit { digit: b'-', radix: 16 }));
+        check("100000000", 9, Err(OutOfRange));
+        assert_eq!(parse_hex_digits(&[b'f'; 33], 33, u128::MAX), Err(IntOverflow));
+        assert_eq!(parse_hex_digits(&[b'f'; 32], 32, u128::MAX), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn test_parse_bool_sign() {
+        assert_eq!(parse_bool_sign(b"+"), Ok(true));
+        assert_eq!(parse_bool_sign(b" - "), Ok(false));
+        assert_eq!(parse_bool_sign(b""), Err(Empty));
+        assert_eq!(parse_bool_sign(b"+e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign(b"++"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign(b"+-"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign(b"1"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign(b"true"), Err(UnknownBoolValue));
+
+        assert_eq!(parse_bool_sign_named(b"+e", "e"), Ok(true));
+        assert_eq!(parse_bool_sign_named(b"-e\n", "e"), Ok(false));
+        assert_eq!(parse_bool_sign_named(b"+", "e"), Ok(true));
+        assert_eq!(parse_bool_sign_named(b"-", "e"), Ok(false));
+        assert_eq!(parse_bool_sign_named(b"+errexit", "errexit"), Ok(true));
+        assert_eq!(parse_bool_sign_named(b"", "e"), Err(Empty));
+        assert_eq!(parse_bool_sign_named(b"+E", "e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign_named(b"+ e", "e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign_named(b"+x", "e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign_named(b"+ee", "e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign_named(b"e", "e"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool_sign_named(b"+-e", "e"), Err(UnknownBoolValue));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use core::time::Duration;
+        const NS: u64 = 1;
+        const US: u64 = 1_000 * NS;
+        const MS: u64 = 1_000 * US;
+        const S: u64 = 1_000 * MS;
+        const M: u64 = 60 * S;
+        const H: u64 = 60 * M;
+        #[track_caller]
+        fn check(s: &str, nanos: u64) {
+            assert_eq!(parse_duration(s.as_ref()), Ok(Duration::from_nanos(nanos)), "input: {:?}", s);
+        }
+        // These mirror the cases in Go's `time.ParseDuration` tests.
+        check("0", 0);
+        check("5s", 5 * S);
+        check("30s", 30 * S);
+        check("1478s", 1478 * S);
+        check("+5s", 5 * S);
+        check("+0", 0);
+        check("5.0s", 5 * S);
+        check("5.6s", 5 * S + 600 * MS);
+        check("5.s", 5 * S);
+        check(".5s", 500 * MS);
+        check("1.0s", S);
+        check("1.00s", S);
+        check("1.004s", S + 4 * MS);
+        check("1.0040s", S + 4 * MS);
+        check("100.00100s", 100 * S + MS);
+        check("10ns", 10 * NS);
+        check("11us", 11 * US);
+        check("12µs", 12 * US);
+        check("12μs", 12 * US);
+        check("13ms", 13 * MS);
+        check("14s", 14 * S);
+        check("15m", 15 * M);
+        check("16h", 16 * H);
+        check("3h30m", 3 * H + 30 * M);
+        check("10.5s4m", 4 * M + 10 * S + 500 * MS);
+        check("1h2m3s4ms5us6ns", H + 2 * M + 3 * S + 4 * MS + 5 * US + 6 * NS);
+        check("39h9m14.425s", 39 * H + 9 * M + 14 * S + 425 * MS);
+        check("52763797000ns", 52763797000 * NS);
+        // Go gets exactly 20m here, from float rounding.
+        check("0.3333333333333333333h", 20 * M - NS);
+        check("9007199254740993ns", (1 << 53) + 1);
+        check("9223372036854775807ns", i64::MAX as u64);
+        check("9223372036854775.807us", i64::MAX as u64);
+        check("9223372036s854ms775us807ns", i64::MAX as u64);
+        check("0.100000000000000000000h", 6 * M);
+        check("0.830103483285477580700h", 49 * M + 48 * S + 372539827 * NS);
+        check("1.5h", H + 30 * M);
+        check(" 1h30m\n", H + 30 * M);
+        // Past what Go allows, since `Duration` can hold it.
+        check("9223372036854775808ns", i64::MAX as u64 + 1);
+        assert_eq!(parse_duration(b"18446744073709551615s"), Ok(Duration::from_secs(u64::MAX)));
+
+        assert_eq!(parse_duration(b""), Err(Empty));
+        assert_eq!(parse_duration(b"3"), Err(MissingTimeUnit));
+        assert_eq!(parse_duration(b"1h3"), Err(MissingTimeUnit));
+        assert_eq!(parse_duration(b"-"), Err(UnexpectedSign));
+        assert_eq!(parse_duration(b"-5s"), Err(UnexpectedSign));
+        assert_eq!(parse_duration(b"s"), Err(NoDigits));
+        assert_eq!(parse_duration(b"."), Err(NoDigits));
+        assert_eq!(parse_duration(b".s"), Err(NoDigits));
+        assert_eq!(parse_duration(b"+.s"), Err(NoDigits));
+        assert_eq!(parse_duration(b"+"), Err(NoDigits));
+        assert_eq!(parse_duration(b"1d"), Err(UnknownTimeUnit));
+        assert_eq!(parse_duration(b"1S"), Err(UnknownTimeUnit));
+        assert_eq!(parse_duration(b"1h 30m"), Err(UnknownTimeUnit));
+        assert_eq!(parse_duration(b"\x85\x85"), Err(NoDigits));
+        assert_eq!(parse_duration(b"1\xffff"), Err(UnknownTimeUnit));
+        assert_eq!(parse_duration(b"18446744073709551616s"), Err(IntOverflow));
+        assert_eq!(parse_duration(b"999999999999999999999999999999999999999h"), Err(IntOverflow));
+        assert_eq!(parse_duration(b"340282366920938463463374607431768211459ns"), Err(IntOverflow));
+        assert_eq!(parse_duration(b"94522879700260684295381835.5h"), Err(IntOverflow));
+        assert_eq!(parse_duration(b"0.9999999999999999999999999999999h"), Ok(Duration::from_nanos(H - 1)));
+    }
+
+    #[test]
+    #[cfg(feature = "bool-i18n")]
+    fn test_parse_bool_i18n() {
+        assert_eq!(parse_bool(b"oui"), Ok(true));
+        assert_eq!(parse_bool(b" NON "), Ok(false));
+        assert_eq!(parse_bool(b"Ja"), Ok(true));
+        assert_eq!(parse_bool(b"nein"), Ok(false));
+        assert_eq!(parse_bool(b"nee"), Ok(false));
+        assert_eq!(parse_bool("sí".as_bytes()), Ok(true));
+        assert_eq!(parse_bool("sì".as_bytes()), Ok(true));
+        assert_eq!(parse_bool(b"si"), Ok(true));
+        assert_eq!(parse_bool(b"sim"), Ok(true));
+        assert_eq!(parse_bool("não".as_bytes()), Ok(false));
+        assert_eq!(parse_bool(b"falso"), Ok(false));
+        assert_eq!(parse_bool(b"verdadeiro"), Ok(true));
+        // The usual words still work, and other things are still rejected.
+        assert_eq!(parse_bool(b"no"), Ok(false));
+        assert_eq!(parse_bool(b"yes"), Ok(true));
+        assert_eq!(parse_bool(b"ouii"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool(b""), Err(Empty));
+        // Only ASCII case is ignored.
+        assert_eq!(parse_bool("SÍ".as_bytes()), Err(UnknownBoolValue));
+
+        for &(lang, yes, no) in BOOL_I18N_TOKENS {
+            for w in yes {
+                assert_eq!(parse_bool(w.as_bytes()), Ok(true), "{}: {}", lang, w);
+            }
+            for w in no {
+                assert_eq!(parse_bool(w.as_bytes()), Ok(false), "{}: {}", lang, w);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "bool-i18n"))]
+    fn test_parse_bool_no_i18n() {
+        assert_eq!(parse_bool(b"oui"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool(b"ja"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool(b"nein"), Err(UnknownBoolValue));
+    }
+
+    #[test]
+    fn test_parse_fill() {
+        assert_eq!(parse_fill::<4>(b"0xAA*4"), Ok([0xaa; 4]));
+        assert_eq!(parse_fill::<3>(b" 255 * 0x3 "), Ok([255; 3]));
+        assert_eq!(parse_fill::<2>(b"0*2"), Ok([0; 2]));
+        assert_eq!(parse_fill::<1>(b"9"), Ok([9]));
+        assert_eq!(parse_fill::<0>(b"1*0"), Ok([]));
+        assert_eq!(parse_fill::<4>(b"9"), Err(WrongCount { expected: 4, found: 1 }));
+        assert_eq!(parse_fill::<4>(b"1*5"), Err(WrongCount { expected: 4, found: 5 }));
+        assert_eq!(
+            parse_fill::<4>(b"1*0xffff_ffff_ffff_ffff_ffff"),
+            Err(WrongCount { expected: 4, found: usize::MAX })
+        );
+        assert_eq!(parse_fill::<4>(b"256*4"), Err(OutOfRange));
+        assert_eq!(parse_fill::<4>(b"-1*4"), Err(UnexpectedSign));
+        assert_eq!(parse_fill::<4>(b"1*-4"), Err(UnexpectedSign));
+        assert_eq!(parse_fill::<4>(b"1*4*4"), Err(InvalidDigit { digit: b'*', radix: 10 }));
+        assert_eq!(parse_fill::<4>(b"*4"), Err(Empty));
+        assert_eq!(parse_fill::<4>(b"1*"), Err(Empty));
+        assert_eq!(parse_fill::<4>(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_char_class() {
+        assert!(contains(b"abc", b""));
+        assert!(contains(b"abc", b"abc"));
+        assert!(contains(b"abc", b"c"));
+        assert!(!contains(b"abc", b"abcd"));
+        assert!(!contains(b"", b"a"));
+
+        let ident = b"a-zA-Z0-9_";
+        for b in 0..=255u8 {
+            assert_eq!(in_char_class(ident, b), b.is_ascii_alphanumeric() || b == b'_', "{}", b);
+        }
+        assert!(in_char_class(b"-a", b'-'));
+        assert!(in_char_class(b"a-", b'-'));
+        assert!(!in_char_class(b"a-c", b'-'));
+        assert!(in_char_class(b"+--", b','));
+        assert!(!in_char_class(b"z-a", b'm'));
+        assert!(!in_char_class(b"", b'a'));
+
+        assert_eq!(find_not_in_class(b"", b"a-z"), None);
+        assert_eq!(find_not_in_class(b"abc", b"a-z"), None);
+        assert_eq!(find_not_in_class(b"ab c", b"a-z"), Some(2));
+        assert_eq!(find_not_in_class("aé".as_bytes(), b"a-z"), Some(1));
+    }
+
+    #[test]
+    fn test_next_item_loose() {
+        fn items(s: &str) -> alloc::vec::Vec<&str> {
+            let b = s.as_bytes();
+            let mut out = alloc::vec::Vec::new();
+            let mut pos = skip_loose_seps(b, 0);
+            while pos < b.len() {
+                let (start, end, next) = next_item_loose(b, pos);
+                out.push(&s[start..end]);
+                pos = next;
+            }
+            out
+        }
+        assert_eq!(items("1,2,3"), ["1", "2", "3"]);
+        assert_eq!(items("1 2 3"), ["1", "2", "3"]);
+        assert_eq!(items(" 1, 2 ,\t3\n"), ["1", "2", "3"]);
+        assert_eq!(items(",,1,,,2,"), ["1", "2"]);
+        assert_eq!(items("one"), ["one"]);
+        assert!(items("").is_empty());
+        assert!(items(" , ,").is_empty());
+    }
+
+    #[test]
+    fn test_parse_duration_parts() {
+        assert_eq!(parse_duration_parts(b"0"), Ok((0, 0)));
+        assert_eq!(parse_duration_parts(b"1ns"), Ok((0, 1)));
+        assert_eq!(parse_duration_parts(b"2.5s"), Ok((2, 500_000_000)));
+        // More than a `u64` of nanoseconds (about 584 years).
+        assert_eq!(parse_duration_parts(b"5200000h1ns"), Ok((18_720_000_000, 1)));
+        assert_eq!(parse_duration_parts(b"18446744073709551615s999999999ns"), Ok((u64::MAX, 999_999_999)));
+        assert_eq!(parse_duration_parts(b"18446744073709551615s1000000000ns"), Err(IntOverflow));
+        assert_eq!(parse_duration_parts(b"-1s"), Err(UnexpectedSign));
+        assert_eq!(parse_duration_parts(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version(b"1.2.3"), Ok((1, 2, 3)));
+        assert_eq!(parse_version(b"v1.2.3"), Ok((1, 2, 3)));
+        assert_eq!(parse_version(b" V0.10.0\n"), Ok((0, 10, 0)));
+        assert_eq!(parse_version(b"18446744073709551615.0.0"), Ok((u64::MAX, 0, 0)));
+        assert_eq!(parse_version(b"vv1.2.3"), Err(InvalidDigit { digit: b'v', radix: 10 }));
+        assert_eq!(parse_version(b"1.2.3v"), Err(InvalidDigit { digit: b'v', radix: 10 }));
+        assert_eq!(parse_version(b"v"), Err(InvalidVersion));
+        assert_eq!(parse_version(b"1.2"), Err(InvalidVersion));
+        assert_eq!(parse_version(b"1.2.3.4"), Err(InvalidVersion));
+        assert_eq!(parse_version(b"1..3"), Err(InvalidVersion));
+        assert_eq!(parse_version(b"01.2.3"), Err(InvalidVersion));
+        assert_eq!(parse_version(b"1.2.3-rc.1"), Err(InvalidDigit { digit: b'-', radix: 10 }));
+        assert_eq!(parse_version(b"1.2.3+build"), Err(InvalidDigit { digit: b'+', radix: 10 }));
+        assert_eq!(parse_version(b"v 1.2.3"), Err(InvalidDigit { digit: b' ', radix: 10 }));
+        assert_eq!(parse_version(b"18446744073709551616.0.0"), Err(IntOverflow));
+        assert_eq!(parse_version(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(bounds::U8, (0, 255));
+        assert_eq!(bounds::U128, (0, u128::MAX));
+        assert_eq!(bounds::I8, (-128, 127));
+        assert_eq!(bounds::ISIZE, (isize::MIN as i128, isize::MAX as i128));
+        let (min, max) = bounds::I16;
+        assert_eq!(parse_signed(b"-40000", min, max, true), Ok(-32768));
+    }
+
+    #[test]
+    fn test_parse_strbuf() {
+        assert_eq!(parse_strbuf::<4>(b""), Ok(([0; 4], 0)));
+        assert_eq!(parse_strbuf::<4>(b" ab "), Ok((*b" ab ", 4)));
+        assert_eq!(parse_strbuf::<4>(b"\xc3\xa9"), Ok((*b"\xc3\xa9\0\0", 2)));
+        assert_eq!(parse_strbuf::<4>(br#""""#), Ok(([0; 4], 0)));
+        assert_eq!(parse_strbuf::<4>(br#"  "a b"  "#), Ok((*b"a b\0", 3)));
+        assert_eq!(parse_strbuf::<8>(br#""\n\r\t\0\\\"\'""#), Ok((*b"\n\r\t\0\\\"'\0", 7)));
+        assert_eq!(parse_strbuf::<2>(br#""\x1b\x7F""#), Ok(([0x1b, 0x7f], 2)));
+        assert_eq!(parse_strbuf::<2>(b"abc"), Err(OutOfRange));
+        assert_eq!(parse_strbuf::<2>(br#""a\tb""#), Err(OutOfRange));
+        assert_eq!(parse_strbuf::<4>(br#""\q""#), Err(InvalidEscape { byte: b'q' }));
+        assert_eq!(parse_strbuf::<4>(br#""\x80""#), Err(InvalidEscape { byte: b'x' }));
+        assert_eq!(parse_strbuf::<4>(br#""\x1""#), Err(InvalidEscape { byte: b'x' }));
+        assert_eq!(parse_strbuf::<4>(br#""abc"#), Err(UnclosedQuote));
+        assert_eq!(parse_strbuf::<4>(br#""abc\""#), Err(UnclosedQuote));
+        assert_eq!(parse_strbuf::<4>(br#""ab"c"#), Err(TextAfterQuote));
+        assert_eq!(parse_strbuf::<4>(br#"""#), Err(UnclosedQuote));
+    }
+
+    #[test]
+    fn test_parse_relative() {
+        let max = u8::MAX as u128;
+        assert_eq!(parse_relative(b"N", 8, max), Ok(8));
+        assert_eq!(parse_relative(b" n ", 8, max), Ok(8));
+        assert_eq!(parse_relative(b"N-1", 8, max), Ok(7));
+        assert_eq!(parse_relative(b"N - 10", 8, max), Ok(0));
+        assert_eq!(parse_relative(b"N/2", 8, max), Ok(4));
+        assert_eq!(parse_relative(b"N/3", 8, max), Ok(2));
+        assert_eq!(parse_relative(b"N+1", 8, max), Ok(9));
+        assert_eq!(parse_relative(b"N*100", 8, max), Ok(255));
+        assert_eq!(parse_relative(b"N+0x100000000000000000000000000000000", 8, max), Ok(255));
+        assert_eq!(parse_relative(b"12", 8, max), Ok(12));
+        assert_eq!(parse_relative(b"300", 8, max), Err(OutOfRange));
+        assert_eq!(parse_relative(b"N/0", 8, max), Err(ZeroDenominator));
+        assert_eq!(parse_relative(b"N%2", 8, max), Err(UnknownOperator { op: b'%' }));
+        assert_eq!(parse_relative(b"N1", 8, max), Err(UnknownOperator { op: b'1' }));
+        assert_eq!(parse_relative(b"N-", 8, max), Err(NoDigits));
+        assert_eq!(parse_relative(b"N--1", 8, max), Err(UnexpectedSign));
+        assert_eq!(parse_relative(b"M-1", 8, max), Err(InvalidDigit { digit: b'M', radix: 10 }));
+        assert_eq!(parse_relative(b"", 8, max), Err(Empty));
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        assert_eq!(strip_prefix(b"default:30", b"default:"), Some(&b"30"[..]));
+        assert_eq!(strip_prefix(b"  DEFAULT: 30 ", b"default:"), Some(&b" 30 "[..]));
+        assert_eq!(strip_prefix(b"default:", b"default:"), Some(&b""[..]));
+        assert_eq!(strip_prefix(b"defaul", b"default:"), None);
+        assert_eq!(strip_prefix(b"30default:", b"default:"), None);
+        assert_eq!(strip_prefix(b"", b""), Some(&b""[..]));
+    }
+
+    #[test]
+    fn test_has_item() {
+        assert!(has_item(b"a,b,c", b"b"));
+        assert!(has_item(b" a , B , c ", b"b"));
+        assert!(has_item(b"a,b,c,", b"c"));
+        assert!(!has_item(b"a,bb,c", b"b"));
+        assert!(!has_item(b"a b,c", b"b"));
+        assert!(!has_item(b"", b"b"));
+        assert!(has_item(b"a,,c", b""));
+    }
+
+    #[test]
+    fn test_parse_char_code() {
+        assert_eq!(parse_char_code(b"U+003B"), Ok(';'));
+        assert_eq!(parse_char_code(b" u+3b "), Ok(';'));
+        assert_eq!(parse_char_code(b"0x3B"), Ok(';'));
+        assert_eq!(parse_char_code(b"59"), Ok(';'));
+        assert_eq!(parse_char_code(b"0"), Ok('\0'));
+        assert_eq!(parse_char_code(b"U+10FFFF"), Ok(char::MAX));
+        assert_eq!(parse_char_code(b"U+110000"), Err(OutOfRange));
+        assert_eq!(parse_char_code(b"U+0000000000000000041"), Ok('A'));
+        assert_eq!(parse_char_code(b"U+FFFFFFFFFFFFFFFFFFFFFF"), Err(OutOfRange));
+        assert_eq!(parse_char_code(b"0x110000"), Err(OutOfRange));
+        assert_eq!(parse_char_code(b"0xffffffffffffffffffffffffffffffffff"), Err(OutOfRange));
+        assert_eq!(parse_char_code(b"U+D800"), Err(SurrogateCodePoint));
+        assert_eq!(parse_char_code(b"0xDFFF"), Err(SurrogateCodePoint));
+        assert_eq!(parse_char_code(b"U+"), Err(NoDigits));
+        assert_eq!(parse_char_code(b"U+3G"), Err(InvalidDigit { digit: b'G', radix: 16 }));
+        assert_eq!(parse_char_code(b"U+ 3B"), Err(InvalidDigit { digit: b' ', radix: 16 }));
+        assert_eq!(parse_char_code(b"-1"), Err(UnexpectedSign));
+        assert_eq!(parse_char_code(b";"), Err(InvalidDigit { digit: b';', radix: 10 }));
+        assert_eq!(parse_char_code(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_parse_env_assignment() {
+        assert_eq!(parse_env_assignment(b"KEY=value"), Some((&b"KEY"[..], &b"value"[..])));
+        assert_eq!(parse_env_assignment(b"  _key_2 = value "), Some((&b"_key_2"[..], &b" value "[..])));
+        assert_eq!(parse_env_assignment(b"KEY="), Some((&b"KEY"[..], &b""[..])));
+        assert_eq!(parse_env_assignment(b"KEY==x"), Some((&b"KEY"[..], &b"=x"[..])));
+        assert_eq!(parse_env_assignment(b"KEY"), None);
+        assert_eq!(parse_env_assignment(b"=value"), None);
+        assert_eq!(parse_env_assignment(b" =value"), None);
+        assert_eq!(parse_env_assignment(b"9KEY=value"), None);
+        assert_eq!(parse_env_assignment(b"MY KEY=value"), None);
+        assert_eq!(parse_env_assignment(b"MY-KEY=value"), None);
+        assert_eq!(parse_env_assignment(b"export KEY=value"), None);
+    }
+
+    #[test]
+    fn test_split_flag() {
+        assert_eq!(split_flag(b"on:256"), Ok((true, Some(&b"256"[..]))));
+        assert_eq!(split_flag(b" ON : 256 "), Ok((true, Some(&b" 256 "[..]))));
+        assert_eq!(split_flag(b"1:"), Ok((true, Some(&b""[..]))));
+        assert_eq!(split_flag(b"on"), Ok((true, None)));
+        assert_eq!(split_flag(b"false"), Ok((false, None)));
+        assert_eq!(split_flag(b"off:1"), Err(UnexpectedArgument));
+        assert_eq!(split_flag(b"maybe:1"), Err(UnknownBoolValue));
+        assert_eq!(split_flag(b":1"), Err(UnknownBoolValue));
+        assert_eq!(split_flag(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_next_word() {
+        assert_eq!(next_word(b"one"), Some((&b"one"[..], &b""[..])));
+        assert_eq!(next_word(b"\t one  two"), Some((&b"one"[..], &b"  two"[..])));
+        assert_eq!(next_word(b"a,b c"), Some((&b"a,b"[..], &b" c"[..])));
+        assert_eq!(next_word(b""), None);
+        assert_eq!(next_word(b" \t\n "), None);
+
+        let mut rest: &[u8] = b" \tfast  \t safe\t\tquiet \n";
+        let mut words = [&b""[..]; 3];
+        let mut n = 0;
+        while let Some((word, r)) = next_word(rest) {
+            words[n] = word;
+            n += 1;
+            rest = r;
+        }
+        assert_eq!(words, [&b"fast"[..], b"safe", b"quiet"]);
+    }
+
+    #[test]
+    fn test_parse_basis_points() {
+        let bp = |s: &str| parse_basis_points(s.as_bytes(), i64::MIN as i128, i64::MAX as i128, false);
+        assert_eq!(bp("0.0325"), Ok(325));
+        assert_eq!(bp(" +0.0325 "), Ok(325));
+        assert_eq!(bp("-0.0325"), Ok(-325));
+        assert_eq!(bp("1"), Ok(10000));
+        assert_eq!(bp("1."), Ok(10000));
+        assert_eq!(bp(".5"), Ok(5000));
+        assert_eq!(bp("-0"), Ok(0));
+        assert_eq!(bp("0.03250000"), Ok(325));
+        assert_eq!(bp("0.03251"), Err(TooManyDecimals { max: 4 }));
+        assert_eq!(bp("."), Err(NoDigits));
+        assert_eq!(bp("-"), Err(NoDigits));
+        assert_eq!(bp(""), Err(Empty));
+        assert_eq!(bp("1.2.3"), Err(InvalidDigit { digit: b'.', radix: 10 }));
+        assert_eq!(bp("1e-3"), Err(InvalidDigit { digit: b'e', radix: 10 }));
+        assert_eq!(bp("922337203685477.5807"), Ok(i64::MAX as i128));
+        assert_eq!(bp("922337203685477.5808"), Err(OutOfRange));
+        assert_eq!(bp("-922337203685477.5808"), Ok(i64::MIN as i128));
+        assert_eq!(bp("1".repeat(40).as_str()), Err(IntOverflow));
+        assert_eq!(parse_basis_points(b"1.5", 0, 10000, true), Ok(10000));
+        assert_eq!(parse_basis_points(b"-1", 0, 10000, true), Ok(0));
+        assert_eq!(parse_basis_points(&b"9".repeat(40), 0, 10000, true), Ok(10000));
+    }
+
+    #[test]
+    fn test_parse_weighted_flag() {
+        assert_eq!(parse_weighted_flag(b"true:50"), Ok((true, 50)));
+        assert_eq!(parse_weighted_flag(b" on : 0 "), Ok((true, 0)));
+        assert_eq!(parse_weighted_flag(b"yes:100"), Ok((true, 100)));
+        assert_eq!(parse_weighted_flag(b"true"), Ok((true, 100)));
+        assert_eq!(parse_weighted_flag(b"off"), Ok((false, 0)));
+        assert_eq!(parse_weighted_flag(b"true:101"), Err(OutOfRange));
+        assert_eq!(parse_weighted_flag(b"true:-1"), Err(UnexpectedSign));
+        assert_eq!(parse_weighted_flag(b"true:"), Err(NoDigits));
+        assert_eq!(parse_weighted_flag(b"true:half"), Err(InvalidDigit { digit: b'h', radix: 10 }));
+        assert_eq!(parse_weighted_flag(b"false:50"), Err(UnexpectedArgument));
+        assert_eq!(parse_weighted_flag(b"sure:50"), Err(UnknownBoolValue));
+        assert_eq!(parse_weighted_flag(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_split_range() {
+        assert_eq!(split_range(b"10..100"), Ok((&b"10"[..], &b"100"[..], false)));
+        assert_eq!(split_range(b"10..=100"), Ok((&b"10"[..], &b"100"[..], true)));
+        assert_eq!(split_range(b"-5..-1"), Ok((&b"-5"[..], &b"-1"[..], false)));
+        assert_eq!(split_range(b"10.."), Ok((&b"10"[..], &b""[..], false)));
+        assert_eq!(split_range(b"..=7"), Ok((&b""[..], &b"7"[..], true)));
+        assert_eq!(split_range(b".."), Ok((&b""[..], &b""[..], false)));
+        assert_eq!(split_range(b"10"), Err(InvalidRange));
+        assert_eq!(split_range(b"10."), Err(InvalidRange));
+        assert_eq!(split_range(b" "), Err(Empty));
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(parse_mode(b"644"), Ok(0o644));
+        assert_eq!(parse_mode(b"0644"), Ok(0o644));
+        assert_eq!(parse_mode(b" 0o644 "), Ok(0o644));
+        assert_eq!(parse_mode(b"0O7_777"), Ok(0o7777));
+        assert_eq!(parse_mode(b"0"), Ok(0));
+        assert_eq!(parse_mode(b"0000644"), Ok(0o644));
+        assert_eq!(parse_mode(b"10000"), Err(OutOfRange));
+        assert_eq!(parse_mode(&b"7".repeat(50)), Err(OutOfRange));
+        assert_eq!(parse_mode(b"0o999"), Err(InvalidDigit { digit: b'9', radix: 8 }));
+        assert_eq!(parse_mode(b"0x1a4"), Err(InvalidDigit { digit: b'x', radix: 8 }));
+        assert_eq!(parse_mode(b"-644"), Err(InvalidDigit { digit: b'-', radix: 8 }));
+        assert_eq!(parse_mode(b"0o"), Err(NoDigits));
+        assert_eq!(parse_mode(b"_"), Err(NoDigits));
+        assert_eq!(parse_mode(b""), Err(Empty));
+    }
+
+    #[test]
+    fn test_parse_lint_level() {
+        use crate::LintLevel::*;
+        assert_eq!(parse_lint_level(b"allow"), Ok(Allow));
+        assert_eq!(parse_lint_level(b" Warn "), Ok(Warn));
+        assert_eq!(parse_lint_level(b"DENY"), Ok(Deny));
+        assert_eq!(parse_lint_level(b"forbid\n"), Ok(Forbid));
+        assert_eq!(parse_lint_level(b""), Err(Empty));
+        assert_eq!(parse_lint_level(b"error"), Err(UnknownToken));
+        assert_eq!(parse_lint_level(b"warnings"), Err(UnknownToken));
+        assert!(Allow < Warn && Warn < Deny && Deny < Forbid);
+        assert!(Forbid.at_least(Deny) && Deny.at_least(Deny) && !Warn.at_least(Deny));
+    }
+
+    #[test]
+    fn test_strip_try_marker() {
+        assert_eq!(strip_try_marker(b"5?"), (&b"5"[..], true));
+        assert_eq!(strip_try_marker(b" 5 ?\t"), (&b" 5 "[..], true));
+        assert_eq!(strip_try_marker(b"?"), (&b""[..], true));
+        assert_eq!(strip_try_marker(b"5??"), (&b"5?"[..], true));
+        assert_eq!(strip_try_marker(b"?5"), (&b"?5"[..], false));
+        assert_eq!(strip_try_marker(b" 5 "), (&b" 5 "[..], false));
+        assert_eq!(strip_try_marker(b""), (&b""[..], false));
+    }
+
+    #[test]
+    #[cfg(feature = "bool-emoji")]
+    fn test_parse_bool_emoji() {
+        assert_eq!(parse_bool("\u{2713}".as_bytes()), Ok(true));
+        assert_eq!(parse_bool(" \u{2714}\t".as_bytes()), Ok(true));
+        assert_eq!(parse_bool("\u{2705}".as_bytes()), Ok(true));
+        assert_eq!(parse_bool("\u{2717}".as_bytes()), Ok(false));
+        assert_eq!(parse_bool("\u{2718}".as_bytes()), Ok(false));
+        assert_eq!(parse_bool("\u{274c}\n".as_bytes()), Ok(false));
+        // The usual words still work.
+        assert_eq!(parse_bool(b"yes"), Ok(true));
+        assert_eq!(parse_bool(b""), Err(Empty));
+        // Only the whole symbol counts, not a prefix of its bytes, or a
+        // sequence with something more after it.
+        for sym in BOOL_EMOJI_TOKENS.0.iter().chain(BOOL_EMOJI_TOKENS.1) {
+            let b = sym.as_bytes();
+            for len in 1..b.len() {
+                assert_eq!(parse_bool(&b[..len]), Err(UnknownBoolValue), "{:?}", &b[..len]);
+                assert_eq!(parse_bool(&b[len..]), Err(UnknownBoolValue), "{:?}", &b[len..]);
+            }
+            let mut doubled = alloc::vec::Vec::from(b);
+            doubled.extend_from_slice(b);
+            assert_eq!(parse_bool(&doubled), Err(UnknownBoolValue), "{:?}", doubled);
+            let mut with_selector = alloc::vec::Vec::from(b);
+            with_selector.extend_from_slice("\u{fe0f}".as_bytes());
+            assert_eq!(parse_bool(&with_selector), Err(UnknownBoolValue), "{:?}", with_selector);
+        }
+        // Neighboring code points aren't accepted.
+        assert_eq!(parse_bool("\u{2712}".as_bytes()), Err(UnknownBoolValue));
+        assert_eq!(parse_bool("\u{2706}".as_bytes()), Err(UnknownBoolValue));
+        assert_eq!(parse_bool("\u{274b}".as_bytes()), Err(UnknownBoolValue));
+    }
+
+    #[test]
+    fn test_starts_ends_with() {
+        assert!(starts_with(b"abc", b"ab"));
+        assert!(starts_with(b"abc", b"abc"));
+        assert!(!starts_with(b"abc", b"abcd"));
+        assert!(!starts_with(b"abc", b"bc"));
+        assert!(!starts_with(b"abc", b"AB"));
+        assert!(starts_with(b"abc", b""));
+        assert!(starts_with(b"", b""));
+        assert!(!starts_with(b"", b"a"));
+
+        assert!(ends_with(b"abc", b"bc"));
+        assert!(ends_with(b"abc", b"abc"));
+        assert!(!ends_with(b"abc", b"zabc"));
+        assert!(!ends_with(b"abc", b"ab"));
+        assert!(!ends_with(b"abc", b"BC"));
+        assert!(ends_with(b"abc", b""));
+        assert!(ends_with(b"", b""));
+        assert!(!ends_with(b"", b"a"));
+
+        assert!(starts_with_ignore_case(b"ABC", b"ab"));
+        assert!(starts_with_ignore_case(b"abc", b"ABC"));
+        assert!(!starts_with_ignore_case(b"abc", b"ABCD"));
+        assert!(!starts_with_ignore_case(b"abc", b"BC"));
+        assert!(starts_with_ignore_case(b"abc", b""));
+        assert!(starts_with_ignore_case(b"", b""));
+        assert!(!starts_with_ignore_case(b"", b"a"));
+
+        assert!(ends_with_ignore_case(b"ABC", b"bc"));
+        assert!(ends_with_ignore_case(b"abc", b"ABC"));
+        assert!(!ends_with_ignore_case(b"abc", b"ZABC"));
+        assert!(!ends_with_ignore_case(b"abc", b"AB"));
+        assert!(ends_with_ignore_case(b"abc", b""));
+        assert!(ends_with_ignore_case(b"", b""));
+        assert!(!ends_with_ignore_case(b"", b"a"));
+
+        // Case folding is ASCII only, and non-ASCII bytes are compared exactly.
+        assert!(!starts_with_ignore_case("\u{c9}t\u{e9}".as_bytes(), "\u{e9}".as_bytes()));
+        assert!(ends_with_ignore_case("\u{c9}T\u{e9}".as_bytes(), "t\u{e9}".as_bytes()));
+        // Needles that only match in the middle don't count.
+        assert!(!starts_with(b"xabcx", b"abc"));
+        assert!(!ends_with(b"xabcx", b"abc"));
+
+        const OK: [bool; 2] = [starts_with(b"0x1f", b"0x"), ends_with_ignore_case(b"lib.RS", b".rs")];
+        assert_eq!(OK, [true, true]);
+    }
+
+    #[test]
+    fn test_parse_qfixed() {
+        let q = |s: &str, i, f| parse_qfixed(s.as_bytes(), i, f);
+        assert_eq!(q("1.5", 16, 16), Ok(0x0001_8000));
+        assert_eq!(q(" -1.5 ", 16, 16), Ok(-0x0001_8000));
+        assert_eq!(q("+.5", 16, 16), Ok(0x8000));
+        assert_eq!(q("2.", 16, 16), Ok(0x0002_0000));
+        assert_eq!(q("-0", 16, 16), Ok(0));
+        assert_eq!(q("0.1", 16, 16), Ok(6554)); // 6553.6
+        assert_eq!(q("-0.1", 16, 16), Ok(-6554));
+        assert_eq!(q("0.00000000000000000000000000000000000001000", 8, 8), Ok(0));
+        // Ties round away from zero.
+        assert_eq!(q("0.5", 8, 0), Ok(1));
+        assert_eq!(q("-0.5", 8, 0), Ok(-1));
+        assert_eq!(q("2.5", 8, 0), Ok(3));
+        assert_eq!(q("0.4999", 8, 0), Ok(0));
+        assert_eq!(q("0.125", 4, 2), Ok(1)); // 0.5 of the last bit
+        assert_eq!(q("-0.125", 4, 2), Ok(-1));
+        // The edges of Q1.15.
+        assert_eq!(q("-1", 1, 15), Ok(-0x8000));
+        assert_eq!(q("0.99996948", 1, 15), Ok(0x7fff));
+        assert_eq!(q("0.99999", 1, 15), Err(OutOfRange)); // rounds up to 1
+        assert_eq!(q("1", 1, 15), Err(OutOfRange));
+        assert_eq!(q("-1.00002", 1, 15), Err(OutOfRange));
+        assert_eq!(parse_qfixed_clamp(b"1", 1, 15), Ok(0x7fff));
+        assert_eq!(parse_qfixed_clamp(b"-1.00002", 1, 15), Ok(-0x8000));
+        assert_eq!(parse_qfixed_clamp("9".repeat(50).as_bytes(), 16, 16), Ok(i32::MAX as i128));
+        assert_eq!(parse_qfixed_clamp(b"0.25", 1, 15), Ok(0x2000));
+        // The widest formats.
+        assert_eq!(q("-1", 128, 0), Ok(-1));
+        assert_eq!(q(&alloc::format!("{}", i128::MAX), 128, 0), Ok(i128::MAX));
+        assert_eq!(q(&alloc::format!("{}", i128::MIN), 128, 0), Ok(i128::MIN));
+        assert_eq!(q("170141183460469231731687303715884105728", 128, 0), Err(OutOfRange));
+        assert_eq!(q("-0.5", 0, 128), Ok(i128::MIN));
+        assert_eq!(q("0.25", 0, 128), Ok(1 << 126));
+        assert_eq!(q("0.5", 0, 128), Err(OutOfRange));
+        assert_eq!(q("1", 0, 128), Err(OutOfRange));
+        assert_eq!(q("1", 1, 127), Err(OutOfRange));
+        assert_eq!(parse_qfixed_clamp(b"1", 0, 128), Ok(i128::MAX));
+        assert_eq!(q("9".repeat(50).as_str(), 64, 64), Err(OutOfRange));
+        // Errors.
+        assert_eq!(q("", 16, 16), Err(Empty));
+        assert_eq!(q(".", 16, 16), Err(NoDigits));
+        assert_eq!(q("-", 16, 16), Err(NoDigits));
+        assert_eq!(q("1.2.3", 16, 16), Err(InvalidDigit { digit: b'.', radix: 10 }));
+        assert_eq!(q("1e3", 16, 16), Err(InvalidDigit { digit: b'e', radix: 10 }));
+        assert_eq!(q("0x10", 16, 16), Err(InvalidDigit { digit: b'x', radix: 10 }));
+        assert_eq!(q(&alloc::format!("0.{}1", "0".repeat(38)), 16, 16), Err(TooManyDecimals { max: 38 }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_qfixed_too_wide() {
+        let _ = parse_qfixed(b"1", 64, 65);
+    }
+
+    #[test]
+    fn test_parse_bool_first_char() {
+        for s in ["t", "T", "1", "true", "truthy", " Tru ", "1000", "\ttx"] {
+            assert_eq!(parse_bool_first_char(s.as_bytes()), Ok(true), "input: {:?}", s);
+        }
+        for s in ["f", "F", "0", "false", "falsey", " fals", "0.5", "\nF!"] {
+            assert_eq!(parse_bool_first_char(s.as_bytes()), Ok(false), "input: {:?}", s);
+        }
+        for s in ["yes", "no", "on", "2", "-1", "xt", "\u{fe}true"] {
+            assert_eq!(parse_bool_first_char(s.as_bytes()), Err(UnknownBoolValue), "input: {:?}", s);
+        }
+        assert_eq!(parse_bool_first_char(b""), Err(Empty));
+        assert_eq!(parse_bool_first_char(b" \t"), Err(Empty));
+        // The strict parser still rejects these.
+        assert_eq!(parse_bool(b"truthy"), Err(UnknownBoolValue));
+        assert_eq!(parse_bool(b"falsey"), Err(UnknownBoolValue));
+    }
+
+    #[test]
+    fn test_parse_rust_log() {
+        assert_eq!(parse_rust_log(b"off"), Ok(0));
+        assert_eq!(parse_rust_log(b"error"), Ok(1));
+        assert_eq!(parse_rust_log(b" WARN "), Ok(2));
+        assert_eq!(parse_rust_log(b"info"), Ok(3));
+        assert_eq!(parse_rust_log(b"debug"), Ok(4));
+        assert_eq!(parse_rust_log(b"trace"), Ok(5));
+        // Module directives don't change the global level, and without a bare
+        // level it's `off`.
+        assert_eq!(parse_rust_log(b"info,a=trace,b"), Ok(3));
+        assert_eq!(parse_rust_log(b"a=trace, b"), Ok(0));
+        assert_eq!(parse_rust_log(b"error,,debug,"), Ok(4));
+        assert_eq!(parse_rust_log(b"info/some.*regex"), Ok(3));
+        assert_eq!(parse_rust_log(b"a=info/x=trace"), Ok(0));
+        assert_eq!(parse_rust_log(b""), Err(Empty));
+        assert_eq!(parse_rust_log(b" "), Err(Empty));
+        assert_eq!(parse_rust_log(b"a=loud"), Err(UnknownToken));
+        assert_eq!(parse_rust_log(b"a="), Err(UnknownToken));
+        assert_eq!(parse_rust_log(b"3"), Ok(0)); // a module named `3`
+
+        let spec = b"warn, a=info ,a::b=trace,a::b::c=off,ab=debug";
+        assert_eq!(parse_rust_log_for(spec, b""), Ok(2));
+        assert_eq!(parse_rust_log_for(spec, b"a"), Ok(3));
+        assert_eq!(parse_rust_log_for(spec, b"a::x"), Ok(3));
+        assert_eq!(parse_rust_log_for(spec, b"a::b"), Ok(5));
+        assert_eq!(parse_rust_log_for(spec, b"a::bc"), Ok(3));
+        assert_eq!(parse_rust_log_for(spec, b"a::b::c::d"), Ok(0));
+        assert_eq!(parse_rust_log_for(spec, b"ab"), Ok(4));
+        assert_eq!(parse_rust_log_for(spec, b"abc"), Ok(2));
+        assert_eq!(parse_rust_log_for(spec, b"x"), Ok(2));
+        // Later directives win ties, and a bare path is `trace`.
+        assert_eq!(parse_rust_log_for(b"a=info,a=error", b"a"), Ok(1));
+        assert_eq!(parse_rust_log_for(b"error,a", b"a::b"), Ok(5));
+        assert_eq!(parse_rust_log_for(b"a=debug,error", b"a"), Ok(4));
+    }
+
+    #[test]
+    fn test_version_matches() {
+        let v = (1, 70, 3);
+        for (req, want) in [
+            (">=1.70", true),
+            (">= 1.70.3", true),
+            (">=1.70.4", false),
+            (">=1.71", false),
+            (">=1", true),
+            (">1.70", false),
+            (">1.69", true),
+            (">1.70.2", true),
+            ("<1.70", false),
+            ("<1.71", true),
+            ("<2", true),
+            ("<1.70.4", true),
+            ("<=1.70", true),
+            ("<=1.69", false),
+            ("<=1.70.2", false),
+            ("=1.70", true),
+            ("=1", true),
+            ("=1.70.3", true),
+            ("=1.70.2", false),
+            ("=2", false),
+            (" >=0.0.0 ", true),
+        ] {
+            assert_eq!(version_matches(req.as_bytes(), v), Ok(want), "req: {:?}", req);
+        }
+        assert_eq!(version_matches(b"", v), Err(Empty));
+        assert_eq!(version_matches(b"1.70", v), Err(UnknownOperator { op: b'1' }));
+        assert_eq!(version_matches(b"^1.70", v), Err(UnknownOperator { op: b'^' }));
+        assert_eq!(version_matches(b">=", v), Err(InvalidVersion));
+        assert_eq!(version_matches(b">=1.", v), Err(InvalidVersion));
+        assert_eq!(version_matches(b">=1..2", v), Err(InvalidVersion));
+        assert_eq!(version_matches(b">=1.2.3.4", v), Err(InvalidVersion));
+        assert_eq!(version_matches(b">=01.2", v), Err(InvalidVersion));
+        assert_eq!(version_matches(b">=1.x", v), Err(InvalidDigit { digit: b'x', radix: 10 }));
+        assert_eq!(version_matches(b">=1.2-rc.1", v), Err(InvalidDigit { digit: b'-', radix: 10 }));
+        assert_eq!(version_matches(b"=>1.2", v), Err(InvalidDigit { digit: b'>', radix: 10 }));
+        assert_eq!(version_matches(b">=99999999999999999999", v), Err(IntOverflow));
+    }
+
+    #[test]
+    fn test_strip_brackets() {
+        assert_eq!(strip_brackets(b"[1,2]"), Some(&b"1,2"[..]));
+        assert_eq!(strip_brackets(b"(1,2)"), Some(&b"1,2"[..]));
+        assert_eq!(strip_brackets(b"{1,2}"), Some(&b"1,2"[..]));
+        assert_eq!(strip_brackets(b"  ( 1 ) \n"), Some(&b" 1 "[..]));
+        assert_eq!(strip_brackets(b"[]"), Some(&b""[..]));
+        assert_eq!(strip_brackets(b"[[1]]"), Some(&b"[1]"[..]));
+        assert_eq!(strip_brackets(b"1,2"), Some(&b"1,2"[..]));
+        assert_eq!(strip_brackets(b""), Some(&b""[..]));
+        assert_eq!(strip_brackets(b"  "), Some(&b"  "[..]));
+        assert_eq!(strip_brackets(b"[1,2)"), None);
+        assert_eq!(strip_brackets(b"(1,2}"), None);
+        assert_eq!(strip_brackets(b"[1,2"), None);
+        assert_eq!(strip_brackets(b"1,2]"), None);
+        assert_eq!(strip_brackets(b"["), None);
+    }
+
+    #[test]
+    fn test_parse_duration_range() {
+        assert_eq!(parse_duration_range(b"100ms..=500ms"), Ok((100_000_000, 500_000_000)));
+        assert_eq!(parse_duration_range(b" 100ms .. 500ms "), Ok((100_000_000, 499_999_999)));
+        assert_eq!(parse_duration_range(b"500ms..=2s"), Ok((500_000_000, 2_000_000_000)));
+        assert_eq!(parse_duration_range(b"1s..=1s"), Ok((1_000_000_000, 1_000_000_000)));
+        assert_eq!(parse_duration_range(b"0..=1us"), Ok((0, 1_000)));
+        assert_eq!(parse_duration_range(b"..1ns"), Ok((0, 0)));
+        assert_eq!(parse_duration_range(b"1h.."), Ok((3_600_000_000_000, u64::MAX)));
+        assert_eq!(parse_duration_range(b"2s..=1s"), Err(InvalidRange));
+        assert_eq!(parse_duration_range(b"1s..1s"), Err(InvalidRange));
+        assert_eq!(parse_duration_range(b"..0"), Err(InvalidRange));
+        assert_eq!(parse_duration_range(b"1s"), Err(InvalidRange));
+        assert_eq!(parse_duration_range(b"100..500ms"), Err(MissingTimeUnit));
+        assert_eq!(parse_duration_range(b"1s..10000000h"), Err(IntOverflow));
+        assert_eq!(parse_duration_range(b"1s..340282366920938463463374607431768211459ns"), Err(IntOverflow));
+        assert_eq!(parse_duration_range(b" "), Err(Empty));
+    }
+
+    #[test]
+    fn test_count_delimited() {
+        assert_eq!(count_delimited(b"", b','), 0);
+        assert_eq!(count_delimited(b" \t ", b','), 0);
+        assert_eq!(count_delimited(b"1", b','), 1);
+        assert_eq!(count_delimited(b" x ", b';'), 1);
+        assert_eq!(count_delimited(b"1,2,3", b','), 3);
+        assert_eq!(count_delimited(b"1,2,3,", b','), 4);
+        assert_eq!(count_delimited(b"1,2,3, ", b','), 4);
+        assert_eq!(count_delimited(b"1,2,,", b','), 4);
+        assert_eq!(count_delimited(b"1,,3", b','), 3);
+        assert_eq!(count_delimited(b",", b','), 2);
+        assert_eq!(count_delimited(b",,", b','), 3);
+        assert_eq!(count_delimited(b"1,2;3", b';'), 2);
     }
 }
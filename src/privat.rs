@@ -1,7 +1,12 @@
-/// The way we make this work without traits is we just look inside
-/// `__priv::parsers` for a function with the same name as the type they provided
-/// to the macro. Not very extensible, but doesn't require const traits (which
-/// feel like they're a jillion years away).
+//! The way we make this work without traits is we just look inside
+//! `__priv::parsers` for a function with the same name as the type they provided
+//! to the macro. Not very extensible, but doesn't require const traits (which
+//! feel like they're a jillion years away).
+
+use crate::parse::ParseError;
+
+/// Seals traits which we only want implemented for our own list of types.
+pub trait Sealed {}
 
 macro_rules! unwrap_or {
     ($o:expr, $or:expr) => {
@@ -130,8 +135,163 @@ pub mod parse_bounded {
         max: Option<i128>,
         clamp: bool,
     ) -> Option<i128> {
-        match parse_signed(s, unwrap_or!(min, i128::MIN) as i128, unwrap_or!(max, i128::MAX) as i128, clamp) {
-            Ok(v) => Some(v as i128),
+        match parse_signed(s, unwrap_or!(min, i128::MIN), unwrap_or!(max, i128::MAX), clamp) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    // `NonZero*`. The bounds are the underlying integer type, and the macros
+    // make sure the range doesn't include zero before calling these.
+    macro_rules! def_nonzero {
+        ($($nz:ident($t:ident)),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $nz(
+                s: &[u8],
+                default: Option<core::num::$nz>,
+                min: Option<$t>,
+                max: Option<$t>,
+                clamp: bool,
+            ) -> Option<core::num::$nz> {
+                let default = match default {
+                    Some(d) => Some(d.get()),
+                    None => None,
+                };
+                core::num::$nz::new(unwrap_or!($t(s, default, min, max, clamp), return None))
+            }
+        )*};
+    }
+
+    def_nonzero! {
+        NonZeroUsize(usize), NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128),
+        NonZeroIsize(isize), NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128),
+    }
+
+    // Other things
+    pub const fn int(
+        s: &[u8],
+        default: Option<i128>,
+        min: Option<i128>,
+        max: Option<i128>,
+        clamp: bool,
+    ) -> Option<i128> {
+        i128(s, default, min, max, clamp)
+    }
+
+    pub const fn cased_size(
+        s: &[u8],
+        default: Option<usize>,
+        min: Option<usize>,
+        max: Option<usize>,
+        clamp: bool,
+    ) -> Option<usize> {
+        match crate::parse::parse_cased_size(s, unwrap_or!(min, 0) as u128, unwrap_or!(max, usize::MAX) as u128, clamp)
+        {
+            Ok(v) => Some(v as usize),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn rate(s: &[u8], default: Option<u64>, min: Option<u64>, max: Option<u64>, clamp: bool) -> Option<u64> {
+        match crate::parse::parse_rate(s, unwrap_or!(min, 0) as u128, unwrap_or!(max, u64::MAX) as u128, clamp) {
+            Ok(v) => Some(v as u64),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    /// Not looked up by type name either, since it takes the name of the flag.
+    pub const fn bool_sign_named(s: &[u8], default: Option<bool>, name: &str) -> Option<bool> {
+        match crate::parse::parse_bool_sign_named(s, name) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    /// For `rust_log "path"`, which takes the module path.
+    pub const fn rust_log_for(s: &[u8], default: Option<u8>, path: &str) -> Option<u8> {
+        match crate::parse::parse_rust_log_for(s, path.as_bytes()) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    /// Not looked up by type name like the others, since it takes the page
+    /// size as well.
+    pub const fn pages(
+        s: &[u8],
+        default: Option<usize>,
+        page_size: usize,
+        min: Option<usize>,
+        max: Option<usize>,
+        clamp: bool,
+    ) -> Option<usize> {
+        let (min, max) = (unwrap_or!(min, 0) as u128, unwrap_or!(max, usize::MAX) as u128);
+        match crate::parse::parse_pages(s, page_size as u128, min, max, clamp) {
+            Ok(v) => Some(v as usize),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    /// Not looked up by type name either, since it takes the threshold. Note
+    /// that anything other than a non-negative integer is an error, rather
+    /// than `false`.
+    pub const fn bool_at_least(s: &[u8], default: Option<bool>, threshold: u128) -> Option<bool> {
+        match crate::parse::parse_unsigned(s, 0, u128::MAX, false) {
+            Ok(v) => Some(v >= threshold),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn degrees(
+        s: &[u8],
+        default: Option<i32>,
+        min: Option<i32>,
+        max: Option<i32>,
+        clamp: bool,
+    ) -> Option<i32> {
+        let (min, max) = (unwrap_or!(min, i32::MIN) as i128, unwrap_or!(max, i32::MAX) as i128);
+        match crate::parse::parse_degrees(s, min, max, clamp) {
+            Ok(v) => Some(v as i32),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn basis_points(
+        s: &[u8],
+        default: Option<i64>,
+        min: Option<i64>,
+        max: Option<i64>,
+        clamp: bool,
+    ) -> Option<i64> {
+        let (min, max) = (unwrap_or!(min, i64::MIN) as i128, unwrap_or!(max, i64::MAX) as i128);
+        match crate::parse::parse_basis_points(s, min, max, clamp) {
+            Ok(v) => Some(v as i64),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn char(
+        s: &[u8],
+        default: Option<char>,
+        min: Option<char>,
+        max: Option<char>,
+        clamp: bool,
+    ) -> Option<char> {
+        let (min, max) = (unwrap_or!(min, '\0'), unwrap_or!(max, char::MAX));
+        match crate::parse::parse_char(s) {
+            Ok(c) if c < min && clamp => Some(min),
+            Ok(c) if c > max && clamp => Some(max),
+            Ok(c) if c < min || c > max => None,
+            Ok(c) => Some(c),
             Err(Empty) => default,
             _ => None,
         }
@@ -181,6 +341,20 @@ pub mod parsers {
         super::parse_bounded::i128(s, default, None, None, false)
     }
 
+    macro_rules! def_nonzero {
+        ($($nz:ident),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $nz(s: &[u8], default: Option<core::num::$nz>) -> Option<core::num::$nz> {
+                super::parse_bounded::$nz(s, default, None, None, false)
+            }
+        )*};
+    }
+
+    def_nonzero! {
+        NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
+        NonZeroIsize, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
+    }
+
     // Other things
     pub const fn bool(s: &[u8], default: Option<bool>) -> Option<bool> {
         match crate::parse::parse_bool(s) {
@@ -189,66 +363,2100 @@ pub mod parsers {
             _ => None,
         }
     }
-}
 
-#[derive(Copy, Clone)]
-pub struct RangeWrap<R, T>(pub R, pub core::marker::PhantomData<T>);
+    pub const fn bool_sign(s: &[u8], default: Option<bool>) -> Option<bool> {
+        match crate::parse::parse_bool_sign(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
 
-macro_rules! def_to_inclusive {
-    ($t:ident, $min:expr) => {
-        impl RangeWrap<core::ops::Range<$t>, $t> {
-            pub const fn start(&self) -> $t {
-                self.0.start
-            }
-            pub const fn end_incl(&self) -> $t {
-                self.0.end - 1
-            }
+    pub const fn bool_tf(s: &[u8], default: Option<bool>) -> Option<bool> {
+        match crate::parse::parse_bool_tf(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
         }
-        impl RangeWrap<core::ops::RangeFrom<$t>, $t> {
-            pub const fn start(&self) -> $t {
-                self.0.start
-            }
-            pub const fn end_incl(&self) -> $t {
-                $t::MAX
-            }
+    }
+
+    pub const fn bool_first_char(s: &[u8], default: Option<bool>) -> Option<bool> {
+        match crate::parse::parse_bool_first_char(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
         }
-        impl RangeWrap<core::ops::RangeTo<$t>, $t> {
-            pub const fn start(&self) -> $t {
-                $min
-            }
-            pub const fn end_incl(&self) -> $t {
-                self.0.end - 1
-            }
+    }
+
+    pub const fn int(s: &[u8], default: Option<i128>) -> Option<i128> {
+        super::parse_bounded::int(s, default, None, None, false)
+    }
+
+    pub const fn cased_size(s: &[u8], default: Option<usize>) -> Option<usize> {
+        super::parse_bounded::cased_size(s, default, None, None, false)
+    }
+
+    pub const fn rate(s: &[u8], default: Option<u64>) -> Option<u64> {
+        super::parse_bounded::rate(s, default, None, None, false)
+    }
+
+    pub const fn degrees(s: &[u8], default: Option<i32>) -> Option<i32> {
+        super::parse_bounded::degrees(s, default, None, None, false)
+    }
+
+    pub const fn basis_points(s: &[u8], default: Option<i64>) -> Option<i64> {
+        super::parse_bounded::basis_points(s, default, None, None, false)
+    }
+
+    pub const fn char(s: &[u8], default: Option<char>) -> Option<char> {
+        super::parse_bounded::char(s, default, None, None, false)
+    }
+
+    pub const fn ratio(s: &[u8], default: Option<(i128, i128)>) -> Option<(i128, i128)> {
+        match crate::parse::parse_ratio(s, false) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
         }
-        impl RangeWrap<core::ops::RangeInclusive<$t>, $t> {
-            pub const fn start(&self) -> $t {
-                *self.0.start()
-            }
-            pub const fn end_incl(&self) -> $t {
-                *self.0.end()
-            }
+    }
+
+    pub const fn on_off_auto(s: &[u8], default: Option<crate::OnOffAuto>) -> Option<crate::OnOffAuto> {
+        match crate::parse::parse_on_off_auto(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
         }
-        impl RangeWrap<core::ops::RangeToInclusive<$t>, $t> {
-            pub const fn start(&self) -> $t {
-                $min
-            }
-            pub const fn end_incl(&self) -> $t {
-                self.0.end
-            }
+    }
+
+    pub const fn duration(s: &[u8], default: Option<core::time::Duration>) -> Option<core::time::Duration> {
+        match crate::parse::parse_duration(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
         }
-        impl RangeWrap<core::ops::RangeFull, $t> {
-            pub const fn start(&self) -> $t {
-                $min
-            }
-            pub const fn end_incl(&self) -> $t {
-                $t::MAX
+    }
+
+    pub const fn duration_range(s: &[u8], default: Option<(u64, u64)>) -> Option<(u64, u64)> {
+        match crate::parse::parse_duration_range(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn lint_level(s: &[u8], default: Option<crate::LintLevel>) -> Option<crate::LintLevel> {
+        match crate::parse::parse_lint_level(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn rust_log(s: &[u8], default: Option<u8>) -> Option<u8> {
+        match crate::parse::parse_rust_log(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn prompt_policy(s: &[u8], default: Option<crate::PromptPolicy>) -> Option<crate::PromptPolicy> {
+        match crate::parse::parse_prompt_policy(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn sort_dir(s: &[u8], default: Option<crate::SortDir>) -> Option<crate::SortDir> {
+        match crate::parse::parse_sort_dir(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    #[allow(non_snake_case)]
+    pub const fn Ipv4Addr(s: &[u8], default: Option<core::net::Ipv4Addr>) -> Option<core::net::Ipv4Addr> {
+        match crate::parse::parse_ipv4(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn char_code(s: &[u8], default: Option<char>) -> Option<char> {
+        match crate::parse::parse_char_code(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn version(s: &[u8], default: Option<(u64, u64, u64)>) -> Option<(u64, u64, u64)> {
+        match crate::parse::parse_version(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn mode(s: &[u8], default: Option<u32>) -> Option<u32> {
+        match crate::parse::parse_mode(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    pub const fn weighted_flag(s: &[u8], default: Option<(bool, u8)>) -> Option<(bool, u8)> {
+        match crate::parse::parse_weighted_flag(s) {
+            Ok(v) => Some(v),
+            Err(Empty) => default,
+            _ => None,
+        }
+    }
+
+    // Strings. These need `'static` input, since they return (part of) it.
+    // Neither can fail.
+    pub const fn str(s: &'static [u8], _default: Option<&'static str>) -> Option<&'static str> {
+        match core::str::from_utf8(s) {
+            Ok(s) => Some(s),
+            // The macros only give us bytes which came from a `str`.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    pub const fn trimmed_str(s: &'static [u8], default: Option<&'static str>) -> Option<&'static str> {
+        match crate::parse::trim_ws(s) {
+            Some((start, end)) => str(crate::parse::subslice(s, start, end), None),
+            None => Some(unwrap_or!(default, "")),
+        }
+    }
+}
+
+/// Checks for `str starts_with "..."` and friends, looked up by the name of the
+/// modifier.
+pub mod affix {
+    use crate::parse;
+
+    pub const fn starts_with(s: &str, pat: &str) -> bool {
+        parse::starts_with(s.as_bytes(), pat.as_bytes())
+    }
+
+    pub const fn ends_with(s: &str, pat: &str) -> bool {
+        parse::ends_with(s.as_bytes(), pat.as_bytes())
+    }
+
+    pub const fn starts_with_ignore_case(s: &str, pat: &str) -> bool {
+        parse::starts_with_ignore_case(s.as_bytes(), pat.as_bytes())
+    }
+
+    pub const fn ends_with_ignore_case(s: &str, pat: &str) -> bool {
+        parse::ends_with_ignore_case(s.as_bytes(), pat.as_bytes())
+    }
+}
+
+/// For `from { ... }`, returns the index of the name in `words` which `s`
+/// matches (ignoring case and surrounding whitespace).
+pub const fn word_index(s: &[u8], words: &[&str]) -> Option<usize> {
+    match crate::parse::match_word(s, words) {
+        Ok(i) => Some(i),
+        Err(_) => None,
+    }
+}
+
+/// Returns false if the character class given to `chars` can't be what was
+/// meant: if it's empty, or has a range like `z-a` which matches nothing.
+pub const fn valid_char_class(class: &str) -> bool {
+    let class = class.as_bytes();
+    let mut i = 0;
+    while i + 2 < class.len() {
+        if class[i + 1] == b'-' {
+            if class[i] > class[i + 2] {
+                return false;
             }
+            i += 3;
+        } else {
+            i += 1;
         }
-    };
+    }
+    !class.is_empty()
 }
 
-def_to_inclusive!(u8, 0);
-def_to_inclusive!(u16, 0);
+/// Called by the macros when a string has a byte (at `pos`) which isn't
+/// allowed by `chars`. Like [`fail_message`], the caller is responsible for
+/// panicking.
+pub const fn chars_message(prefix: &str, check: &str, s: &str, pos: usize) -> Message {
+    Message::new()
+        .push(prefix)
+        .push(" has `")
+        .push_escaped(s.as_bytes()[pos])
+        .push("` at byte ")
+        .push_u32(pos as u32)
+        .push(", which isn't allowed by `")
+        .push(check)
+        .push("`.")
+}
+
+/// The type each of the `parsers` produces, so that the macros can name it for
+/// things that aren't a real type (like `ratio`).
+#[allow(non_camel_case_types)]
+pub mod types {
+    pub type usize = core::primitive::usize;
+    pub type u8 = core::primitive::u8;
+    pub type u16 = core::primitive::u16;
+    pub type u32 = core::primitive::u32;
+    pub type u64 = core::primitive::u64;
+    pub type u128 = core::primitive::u128;
+    pub type isize = core::primitive::isize;
+    pub type i8 = core::primitive::i8;
+    pub type i16 = core::primitive::i16;
+    pub type i32 = core::primitive::i32;
+    pub type i64 = core::primitive::i64;
+    pub type i128 = core::primitive::i128;
+    pub type bool = core::primitive::bool;
+    pub type char = core::primitive::char;
+    pub type bool_tf = core::primitive::bool;
+    pub type bool_first_char = core::primitive::bool;
+    pub type bool_sign = core::primitive::bool;
+    pub type int = core::primitive::i128;
+    pub type cased_size = core::primitive::usize;
+    pub type rate = core::primitive::u64;
+    pub type degrees = core::primitive::i32;
+    pub type basis_points = core::primitive::i64;
+    pub type ratio = (i128, i128);
+    pub type on_off_auto = crate::OnOffAuto;
+    pub type sort_dir = crate::SortDir;
+    pub type prompt_policy = crate::PromptPolicy;
+    pub type lint_level = crate::LintLevel;
+    pub type rust_log = core::primitive::u8;
+    pub type duration = core::time::Duration;
+    pub type duration_range = (u64, u64);
+    pub type Ipv4Addr = core::net::Ipv4Addr;
+    pub type version = (u64, u64, u64);
+    pub type weighted_flag = (bool, u8);
+    pub type mode = core::primitive::u32;
+    pub type char_code = core::primitive::char;
+    pub type str = &'static core::primitive::str;
+    pub type trimmed_str = &'static core::primitive::str;
+    pub use core::num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    };
+}
+
+/// Looked up by type the same way as `parsers`, these compare two values in
+/// const (where we can't use `PartialEq`), for `assert_parses!`.
+pub mod eq {
+    use super::types;
+
+    macro_rules! def_eq {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(a: types::$t, b: types::$t) -> bool {
+                a == b
+            }
+        )*};
+    }
+
+    def_eq! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+        bool, bool_tf, bool_first_char, bool_sign, char, char_code, int, cased_size, rate, degrees, basis_points, mode,
+        rust_log,
+    }
+
+    macro_rules! def_eq_nonzero {
+        ($($t:ident),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t(a: types::$t, b: types::$t) -> bool {
+                a.get() == b.get()
+            }
+        )*};
+    }
+
+    def_eq_nonzero! {
+        NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
+        NonZeroIsize, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
+    }
+
+    pub const fn ratio(a: (i128, i128), b: (i128, i128)) -> bool {
+        a.0 == b.0 && a.1 == b.1
+    }
+
+    pub const fn on_off_auto(a: crate::OnOffAuto, b: crate::OnOffAuto) -> bool {
+        a as u8 == b as u8
+    }
+
+    pub const fn duration(a: core::time::Duration, b: core::time::Duration) -> bool {
+        a.as_secs() == b.as_secs() && a.subsec_nanos() == b.subsec_nanos()
+    }
+
+    pub const fn duration_range(a: (u64, u64), b: (u64, u64)) -> bool {
+        a.0 == b.0 && a.1 == b.1
+    }
+
+    pub const fn lint_level(a: crate::LintLevel, b: crate::LintLevel) -> bool {
+        a as u8 == b as u8
+    }
+
+    pub const fn sort_dir(a: crate::SortDir, b: crate::SortDir) -> bool {
+        a as u8 == b as u8
+    }
+
+    pub const fn prompt_policy(a: crate::PromptPolicy, b: crate::PromptPolicy) -> bool {
+        a as u8 == b as u8
+    }
+
+    #[allow(non_snake_case)]
+    pub const fn Ipv4Addr(a: core::net::Ipv4Addr, b: core::net::Ipv4Addr) -> bool {
+        u32::from_be_bytes(a.octets()) == u32::from_be_bytes(b.octets())
+    }
+
+    pub const fn version(a: (u64, u64, u64), b: (u64, u64, u64)) -> bool {
+        a.0 == b.0 && a.1 == b.1 && a.2 == b.2
+    }
+
+    pub const fn weighted_flag(a: (bool, u8), b: (bool, u8)) -> bool {
+        a.0 == b.0 && a.1 == b.1
+    }
+
+    pub const fn str(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    pub const fn trimmed_str(a: &str, b: &str) -> bool {
+        str(a, b)
+    }
+}
+
+/// Looked up by type the same way as `parsers`, these re-parse a value that
+/// failed to parse to find out why, so that `fail` can explain it.
+pub mod diagnose {
+    use crate::parse::{
+        parse_basis_points, parse_bool, parse_bool_first_char, parse_bool_sign, parse_bool_sign_named, parse_bool_tf,
+        parse_cased_size, parse_char, parse_char_code, parse_degrees, parse_duration, parse_duration_range, parse_ipv4,
+        parse_lint_level, parse_mode, parse_on_off_auto, parse_pages, parse_prompt_policy, parse_rate, parse_ratio,
+        parse_rust_log, parse_rust_log_for, parse_signed, parse_sort_dir, parse_unsigned, parse_version,
+        parse_weighted_flag, ParseError,
+    };
+
+    macro_rules! def_diagnose {
+        (unsigned => $($t:ident),*) => {$(
+            pub const fn $t(s: &[u8]) -> Option<ParseError> {
+                match parse_unsigned(s, 0, $t::MAX as u128, false) {
+                    Ok(_) => None,
+                    Err(e) => Some(e),
+                }
+            }
+        )*};
+        (signed => $($t:ident),*) => {$(
+            pub const fn $t(s: &[u8]) -> Option<ParseError> {
+                match parse_signed(s, $t::MIN as i128, $t::MAX as i128, false) {
+                    Ok(_) => None,
+                    Err(e) => Some(e),
+                }
+            }
+        )*};
+    }
+
+    def_diagnose!(unsigned => usize, u8, u16, u32, u64, u128);
+    def_diagnose!(signed => isize, i8, i16, i32, i64, i128);
+
+    macro_rules! def_diagnose_nonzero {
+        ($($nz:ident($t:ident)),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $nz(s: &[u8]) -> Option<ParseError> {
+                match super::parse_bounded::$t(s, None, None, None, false) {
+                    Some(0) => Some(ParseError::OutOfRange),
+                    Some(_) => None,
+                    None => $t(s),
+                }
+            }
+        )*};
+    }
+
+    def_diagnose_nonzero! {
+        NonZeroUsize(usize), NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128),
+        NonZeroIsize(isize), NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128),
+    }
+
+    pub const fn bool(s: &[u8]) -> Option<ParseError> {
+        match parse_bool(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn bool_sign(s: &[u8]) -> Option<ParseError> {
+        match parse_bool_sign(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    /// Not looked up by type name, since it takes the name of the flag.
+    pub const fn bool_sign_named(s: &[u8], name: &str) -> Option<ParseError> {
+        match parse_bool_sign_named(s, name) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn bool_tf(s: &[u8]) -> Option<ParseError> {
+        match parse_bool_tf(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn bool_first_char(s: &[u8]) -> Option<ParseError> {
+        match parse_bool_first_char(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn int(s: &[u8]) -> Option<ParseError> {
+        i128(s)
+    }
+
+    pub const fn cased_size(s: &[u8]) -> Option<ParseError> {
+        match parse_cased_size(s, 0, u128::MAX, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn rate(s: &[u8]) -> Option<ParseError> {
+        match parse_rate(s, 0, u64::MAX as u128, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn bool_at_least(s: &[u8]) -> Option<ParseError> {
+        match parse_unsigned(s, 0, u128::MAX, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn pages(s: &[u8], page_size: usize) -> Option<ParseError> {
+        match parse_pages(s, page_size as u128, 0, usize::MAX as u128, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn degrees(s: &[u8]) -> Option<ParseError> {
+        match parse_degrees(s, i32::MIN as i128, i32::MAX as i128, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn basis_points(s: &[u8]) -> Option<ParseError> {
+        match parse_basis_points(s, i64::MIN as i128, i64::MAX as i128, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn char(s: &[u8]) -> Option<ParseError> {
+        match parse_char(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn ratio(s: &[u8]) -> Option<ParseError> {
+        match parse_ratio(s, false) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn on_off_auto(s: &[u8]) -> Option<ParseError> {
+        match parse_on_off_auto(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn duration(s: &[u8]) -> Option<ParseError> {
+        match parse_duration(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn duration_range(s: &[u8]) -> Option<ParseError> {
+        match parse_duration_range(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn lint_level(s: &[u8]) -> Option<ParseError> {
+        match parse_lint_level(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn rust_log(s: &[u8]) -> Option<ParseError> {
+        match parse_rust_log(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn rust_log_for(s: &[u8], path: &str) -> Option<ParseError> {
+        match parse_rust_log_for(s, path.as_bytes()) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn sort_dir(s: &[u8]) -> Option<ParseError> {
+        match parse_sort_dir(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn prompt_policy(s: &[u8]) -> Option<ParseError> {
+        match parse_prompt_policy(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    #[allow(non_snake_case)]
+    pub const fn Ipv4Addr(s: &[u8]) -> Option<ParseError> {
+        match parse_ipv4(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn char_code(s: &[u8]) -> Option<ParseError> {
+        match parse_char_code(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn version(s: &[u8]) -> Option<ParseError> {
+        match parse_version(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn mode(s: &[u8]) -> Option<ParseError> {
+        match parse_mode(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn weighted_flag(s: &[u8]) -> Option<ParseError> {
+        match parse_weighted_flag(s) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub const fn str(_: &[u8]) -> Option<ParseError> {
+        None
+    }
+
+    pub const fn trimmed_str(_: &[u8]) -> Option<ParseError> {
+        None
+    }
+}
+
+/// Buffer for assembling a panic message in const, since we can't use
+/// `format!` there. Anything past the capacity is dropped.
+#[derive(Copy, Clone)]
+pub struct Message {
+    buf: [u8; 512],
+    len: usize,
+}
+
+impl Message {
+    // Only reachable through `__priv`, so `Default` would be of no use.
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self { buf: [0; 512], len: 0 }
+    }
+
+    pub const fn push_bytes(mut self, s: &[u8]) -> Self {
+        let mut i = 0;
+        while i < s.len() && self.len < self.buf.len() {
+            self.buf[self.len] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn push(self, s: &str) -> Self {
+        self.push_bytes(s.as_bytes())
+    }
+
+    pub const fn push_u32(self, mut n: u32) -> Self {
+        let mut digits = [0u8; 10];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        self.push_bytes(crate::parse::subslice(&digits, i, digits.len()))
+    }
+
+    /// Pushes a byte from the input, escaping it if it's not printable ASCII.
+    pub const fn push_escaped(self, b: u8) -> Self {
+        if b.is_ascii_graphic() {
+            self.push_bytes(&[b])
+        } else {
+            const HEX: &[u8; 16] = b"0123456789abcdef";
+            self.push_bytes(&[b'\\', b'x', HEX[(b >> 4) as usize], HEX[(b & 0xf) as usize]])
+        }
+    }
+
+    pub const fn as_str(&self) -> &str {
+        let bytes = crate::parse::subslice(&self.buf, 0, self.len);
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            // We cut a multibyte char in half when truncating.
+            Err(e) => match core::str::from_utf8(crate::parse::subslice(bytes, 0, e.valid_up_to())) {
+                Ok(s) => s,
+                Err(_) => "",
+            },
+        }
+    }
+}
+
+impl Message {
+    /// Pushes a more specific description of `err` (if we have one worth
+    /// giving), or `fallback`.
+    pub const fn push_error(self, err: Option<ParseError>, fallback: &str) -> Self {
+        match err {
+            Some(ParseError::InvalidDigit { digit, radix }) => {
+                self.push(": invalid digit `").push_escaped(digit).push("` for base ").push_u32(radix).push(".")
+            }
+            Some(ParseError::ZeroDenominator) => self.push(": the denominator is zero."),
+            Some(ParseError::UnknownToken) => self.push(": unrecognized word."),
+            Some(ParseError::InvalidIpv4Addr) => self.push(": expected four numbers from 0 to 255, separated by `.`."),
+            Some(ParseError::InvalidVersion) => self.push(": expected a version like `1.2.3`."),
+            Some(ParseError::TooManyDecimals { max }) => {
+                self.push(": more than ").push_u32(max as u32).push(" digits after the decimal point.")
+            }
+            Some(ParseError::InvalidRange) => self.push(": expected a non-empty range like `10..100` or `10..=99`."),
+            Some(ParseError::UnexpectedArgument) => self.push(": only an enabled flag can have an argument."),
+            Some(ParseError::SurrogateCodePoint) => {
+                self.push(": it's a surrogate code point (`U+D800` to `U+DFFF`), which isn't a valid `char`.")
+            }
+            Some(ParseError::MissingRateUnit) => {
+                self.push(": expected a `/` and a time unit after the size, like `10MB/s`.")
+            }
+            Some(ParseError::UnknownRateUnit) => {
+                self.push(": unrecognized time unit (expected `ms`, `s`, `min` or `h`).")
+            }
+            Some(ParseError::MissingTimeUnit) => self.push(": missing a time unit."),
+            Some(ParseError::UnknownTimeUnit) => self.push(": unrecognized time unit."),
+            Some(ParseError::MissingHexPrefix) => self.push(": expected a hex number starting with `0x`."),
+            Some(ParseError::WrongCount { expected, found }) => self
+                .push(": expected exactly ")
+                .push_u32(expected as u32)
+                .push(", found ")
+                .push_u32(if found > u32::MAX as usize { u32::MAX } else { found as u32 })
+                .push("."),
+            Some(ParseError::InvalidEscape { byte }) => {
+                self.push(": invalid escape `\\").push_escaped(byte).push("` in a quoted string.")
+            }
+            Some(ParseError::UnclosedQuote) => self.push(": a quoted string is missing its closing `\"`."),
+            Some(ParseError::TextAfterQuote) => {
+                self.push(": unexpected text after the closing `\"` of a quoted string.")
+            }
+            Some(ParseError::UnknownOperator { op }) => {
+                self.push(": unknown operator `").push_escaped(op).push("` (expected `+`, `-`, `*` or `/`).")
+            }
+            _ => self.push(fallback),
+        }
+    }
+}
+
+/// Called by the macros when parsing fails. Builds a message from `prefix`
+/// followed by either a more specific description of `err` (if we have one
+/// worth giving), or `fallback`.
+///
+/// This doesn't panic itself: the `panic!` has to be in the macro expansion,
+/// or older compilers point the error at this file rather than the caller.
+pub const fn fail_message(prefix: &str, err: Option<ParseError>, fallback: &str) -> Message {
+    Message::new().push(prefix).push_error(err, fallback)
+}
+
+/// Why splitting a `KEY:value` pair failed.
+#[derive(Copy, Clone)]
+pub enum PairError {
+    NoSeparator,
+    KeyLength,
+    /// The value failed to parse, and the result of `diagnose` for it.
+    Value(Option<ParseError>),
+}
+
+/// Splits `s` at the first `sep` into a key (with surrounding whitespace
+/// trimmed) and the bytes of the value, checking the length of the key (in
+/// bytes) is in `min_len..=max_len`.
+pub const fn split_pair(
+    s: &'static str,
+    sep: char,
+    min_len: usize,
+    max_len: usize,
+) -> Result<(&'static str, &'static [u8]), PairError> {
+    let (buf, len) = encode_utf8(sep);
+    let sep = crate::parse::subslice(&buf, 0, len);
+    let b = s.as_bytes();
+    let mut at = 0;
+    while at + sep.len() <= b.len() && !crate::parse::starts_with_at(b, at, sep) {
+        at += 1;
+    }
+    if at + sep.len() > b.len() {
+        return Err(PairError::NoSeparator);
+    }
+    let (mut start, mut end) = (0, at);
+    while start < end && b[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && b[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if end - start < min_len || end - start > max_len {
+        return Err(PairError::KeyLength);
+    }
+    let key = match core::str::from_utf8(crate::parse::subslice(b, start, end)) {
+        Ok(key) => key,
+        // We only split at ASCII whitespace and the boundaries of `sep`.
+        Err(_) => unreachable!(),
+    };
+    Ok((key, crate::parse::subslice(b, at + sep.len(), b.len())))
+}
+
+/// Called by the macros when parsing a `KEY:value` pair fails. Like
+/// [`fail_message`], the caller is responsible for panicking.
+pub const fn pair_message(prefix: &str, sep: &str, len: &str, typ: &str, err: PairError) -> Message {
+    let msg = Message::new().push(prefix);
+    match err {
+        PairError::NoSeparator => msg.push(" is missing the ").push(sep).push(" between the key and value."),
+        PairError::KeyLength => msg.push(" has a key whose length is outside of the range `").push(len).push("`."),
+        PairError::Value(e) => msg
+            .push(" has a value (after the ")
+            .push(sep)
+            .push(") which doesn't parse as a `")
+            .push(typ)
+            .push("`")
+            .push_error(e, ", or is out of range."),
+    }
+}
+
+/// Why parsing a list of `KEY:value` pairs for `pairs(str ':' T; N)` failed.
+#[derive(Copy, Clone)]
+pub enum PairsError {
+    /// Got `found` pairs, but wanted exactly `expected`.
+    TooMany { expected: usize, found: usize },
+    /// Got `found` pairs, but wanted exactly `expected`.
+    TooFew { expected: usize, found: usize },
+    /// The item at this index is empty, as in `a:1,,b:2`.
+    EmptyItem(usize),
+    /// The pair at this index failed to split or parse.
+    Pair(usize, PairError),
+}
+
+/// Called by the macros when parsing `pairs(str ':' T; N)` fails. Like
+/// [`fail_message`], the caller is responsible for panicking.
+pub const fn pairs_message(prefix: &str, typ: &str, sep: &str, item_typ: &str, err: PairsError) -> Message {
+    let msg = Message::new().push(prefix);
+    match err {
+        PairsError::TooMany { expected, found } => msg
+            .push(" has too many pairs for a `")
+            .push(typ)
+            .push("`: expected ")
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        PairsError::TooFew { expected, found } => msg
+            .push(" has too few pairs for a `")
+            .push(typ)
+            .push("`: expected ")
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        PairsError::EmptyItem(i) => msg
+            .push(" has an empty item (at index ")
+            .push_u32(i as u32)
+            .push(") in a `")
+            .push(typ)
+            .push("`. Remove any extra or trailing commas."),
+        PairsError::Pair(i, e) => {
+            let msg = msg.push(" has a pair (at index ").push_u32(i as u32).push(")");
+            match e {
+                PairError::NoSeparator => {
+                    msg.push(" which is missing the ").push(sep).push(" between the key and value.")
+                }
+                PairError::KeyLength => msg.push(" with an empty key."),
+                PairError::Value(e) => msg
+                    .push(" with a value which doesn't parse as a `")
+                    .push(item_typ)
+                    .push("`")
+                    .push_error(e, ", or is out of range."),
+            }
+        }
+    }
+}
+
+/// Why parsing a `value:min:max` triple for `self_bounded(T)` failed.
+#[derive(Copy, Clone)]
+pub enum TripleError {
+    /// The whole value was blank, and there was no default.
+    Empty,
+    /// There weren't exactly two `:`s.
+    Shape,
+    /// Part 0 (the value), 1 (the min) or 2 (the max) failed to parse, and
+    /// the result of `diagnose` for it.
+    Part(usize, Option<ParseError>),
+    /// The min is greater than the max.
+    Inverted,
+    /// The value is outside of `min..=max`.
+    OutOfBounds,
+}
+
+/// Called by the macros when parsing a `self_bounded(T)` fails. Like
+/// [`fail_message`], the caller is responsible for panicking.
+pub const fn triple_message(prefix: &str, typ: &str, err: TripleError) -> Message {
+    let msg = Message::new().push(prefix);
+    match err {
+        TripleError::Empty => msg.push(" is empty."),
+        TripleError::Shape => msg.push(" isn't of the form `value:min:max`."),
+        TripleError::Part(i, e) => msg
+            .push(" has a ")
+            .push(match i {
+                0 => "value",
+                1 => "min",
+                _ => "max",
+            })
+            .push(" which doesn't parse as a `")
+            .push(typ)
+            .push("`")
+            .push_error(e, ", or is out of range."),
+        TripleError::Inverted => msg.push(" has a min which is greater than its max."),
+        TripleError::OutOfBounds => msg.push(" has a value outside of its own `min:max` bounds."),
+    }
+}
+
+/// Parsers for `self_bounded(T)`, looked up by `T`. These parse `value:min:max`
+/// and check that `min <= value <= max`, giving just the value.
+pub mod self_bounded {
+    use super::TripleError;
+    use crate::parse::{split_once, trim_ws};
+
+    macro_rules! def_self_bounded {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], default: Option<$t>) -> Result<$t, TripleError> {
+                if trim_ws(s).is_none() {
+                    return match default {
+                        Some(d) => Ok(d),
+                        None => Err(TripleError::Empty),
+                    };
+                }
+                let (value, rest) = unwrap_or!(split_once(s, b':'), return Err(TripleError::Shape));
+                let (min, max) = unwrap_or!(split_once(rest, b':'), return Err(TripleError::Shape));
+                if split_once(max, b':').is_some() {
+                    return Err(TripleError::Shape);
+                }
+                let parts = [value, min, max];
+                let mut out: [$t; 3] = [0; 3];
+                let mut i = 0;
+                while i < 3 {
+                    out[i] = unwrap_or!(
+                        super::parsers::$t(parts[i], None),
+                        return Err(TripleError::Part(i, super::diagnose::$t(parts[i])))
+                    );
+                    i += 1;
+                }
+                let [value, min, max] = out;
+                if min > max {
+                    Err(TripleError::Inverted)
+                } else if value < min || value > max {
+                    Err(TripleError::OutOfBounds)
+                } else {
+                    Ok(value)
+                }
+            }
+        )*};
+    }
+
+    def_self_bounded! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// `char::encode_utf8`, but usable in const. Returns the buffer and the number
+/// of bytes used.
+const fn encode_utf8(c: char) -> ([u8; 4], usize) {
+    let c = c as u32;
+    if c < 0x80 {
+        ([c as u8, 0, 0, 0], 1)
+    } else if c < 0x800 {
+        ([0xc0 | (c >> 6) as u8, 0x80 | (c & 0x3f) as u8, 0, 0], 2)
+    } else if c < 0x10000 {
+        ([0xe0 | (c >> 12) as u8, 0x80 | ((c >> 6) & 0x3f) as u8, 0x80 | (c & 0x3f) as u8, 0], 3)
+    } else {
+        let b = [
+            0xf0 | (c >> 18) as u8,
+            0x80 | ((c >> 12) & 0x3f) as u8,
+            0x80 | ((c >> 6) & 0x3f) as u8,
+            0x80 | (c & 0x3f) as u8,
+        ];
+        (b, 4)
+    }
+}
+
+/// Why parsing an array failed.
+#[derive(Copy, Clone)]
+pub enum ArrayError {
+    /// Got `found` items, but wanted at most `expected` (or exactly, if
+    /// `exact` is set).
+    TooMany { expected: usize, found: usize, exact: bool },
+    /// Got `found` items, but wanted exactly `expected`.
+    TooFew { expected: usize, found: usize },
+    /// The item at this index failed to parse, and the result of `diagnose`
+    /// for it.
+    Item(usize, Option<ParseError>),
+    /// The item at this index is empty (as in `1,,3` or `1,2,`), and there's
+    /// no default to use for it.
+    EmptyItem(usize),
+    /// Got more than `expected` distinct items, for a `list(T; max N) unique`.
+    TooManyDistinct { expected: usize },
+}
+
+/// Why parsing a grid (an array of arrays) failed.
+#[derive(Copy, Clone)]
+pub enum GridError {
+    /// Got `found` rows, but wanted at most `expected` (or exactly, if `exact`
+    /// is set).
+    TooManyRows { expected: usize, found: usize, exact: bool },
+    /// Got `found` rows, but wanted exactly `expected`.
+    TooFewRows { expected: usize, found: usize },
+    /// The row at this index failed to parse as an array.
+    Row(usize, ArrayError),
+}
+
+/// Called by the macros when parsing an array fails. Like [`fail_message`],
+/// the caller is responsible for panicking.
+pub const fn array_message(prefix: &str, typ: &str, item_typ: &str, err: ArrayError) -> Message {
+    let msg = Message::new().push(prefix);
+    match err {
+        ArrayError::TooMany { expected, found, exact } => msg
+            .push(" has too many items for a `")
+            .push(typ)
+            .push(if exact { "`: expected " } else { "`: expected at most " })
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        ArrayError::TooFew { expected, found } => msg
+            .push(" has too few items for a `")
+            .push(typ)
+            .push("`: expected ")
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        ArrayError::Item(i, e) => msg
+            .push(" has an item (at index ")
+            .push_u32(i as u32)
+            .push(") which doesn't parse as a `")
+            .push(item_typ)
+            .push("`")
+            .push_error(e, ", or is out of range."),
+        ArrayError::EmptyItem(i) => msg
+            .push(" has an empty item (at index ")
+            .push_u32(i as u32)
+            .push(") in a `")
+            .push(typ)
+            .push("`. Remove any extra or trailing commas."),
+        ArrayError::TooManyDistinct { expected } => {
+            msg.push(" has more than ").push_u32(expected as u32).push(" distinct items for a `").push(typ).push("`.")
+        }
+    }
+}
+
+/// Called by the macros when parsing a grid fails. Errors in a row name the
+/// row's type, `row_typ`, rather than the whole grid's. Like [`fail_message`],
+/// the caller is responsible for panicking.
+pub const fn grid_message(prefix: &str, typ: &str, row_typ: &str, item_typ: &str, err: GridError) -> Message {
+    let msg = Message::new().push(prefix);
+    match err {
+        GridError::TooManyRows { expected, found, exact } => msg
+            .push(" has too many rows for a `")
+            .push(typ)
+            .push(if exact { "`: expected " } else { "`: expected at most " })
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        GridError::TooFewRows { expected, found } => msg
+            .push(" has too few rows for a `")
+            .push(typ)
+            .push("`: expected ")
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        GridError::Row(r, ArrayError::TooMany { expected, found, exact }) => msg
+            .push(" has a row (at index ")
+            .push_u32(r as u32)
+            .push(") with too many items for a `")
+            .push(row_typ)
+            .push(if exact { "`: expected " } else { "`: expected at most " })
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        GridError::Row(r, ArrayError::TooFew { expected, found }) => msg
+            .push(" has a row (at index ")
+            .push_u32(r as u32)
+            .push(") with too few items for a `")
+            .push(row_typ)
+            .push("`: expected ")
+            .push_u32(expected as u32)
+            .push(", found ")
+            .push_u32(found as u32)
+            .push("."),
+        GridError::Row(r, ArrayError::Item(c, e)) => msg
+            .push(" has an item (at row ")
+            .push_u32(r as u32)
+            .push(", column ")
+            .push_u32(c as u32)
+            .push(") which doesn't parse as a `")
+            .push(item_typ)
+            .push("`")
+            .push_error(e, ", or is out of range."),
+        GridError::Row(r, ArrayError::EmptyItem(c)) => msg
+            .push(" has an empty item (at row ")
+            .push_u32(r as u32)
+            .push(", column ")
+            .push_u32(c as u32)
+            .push(") in a `")
+            .push(row_typ)
+            .push("`. Remove any extra or trailing commas."),
+        GridError::Row(r, ArrayError::TooManyDistinct { expected }) => msg
+            .push(" has a row (at index ")
+            .push_u32(r as u32)
+            .push(") with more than ")
+            .push_u32(expected as u32)
+            .push(" distinct items for a `")
+            .push(row_typ)
+            .push("`."),
+    }
+}
+
+/// Parsers for `T hex_required`, looked up by `T`. These require the `0x`
+/// prefix, and then parse like `parsers`. On failure, the result of `diagnose`
+/// (or the missing prefix) is returned.
+pub mod hex_required {
+    use crate::parse::ParseError;
+
+    macro_rules! def_hex_required {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], default: Option<$t>) -> Result<$t, Option<ParseError>> {
+                match crate::parse::require_hex_prefix(s) {
+                    // Blank values are handled (and defaulted) as usual.
+                    Ok(()) | Err(ParseError::Empty) => {}
+                    Err(e) => return Err(Some(e)),
+                }
+                match super::parsers::$t(s, default) {
+                    Some(v) => Ok(v),
+                    None => Err(super::diagnose::$t(s)),
+                }
+            }
+        )*};
+    }
+
+    def_hex_required! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// Parsers for `T hex digits N`, looked up by `T`. On failure, the error from
+/// `parse_hex_digits` is returned.
+pub mod hex_digits {
+    use crate::parse::ParseError;
+
+    macro_rules! def_hex_digits {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], digits: usize, default: Option<$t>) -> Result<$t, Option<ParseError>> {
+                match crate::parse::parse_hex_digits(s, digits, $t::MAX as u128) {
+                    Ok(v) => Ok(v as $t),
+                    Err(ParseError::Empty) => match default {
+                        Some(d) => Ok(d),
+                        None => Err(Some(ParseError::Empty)),
+                    },
+                    Err(e) => Err(Some(e)),
+                }
+            }
+        )*};
+    }
+
+    def_hex_digits!(usize, u8, u16, u32, u64, u128);
+}
+
+/// Why parsing `T hex_required aligned N` failed.
+#[derive(Copy, Clone)]
+pub enum AlignError {
+    /// The value failed to parse, and the error from `hex_required`.
+    Parse(Option<ParseError>),
+    /// The value parsed, but isn't a multiple of the alignment.
+    Misaligned,
+}
+
+/// Parsers for `T hex_required aligned N`, looked up by `T`. These parse like
+/// `hex_required`, and then check the value is a multiple of `align` (which the
+/// macro has already checked is a power of two).
+pub mod aligned {
+    use super::AlignError;
+
+    macro_rules! def_aligned {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], align: $t, default: Option<$t>) -> Result<$t, AlignError> {
+                match super::hex_required::$t(s, default) {
+                    Ok(v) if v & (align - 1) == 0 => Ok(v),
+                    Ok(_) => Err(AlignError::Misaligned),
+                    Err(e) => Err(AlignError::Parse(e)),
+                }
+            }
+        )*};
+    }
+
+    def_aligned!(usize, u8, u16, u32, u64, u128);
+}
+
+/// The parser for `[u8; N] fill`. On failure, the error from `parse_fill` is
+/// returned.
+pub const fn fill<const N: usize>(s: &[u8], default: Option<[u8; N]>) -> Result<[u8; N], Option<ParseError>> {
+    match crate::parse::parse_fill(s) {
+        Ok(v) => Ok(v),
+        Err(ParseError::Empty) => match default {
+            Some(d) if crate::parse::trim_ws(s).is_none() => Ok(d),
+            _ => Err(Some(ParseError::Empty)),
+        },
+        Err(e) => Err(Some(e)),
+    }
+}
+
+/// The parser for `strbuf[N]`. A blank value gives the bytes of `default` (as
+/// they are, without unescaping) if there is one. On failure, the error from
+/// `parse_strbuf` is returned.
+pub const fn strbuf<const N: usize>(s: &[u8], default: Option<&str>) -> Result<([u8; N], usize), Option<ParseError>> {
+    if let Some(d) = default {
+        if crate::parse::trim_ws(s).is_none() {
+            let d = d.as_bytes();
+            let mut out = [0u8; N];
+            let mut i = 0;
+            while i < d.len() && i < N {
+                out[i] = d[i];
+                i += 1;
+            }
+            return Ok((out, i));
+        }
+    }
+    match crate::parse::parse_strbuf(s) {
+        Ok(v) => Ok(v),
+        Err(e) => Err(Some(e)),
+    }
+}
+
+/// Parsers for `T relative_to N`, looked up by `T`. A blank value gives
+/// `default`, if there is one. On failure, the error from `parse_relative` is
+/// returned.
+pub mod relative {
+    use crate::parse::ParseError;
+
+    macro_rules! def_relative {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], base: $t, default: Option<$t>) -> Result<$t, Option<ParseError>> {
+                match crate::parse::parse_relative(s, base as u128, $t::MAX as u128) {
+                    Ok(v) => Ok(v as $t),
+                    Err(ParseError::Empty) => match default {
+                        Some(d) => Ok(d),
+                        None => Err(Some(ParseError::Empty)),
+                    },
+                    Err(e) => Err(Some(e)),
+                }
+            }
+        )*};
+    }
+
+    def_relative!(usize, u8, u16, u32, u64, u128);
+}
+
+/// The parser for `q(I, F)`. A blank value gives `default`, if there is one.
+/// On failure, the error from `parse_qfixed` (or `parse_qfixed_clamp`) is
+/// returned.
+pub const fn qfixed(
+    s: &[u8],
+    int_bits: u32,
+    frac_bits: u32,
+    clamp: bool,
+    default: Option<i128>,
+) -> Result<i128, Option<ParseError>> {
+    let res = if clamp {
+        crate::parse::parse_qfixed_clamp(s, int_bits, frac_bits)
+    } else {
+        crate::parse::parse_qfixed(s, int_bits, frac_bits)
+    };
+    match res {
+        Ok(v) => Ok(v),
+        Err(ParseError::Empty) => match default {
+            Some(d) => Ok(d),
+            None => Err(Some(ParseError::Empty)),
+        },
+        Err(e) => Err(Some(e)),
+    }
+}
+
+/// Returns true if `int_bits + frac_bits` is a width `q(I, F)` supports.
+pub const fn qfixed_valid(int_bits: u32, frac_bits: u32) -> bool {
+    let total = int_bits as u64 + frac_bits as u64;
+    total >= 1 && total <= 128
+}
+
+/// Returns true if `v` fits in a `q(I, F)`, for checking defaults.
+pub const fn qfixed_fits(v: i128, int_bits: u32, frac_bits: u32) -> bool {
+    let total = int_bits + frac_bits;
+    total >= 128 || (v >= -(1i128 << (total - 1)) && v < (1i128 << (total - 1)))
+}
+
+/// Parsers for `flag(T; on N)`, looked up by `T`. These give `None` for a false
+/// flag, `Some(on)` for a true one, and `Some(arg)` for a true one with an
+/// argument, as in `on:256`. On failure, the error from `split_flag` or the
+/// result of `diagnose` for the argument is returned.
+pub mod flag {
+    use crate::parse::ParseError;
+
+    macro_rules! def_flag {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], on: $t, default: Option<Option<$t>>) -> Result<Option<$t>, Option<ParseError>> {
+                if crate::parse::trim_ws(s).is_none() {
+                    return match default {
+                        Some(d) => Ok(d),
+                        None => Err(Some(ParseError::Empty)),
+                    };
+                }
+                match crate::parse::split_flag(s) {
+                    Ok((false, _)) => Ok(None),
+                    Ok((true, None)) => Ok(Some(on)),
+                    Ok((true, Some(arg))) => match super::parsers::$t(arg, None) {
+                        Some(v) => Ok(Some(v)),
+                        None => Err(super::diagnose::$t(arg)),
+                    },
+                    Err(e) => Err(Some(e)),
+                }
+            }
+        )*};
+    }
+
+    def_flag! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// Parsers for the range given by `in_env_range`, looked up by the type it's a
+/// range of. These return the inclusive bounds, which are the bounds of the
+/// type on any side left blank (or for a blank value). On failure, the result
+/// of `diagnose` for the bad side is returned, or `InvalidRange` if there's no
+/// `..` or the range is empty.
+pub mod env_range {
+    use crate::parse::ParseError;
+
+    macro_rules! def_env_range {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8]) -> Result<($t, $t), Option<ParseError>> {
+                let (start, end, incl) = match crate::parse::split_range(s) {
+                    Ok(tup) => tup,
+                    Err(ParseError::Empty) => return Ok(($t::MIN, $t::MAX)),
+                    Err(e) => return Err(Some(e)),
+                };
+                let min = match super::parsers::$t(start, Some($t::MIN)) {
+                    Some(v) => v,
+                    None => return Err(super::diagnose::$t(start)),
+                };
+                let max = match super::parsers::$t(end, Some($t::MAX)) {
+                    // A blank end is unbounded, even if it's exclusive.
+                    Some(v) if incl || crate::parse::trim_ws(end).is_none() => v,
+                    Some($t::MIN) => return Err(Some(ParseError::InvalidRange)),
+                    Some(v) => v - 1,
+                    None => return Err(super::diagnose::$t(end)),
+                };
+                if min > max {
+                    return Err(Some(ParseError::InvalidRange));
+                }
+                Ok((min, max))
+            }
+        )*};
+    }
+
+    def_env_range! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// Parsers for `limit(T)`, looked up by `T`. These give `Limit::Auto` for
+/// `auto` and `Limit::Max` for `max` (ignoring case and surrounding
+/// whitespace), and otherwise parse a `T`. On failure, the result of
+/// `diagnose` is returned.
+pub mod limit {
+    use crate::parse::ParseError;
+    use crate::Limit;
+
+    macro_rules! def_limit {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], default: Option<Limit<$t>>) -> Result<Limit<$t>, Option<ParseError>> {
+                match crate::parse::match_word(s, &["auto", "max"]) {
+                    Ok(0) => return Ok(Limit::Auto),
+                    Ok(_) => return Ok(Limit::Max),
+                    Err(_) => {}
+                }
+                if crate::parse::trim_ws(s).is_none() {
+                    return match default {
+                        Some(d) => Ok(d),
+                        None => Err(Some(ParseError::Empty)),
+                    };
+                }
+                match super::parsers::$t(s, None) {
+                    Some(v) => Ok(Limit::Value(v)),
+                    None => Err(super::diagnose::$t(s)),
+                }
+            }
+        )*};
+    }
+
+    def_limit! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// Parsers for `auto_or(T)`, looked up by `T`. These give `Ok(None)` for
+/// `auto` (ignoring case and surrounding whitespace), a blank value, or zero,
+/// and otherwise parse a `T` in `min..=max`. Negative values are rejected with
+/// `UnexpectedSign`, even for signed types. On other failures, the result of
+/// `diagnose` is returned.
+pub mod auto_or {
+    use crate::parse::ParseError;
+
+    macro_rules! def_auto_or {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(s: &[u8], min: $t, max: $t) -> Result<Option<$t>, Option<ParseError>> {
+                match crate::parse::trim_ws(s) {
+                    None => return Ok(None),
+                    // A negative count makes no sense, even if `T` is signed.
+                    Some((start, _)) if s[start] == b'-' => return Err(Some(ParseError::UnexpectedSign)),
+                    Some(_) => {}
+                }
+                if crate::parse::match_word(s, &["auto"]).is_ok() {
+                    return Ok(None);
+                }
+                match super::parse_bounded::$t(s, None, None, None, false) {
+                    // Zero means `auto` too, even if the range excludes it.
+                    Some(0) => Ok(None),
+                    Some(v) if v >= min && v <= max => Ok(Some(v)),
+                    Some(_) => Err(None),
+                    None => Err(super::diagnose::$t(s)),
+                }
+            }
+        )*};
+    }
+
+    def_auto_or! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+    }
+}
+
+/// Where the first item of a list starts: at the beginning, unless `loose`
+/// (in which case leading separators are skipped).
+const fn first_item(s: &[u8], loose: bool) -> usize {
+    if loose {
+        crate::parse::skip_loose_seps(s, 0)
+    } else {
+        0
+    }
+}
+
+/// Finds the item at `pos`, with `next_item`, or with `next_item_loose` if
+/// `loose` (where any run of commas and whitespace separates items).
+const fn next_item(s: &[u8], pos: usize, loose: bool) -> (usize, usize, usize) {
+    if loose {
+        crate::parse::next_item_loose(s, pos)
+    } else {
+        crate::parse::next_item(s, pos)
+    }
+}
+
+/// Counts the items in `s` from `pos` onwards, for reporting how many there
+/// were after finding too many.
+const fn count_items(s: &[u8], mut pos: usize, loose: bool) -> usize {
+    let mut n = 0;
+    while pos < s.len() {
+        pos = next_item(s, pos, loose).2;
+        n += 1;
+    }
+    n
+}
+
+/// Invokes `$m!` with each type that arrays can hold, along with a value of
+/// that type to use for slots that aren't filled in otherwise.
+macro_rules! with_array_item_types {
+    ($m:ident) => {
+        $m! {
+            usize = 0, u8 = 0, u16 = 0, u32 = 0, u64 = 0, u128 = 0,
+            isize = 0, i8 = 0, i16 = 0, i32 = 0, i64 = 0, i128 = 0,
+            bool = false, bool_tf = false, bool_first_char = false, bool_sign = false, char = '\0', int = 0,
+            cased_size = 0, rate = 0, degrees = 0, ratio = (0, 1),
+            on_off_auto = crate::OnOffAuto::Off, sort_dir = crate::SortDir::Ascending,
+            prompt_policy = crate::PromptPolicy::Ask,
+            lint_level = crate::LintLevel::Allow,
+            duration = core::time::Duration::ZERO, Ipv4Addr = core::net::Ipv4Addr::UNSPECIFIED, version = (0, 0, 0),
+            char_code = '\0', basis_points = 0, weighted_flag = (false, 0), mode = 0,
+            NonZeroUsize = types::NonZeroUsize::MIN, NonZeroU8 = types::NonZeroU8::MIN,
+            NonZeroU16 = types::NonZeroU16::MIN, NonZeroU32 = types::NonZeroU32::MIN,
+            NonZeroU64 = types::NonZeroU64::MIN, NonZeroU128 = types::NonZeroU128::MIN,
+            NonZeroIsize = types::NonZeroIsize::MIN, NonZeroI8 = types::NonZeroI8::MIN,
+            NonZeroI16 = types::NonZeroI16::MIN, NonZeroI32 = types::NonZeroI32::MIN,
+            NonZeroI64 = types::NonZeroI64::MIN, NonZeroI128 = types::NonZeroI128::MIN,
+        }
+    };
+}
+
+/// Parsers for `[T; N]`, from a comma-separated list of `T`. These are looked
+/// up by the element type, the same way as in `parsers`.
+///
+/// If a default is provided, missing or empty items take the value from the
+/// same position in the default. Otherwise, exactly `N` items are required.
+/// More than `N` items and a trailing comma are always errors. With `loose`,
+/// items are separated by any run of commas and whitespace instead.
+pub mod array_parsers {
+    use super::{first_item, next_item, types, ArrayError};
+    use crate::parse::subslice;
+
+    macro_rules! def_array_parser {
+        ($($t:ident = $zero:expr),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t<const N: usize>(
+                s: &[u8],
+                default: Option<[types::$t; N]>,
+                loose: bool,
+            ) -> Result<[types::$t; N], ArrayError> {
+                let mut out = unwrap_or!(default, [$zero; N]);
+                let mut i = 0;
+                let mut pos = first_item(s, loose);
+                while pos < s.len() {
+                    if i == N {
+                        let found = N + super::count_items(s, pos, loose);
+                        return Err(ArrayError::TooMany { expected: N, found, exact: default.is_none() });
+                    }
+                    let (start, end, next) = next_item(s, pos, loose);
+                    // Empty items take the default, except for the last one
+                    // after a comma, which is a trailing comma.
+                    if start == end && (default.is_none() || (i > 0 && next > s.len())) {
+                        return Err(ArrayError::EmptyItem(i));
+                    }
+                    let item = subslice(s, start, end);
+                    let item_default = if default.is_some() { Some(out[i]) } else { None };
+                    out[i] = unwrap_or!(
+                        super::parsers::$t(item, item_default),
+                        return Err(ArrayError::Item(i, super::diagnose::$t(item)))
+                    );
+                    i += 1;
+                    pos = next;
+                }
+                // A trailing comma leaves `pos` at the end, rather than past it.
+                if !loose && pos == s.len() && !s.is_empty() {
+                    return Err(ArrayError::EmptyItem(i));
+                }
+                if i < N && default.is_none() {
+                    return Err(ArrayError::TooFew { expected: N, found: i });
+                }
+                Ok(out)
+            }
+        )*};
+    }
+
+    with_array_item_types!(def_array_parser);
+}
+
+/// Parsers for `[[T; C]; R]`, from `R` rows separated by `;`, each of which is
+/// parsed like an array of `C` items (with `array_parsers`). These are looked
+/// up by the element type.
+///
+/// If a default is provided, missing or empty rows are taken from it, and each
+/// row is parsed with the default's row as its default. Otherwise, exactly `R`
+/// rows are required.
+pub mod grid_parsers {
+    use super::{types, GridError};
+    use crate::parse::{next_split, subslice};
+
+    macro_rules! def_grid_parser {
+        ($($t:ident = $zero:expr),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t<const C: usize, const R: usize>(
+                s: &[u8],
+                default: Option<[[types::$t; C]; R]>,
+            ) -> Result<[[types::$t; C]; R], GridError> {
+                let mut out = unwrap_or!(default, [[$zero; C]; R]);
+                let mut r = 0;
+                let mut pos = 0;
+                while pos < s.len() {
+                    if r == R {
+                        let mut found = R;
+                        while pos < s.len() {
+                            pos = next_split(s, pos, b';').2;
+                            found += 1;
+                        }
+                        return Err(GridError::TooManyRows { expected: R, found, exact: default.is_none() });
+                    }
+                    let (start, end, next) = next_split(s, pos, b';');
+                    let row_default = if default.is_some() { Some(out[r]) } else { None };
+                    out[r] = match super::array_parsers::$t::<C>(subslice(s, start, end), row_default, false) {
+                        Ok(row) => row,
+                        Err(e) => return Err(GridError::Row(r, e)),
+                    };
+                    r += 1;
+                    pos = next;
+                }
+                if r < R && default.is_none() {
+                    return Err(GridError::TooFewRows { expected: R, found: r });
+                }
+                Ok(out)
+            }
+        )*};
+    }
+
+    with_array_item_types!(def_grid_parser);
+}
+
+/// Parsers for `pairs(str ':' T; N)`, looked up by `T`. These split a list of
+/// exactly `N` comma-separated `KEY:value` pairs (with `split_pair`, requiring
+/// a non-empty key) into parallel arrays of the keys and values. A blank value
+/// gives the default, if there is one.
+pub mod pairs_parsers {
+    use super::{split_pair, types, PairError, PairsError};
+    use crate::parse::{next_item, subslice};
+
+    macro_rules! def_pairs_parser {
+        ($($t:ident = $zero:expr),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t<const N: usize>(
+                s: &'static str,
+                sep: char,
+                default: Option<([&'static str; N], [types::$t; N])>,
+            ) -> Result<([&'static str; N], [types::$t; N]), PairsError> {
+                let b = s.as_bytes();
+                if let (Some(default), None) = (default, crate::parse::trim_ws(b)) {
+                    return Ok(default);
+                }
+                let mut keys = [""; N];
+                let mut values = [$zero; N];
+                let mut i = 0;
+                let mut pos = 0;
+                while pos < b.len() {
+                    let (start, end, next) = next_item(b, pos);
+                    if start == end {
+                        return Err(PairsError::EmptyItem(i));
+                    }
+                    if i == N {
+                        let found = N + super::count_items(b, pos, false);
+                        return Err(PairsError::TooMany { expected: N, found });
+                    }
+                    let item = match core::str::from_utf8(subslice(b, start, end)) {
+                        Ok(item) => item,
+                        // We only split at ASCII commas and whitespace.
+                        Err(_) => unreachable!(),
+                    };
+                    let (key, value) = match split_pair(item, sep, 1, usize::MAX) {
+                        Ok(pair) => pair,
+                        Err(e) => return Err(PairsError::Pair(i, e)),
+                    };
+                    keys[i] = key;
+                    values[i] = match super::parsers::$t(value, None) {
+                        Some(v) => v,
+                        None => return Err(PairsError::Pair(i, PairError::Value(super::diagnose::$t(value)))),
+                    };
+                    i += 1;
+                    pos = next;
+                }
+                // A trailing comma leaves `pos` at the end, rather than past it.
+                if pos == b.len() && !b.is_empty() {
+                    return Err(PairsError::EmptyItem(i));
+                }
+                if i < N {
+                    return Err(PairsError::TooFew { expected: N, found: i });
+                }
+                Ok((keys, values))
+            }
+        )*};
+    }
+
+    with_array_item_types!(def_pairs_parser);
+}
+
+/// Parsers for `list(T; max N)`, from a comma-separated list of at most `N`
+/// items. These give the items followed by copies of `fill` (or a placeholder
+/// like zero if it's `None`), and the number of items.
+///
+/// A blank value gives `default` if there is one, and no items otherwise.
+/// Unlike arrays, empty items (including after a trailing comma) are always an
+/// error, since there's no position to take a default from. As with arrays,
+/// `loose` separates items by any run of commas and whitespace.
+pub mod list_parsers {
+    use super::{first_item, next_item, types, ArrayError};
+    use crate::parse::{subslice, trim_ws};
+
+    macro_rules! def_list_parser {
+        ($($t:ident = $zero:expr),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t<const N: usize>(
+                s: &[u8],
+                fill: Option<types::$t>,
+                default: Option<&[types::$t]>,
+                loose: bool,
+            ) -> Result<([types::$t; N], usize), ArrayError> {
+                let mut out = [unwrap_or!(fill, $zero); N];
+                if trim_ws(s).is_none() {
+                    let default = unwrap_or!(default, &[]);
+                    let mut i = 0;
+                    while i < default.len() && i < N {
+                        out[i] = default[i];
+                        i += 1;
+                    }
+                    return Ok((out, i));
+                }
+                let mut i = 0;
+                let mut pos = first_item(s, loose);
+                while pos < s.len() {
+                    if i == N {
+                        let found = N + super::count_items(s, pos, loose);
+                        return Err(ArrayError::TooMany { expected: N, found, exact: false });
+                    }
+                    let (start, end, next) = next_item(s, pos, loose);
+                    let item = subslice(s, start, end);
+                    out[i] = unwrap_or!(
+                        super::parsers::$t(item, None),
+                        return Err(ArrayError::Item(i, super::diagnose::$t(item)))
+                    );
+                    i += 1;
+                    pos = next;
+                }
+                // A trailing comma leaves `pos` at the end, rather than past it.
+                if !loose && pos == s.len() {
+                    return Err(ArrayError::EmptyItem(i));
+                }
+                Ok((out, i))
+            }
+        )*};
+    }
+
+    with_array_item_types!(def_list_parser);
+}
+
+/// Parsers for `list(T; max N) unique`, for integer `T`. These parse a
+/// comma-separated list like `list_parsers`, but keep the items sorted in
+/// ascending order with duplicates removed, returning them and how many
+/// distinct items there were. Duplicates don't count against `N`, so only more
+/// than `N` distinct items is an error.
+///
+/// Each item is inserted into place as it's parsed (an insertion sort), which
+/// is quadratic, but lists in environment variables are short. A blank value
+/// uses `default` (sorted and deduplicated the same way), or is empty. As with
+/// `list_parsers`, empty items are an error, and `loose` separates items by
+/// any run of commas and whitespace.
+pub mod unique_list_parsers {
+    use super::{first_item, next_item, ArrayError};
+    use crate::parse::{subslice, trim_ws};
+
+    macro_rules! def_insert {
+        ($($t:ident),* $(,)?) => {$(
+            /// Inserts `v` into its place in the sorted `out[..len]`, unless
+            /// it's already there. Returns `None` if there's no room for it.
+            pub(super) const fn $t<const N: usize>(mut out: [$t; N], len: usize, v: $t) -> Option<([$t; N], usize)> {
+                let mut at = 0;
+                while at < len && out[at] < v {
+                    at += 1;
+                }
+                if at < len && out[at] == v {
+                    return Some((out, len));
+                }
+                if len == N {
+                    return None;
+                }
+                let mut i = len;
+                while i > at {
+                    out[i] = out[i - 1];
+                    i -= 1;
+                }
+                out[at] = v;
+                Some((out, len + 1))
+            }
+        )*};
+    }
+
+    mod insert {
+        def_insert!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+    }
+
+    macro_rules! def_unique_list_parsers {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t<const N: usize>(
+                s: &[u8],
+                default: Option<&[$t]>,
+                loose: bool,
+            ) -> Result<([$t; N], usize), ArrayError> {
+                let mut out = [0; N];
+                let mut len = 0;
+                if trim_ws(s).is_none() {
+                    let default = unwrap_or!(default, &[]);
+                    let mut i = 0;
+                    while i < default.len() {
+                        (out, len) = unwrap_or!(
+                            insert::$t(out, len, default[i]),
+                            return Err(ArrayError::TooManyDistinct { expected: N })
+                        );
+                        i += 1;
+                    }
+                    return Ok((out, len));
+                }
+                let mut i = 0;
+                let mut pos = first_item(s, loose);
+                while pos < s.len() {
+                    let (start, end, next) = next_item(s, pos, loose);
+                    let item = subslice(s, start, end);
+                    let v = unwrap_or!(
+                        super::parsers::$t(item, None),
+                        return Err(ArrayError::Item(i, super::diagnose::$t(item)))
+                    );
+                    (out, len) = unwrap_or!(
+                        insert::$t(out, len, v),
+                        return Err(ArrayError::TooManyDistinct { expected: N })
+                    );
+                    i += 1;
+                    pos = next;
+                }
+                // A trailing comma leaves `pos` at the end, rather than past it.
+                if !loose && pos == s.len() {
+                    return Err(ArrayError::EmptyItem(i));
+                }
+                Ok((out, len))
+            }
+        )*};
+    }
+
+    def_unique_list_parsers!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+}
+
+/// Parsers for `[Option<T>; N]`, from a comma-separated list of `T` where
+/// empty items are `None`. Missing trailing items are also `None`, so `"1,0"`
+/// is `[Some(true), Some(false), None]` for `N = 3`. More than `N` items is an
+/// error, as is a trailing comma (as in `"1,0,"`).
+pub mod option_array_parsers {
+    use super::{types, ArrayError};
+    use crate::parse::{next_item, subslice};
+
+    macro_rules! def_option_array_parser {
+        ($($t:ident),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $t<const N: usize>(s: &[u8]) -> Result<[Option<types::$t>; N], ArrayError> {
+                let mut out = [None; N];
+                let mut i = 0;
+                let mut pos = 0;
+                while pos < s.len() {
+                    if i == N {
+                        let found = N + super::count_items(s, pos, false);
+                        return Err(ArrayError::TooMany { expected: N, found, exact: false });
+                    }
+                    let (start, end, next) = next_item(s, pos);
+                    if start != end {
+                        let item = subslice(s, start, end);
+                        out[i] = Some(unwrap_or!(
+                            super::parsers::$t(item, None),
+                            return Err(ArrayError::Item(i, super::diagnose::$t(item)))
+                        ));
+                    }
+                    i += 1;
+                    pos = next;
+                }
+                // A trailing comma leaves `pos` at the end, rather than past it.
+                if pos == s.len() && !s.is_empty() {
+                    return Err(ArrayError::EmptyItem(i));
+                }
+                Ok(out)
+            }
+        )*};
+    }
+
+    def_option_array_parser! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+        bool, bool_tf, bool_first_char, bool_sign, char, int, cased_size, rate, degrees, ratio, on_off_auto, sort_dir,
+        duration, Ipv4Addr, version, char_code, basis_points, weighted_flag, mode, lint_level, prompt_policy,
+        NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
+        NonZeroIsize, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128,
+    }
+}
+
+/// Why nothing could ever be inside the range given to `in`.
+#[derive(Copy, Clone)]
+pub enum RangeProblem {
+    Empty,
+    IncludesZero,
+}
+
+/// Called by the macros when the range given to `in` is unusable. Like
+/// [`fail_message`], the caller is responsible for panicking.
+pub const fn range_message(prefix: &str, typ: &str, problem: RangeProblem) -> Message {
+    let msg = Message::new().push(prefix);
+    match problem {
+        RangeProblem::Empty => msg.push(" is empty, so no `").push(typ).push("` could be inside of it."),
+        RangeProblem::IncludesZero => msg.push(" includes zero, which a `").push(typ).push("` can't be."),
+    }
+}
+
+/// Looked up by type the same way as `range_problem`, these check that an
+/// `else` default is inside the (inclusive) bounds from a `RangeWrap`.
+pub mod in_range {
+    use super::types;
+
+    macro_rules! def_in_range {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(v: $t, start: $t, end: $t) -> bool {
+                start <= v && v <= end
+            }
+        )*};
+    }
+
+    def_in_range! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+        char,
+    }
+
+    pub const fn int(v: i128, start: i128, end: i128) -> bool {
+        i128(v, start, end)
+    }
+
+    pub const fn cased_size(v: usize, start: usize, end: usize) -> bool {
+        usize(v, start, end)
+    }
+
+    pub const fn rate(v: u64, start: u64, end: u64) -> bool {
+        u64(v, start, end)
+    }
+
+    pub const fn degrees(v: i32, start: i32, end: i32) -> bool {
+        i32(v, start, end)
+    }
+
+    pub const fn basis_points(v: i64, start: i64, end: i64) -> bool {
+        i64(v, start, end)
+    }
+
+    macro_rules! def_in_range_nonzero {
+        ($($nz:ident($t:ident)),* $(,)?) => {$(
+            #[allow(non_snake_case)]
+            pub const fn $nz(v: types::$nz, start: $t, end: $t) -> bool {
+                $t(v.get(), start, end)
+            }
+        )*};
+    }
+
+    def_in_range_nonzero! {
+        NonZeroUsize(usize), NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128),
+        NonZeroIsize(isize), NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128),
+    }
+}
+
+/// Looked up by type the same way as `parsers`, these check the (inclusive)
+/// bounds from a `RangeWrap` before anything gets parsed.
+pub mod range_problem {
+    use super::RangeProblem;
+
+    macro_rules! def_range_problem {
+        ($($t:ident),* $(,)?) => {$(
+            pub const fn $t(start: $t, end: $t) -> Option<RangeProblem> {
+                if start > end {
+                    Some(RangeProblem::Empty)
+                } else {
+                    None
+                }
+            }
+        )*};
+    }
+
+    def_range_problem! {
+        usize, u8, u16, u32, u64, u128,
+        isize, i8, i16, i32, i64, i128,
+        char,
+    }
+
+    pub const fn int(start: i128, end: i128) -> Option<RangeProblem> {
+        i128(start, end)
+    }
+
+    pub const fn cased_size(start: usize, end: usize) -> Option<RangeProblem> {
+        usize(start, end)
+    }
+
+    pub const fn rate(start: u64, end: u64) -> Option<RangeProblem> {
+        u64(start, end)
+    }
+
+    pub const fn degrees(start: i32, end: i32) -> Option<RangeProblem> {
+        i32(start, end)
+    }
+
+    pub const fn basis_points(start: i64, end: i64) -> Option<RangeProblem> {
+        i64(start, end)
+    }
+
+    macro_rules! def_range_problem_nonzero {
+        ($($nz:ident($t:ident)),* $(,)?) => {$(
+            // Half of these comparisons are pointless for the unsigned types.
+            #[allow(non_snake_case, unused_comparisons, clippy::absurd_extreme_comparisons)]
+            pub const fn $nz(start: $t, end: $t) -> Option<RangeProblem> {
+                if start > end {
+                    Some(RangeProblem::Empty)
+                } else if start <= 0 && end >= 0 {
+                    Some(RangeProblem::IncludesZero)
+                } else {
+                    None
+                }
+            }
+        )*};
+    }
+
+    def_range_problem_nonzero! {
+        NonZeroUsize(usize), NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128),
+        NonZeroIsize(isize), NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128),
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct RangeWrap<R, T>(pub R, pub core::marker::PhantomData<T>);
+
+macro_rules! def_to_inclusive {
+    ($t:ident, $min:expr) => {
+        def_to_inclusive!($t => $t, $min);
+    };
+    // The range is of `$t`, but the `PhantomData` (which is what the macros
+    // pick using the type name) is of `$p`.
+    ($t:ident => $p:ty, $min:expr) => {
+        // An exclusive end at the minimum can't be made inclusive without
+        // underflowing, and means the range is empty anyway, so these give
+        // `MAX..=MIN` for it, which `range_problem` reports as empty.
+        impl RangeWrap<core::ops::Range<$t>, $p> {
+            pub const fn start(&self) -> $t {
+                if self.0.end == $min {
+                    $t::MAX
+                } else {
+                    self.0.start
+                }
+            }
+            pub const fn end_incl(&self) -> $t {
+                if self.0.end == $min {
+                    $min
+                } else {
+                    self.0.end - 1
+                }
+            }
+        }
+        impl RangeWrap<core::ops::RangeFrom<$t>, $p> {
+            pub const fn start(&self) -> $t {
+                self.0.start
+            }
+            pub const fn end_incl(&self) -> $t {
+                $t::MAX
+            }
+        }
+        impl RangeWrap<core::ops::RangeTo<$t>, $p> {
+            pub const fn start(&self) -> $t {
+                if self.0.end == $min {
+                    $t::MAX
+                } else {
+                    $min
+                }
+            }
+            pub const fn end_incl(&self) -> $t {
+                if self.0.end == $min {
+                    $min
+                } else {
+                    self.0.end - 1
+                }
+            }
+        }
+        impl RangeWrap<core::ops::RangeInclusive<$t>, $p> {
+            pub const fn start(&self) -> $t {
+                *self.0.start()
+            }
+            pub const fn end_incl(&self) -> $t {
+                *self.0.end()
+            }
+        }
+        impl RangeWrap<core::ops::RangeToInclusive<$t>, $p> {
+            pub const fn start(&self) -> $t {
+                $min
+            }
+            pub const fn end_incl(&self) -> $t {
+                self.0.end
+            }
+        }
+        impl RangeWrap<core::ops::RangeFull, $p> {
+            pub const fn start(&self) -> $t {
+                $min
+            }
+            pub const fn end_incl(&self) -> $t {
+                $t::MAX
+            }
+        }
+    };
+}
+
+def_to_inclusive!(u8, 0);
+def_to_inclusive!(u16, 0);
 def_to_inclusive!(u32, 0);
 def_to_inclusive!(u64, 0);
 def_to_inclusive!(u128, 0);
@@ -259,3 +2467,309 @@ def_to_inclusive!(i32, i32::MIN);
 def_to_inclusive!(i64, i64::MIN);
 def_to_inclusive!(i128, i128::MIN);
 def_to_inclusive!(isize, isize::MIN);
+def_to_inclusive!(u8 => types::NonZeroU8, 0);
+def_to_inclusive!(u16 => types::NonZeroU16, 0);
+def_to_inclusive!(u32 => types::NonZeroU32, 0);
+def_to_inclusive!(u64 => types::NonZeroU64, 0);
+def_to_inclusive!(u128 => types::NonZeroU128, 0);
+def_to_inclusive!(usize => types::NonZeroUsize, 0);
+def_to_inclusive!(i8 => types::NonZeroI8, i8::MIN);
+def_to_inclusive!(i16 => types::NonZeroI16, i16::MIN);
+def_to_inclusive!(i32 => types::NonZeroI32, i32::MIN);
+def_to_inclusive!(i64 => types::NonZeroI64, i64::MIN);
+def_to_inclusive!(i128 => types::NonZeroI128, i128::MIN);
+def_to_inclusive!(isize => types::NonZeroIsize, isize::MIN);
+
+/// `char` needs its own impls, since it can't do arithmetic, and the range of
+/// valid `char`s has a hole in it for the surrogates. As with the integers, an
+/// exclusive end of `'\0'` gives `char::MAX..='\0'`.
+impl RangeWrap<core::ops::Range<char>, char> {
+    pub const fn start(&self) -> char {
+        if self.0.end == '\0' {
+            char::MAX
+        } else {
+            self.0.start
+        }
+    }
+    pub const fn end_incl(&self) -> char {
+        char_before(self.0.end)
+    }
+}
+impl RangeWrap<core::ops::RangeFrom<char>, char> {
+    pub const fn start(&self) -> char {
+        self.0.start
+    }
+    pub const fn end_incl(&self) -> char {
+        char::MAX
+    }
+}
+impl RangeWrap<core::ops::RangeTo<char>, char> {
+    pub const fn start(&self) -> char {
+        if self.0.end == '\0' {
+            char::MAX
+        } else {
+            '\0'
+        }
+    }
+    pub const fn end_incl(&self) -> char {
+        char_before(self.0.end)
+    }
+}
+impl RangeWrap<core::ops::RangeInclusive<char>, char> {
+    pub const fn start(&self) -> char {
+        *self.0.start()
+    }
+    pub const fn end_incl(&self) -> char {
+        *self.0.end()
+    }
+}
+impl RangeWrap<core::ops::RangeToInclusive<char>, char> {
+    pub const fn start(&self) -> char {
+        '\0'
+    }
+    pub const fn end_incl(&self) -> char {
+        self.0.end
+    }
+}
+impl RangeWrap<core::ops::RangeFull, char> {
+    pub const fn start(&self) -> char {
+        '\0'
+    }
+    pub const fn end_incl(&self) -> char {
+        char::MAX
+    }
+}
+
+const fn char_before(c: char) -> char {
+    match c {
+        '\u{e000}' => '\u{d7ff}',
+        '\0' => '\0',
+        c => match char::from_u32(c as u32 - 1) {
+            Some(c) => c,
+            None => unreachable!(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    // Each width goes through `parse_bounded` with bounds from a cast, so check
+    // the edges of every type, not just the 128-bit ones.
+    macro_rules! check_width {
+        ($($t:ident),* $(,)?) => {$({
+            #[track_caller]
+            fn ok(v: $t) {
+                let s = v.to_string();
+                assert_eq!(super::parsers::$t(s.as_bytes(), None), Some(v), "input: {:?}", s);
+                assert_eq!(super::diagnose::$t(s.as_bytes()), None, "input: {:?}", s);
+            }
+            #[track_caller]
+            fn err(s: &str) {
+                assert_eq!(super::parsers::$t(s.as_bytes(), None), None, "input: {:?}", s);
+                assert!(super::diagnose::$t(s.as_bytes()).is_some(), "input: {:?}", s);
+            }
+            for v in [$t::MIN, $t::MIN / 2, $t::MIN / 3, 0, 1, $t::MAX / 3, $t::MAX / 2, $t::MAX - 1, $t::MAX] {
+                ok(v);
+            }
+            // One past each end, computed in `u128`/`i128`, except where those
+            // are the type in question.
+            match ($t::MAX as u128).checked_add(1) {
+                Some(v) => err(&v.to_string()),
+                None => err("340282366920938463463374607431768211456"),
+            }
+            match ($t::MIN as i128).checked_sub(1) {
+                Some(v) => err(&v.to_string()),
+                None => err("-170141183460469231731687303715884105729"),
+            }
+        })*};
+    }
+
+    #[test]
+    fn test_widths() {
+        check_width!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_range_wrap_exclusive_min() {
+        use super::{range_problem, RangeProblem, RangeWrap};
+        use core::marker::PhantomData;
+
+        macro_rules! check_empty {
+            ($t:ident, $range:expr) => {{
+                let r = RangeWrap($range, PhantomData::<super::types::$t>);
+                assert!(matches!(range_problem::$t(r.start(), r.end_incl()), Some(RangeProblem::Empty)));
+            }};
+        }
+        check_empty!(usize, ..0);
+        check_empty!(u8, 0..0);
+        check_empty!(u128, 5..0);
+        check_empty!(i8, ..i8::MIN);
+        check_empty!(i64, i64::MIN..i64::MIN);
+        check_empty!(char, ..'\0');
+        check_empty!(char, 'a'..'\0');
+        check_empty!(NonZeroU32, ..0);
+
+        // One above the minimum is still fine.
+        let r = RangeWrap(..1u8, PhantomData::<u8>);
+        assert_eq!((r.start(), r.end_incl()), (0, 0));
+        let r = RangeWrap(i8::MIN..i8::MIN + 1, PhantomData::<i8>);
+        assert_eq!((r.start(), r.end_incl()), (i8::MIN, i8::MIN));
+    }
+
+    #[test]
+    fn test_range_wrap_widest() {
+        use super::{in_range, parse_bounded, range_problem, RangeWrap};
+        use core::marker::PhantomData;
+
+        let r = RangeWrap(.., PhantomData::<u128>);
+        assert_eq!((r.start(), r.end_incl()), (0, u128::MAX));
+        assert!(range_problem::u128(r.start(), r.end_incl()).is_none());
+        assert!(in_range::u128(u128::MAX, r.start(), r.end_incl()));
+        let max = b"340282366920938463463374607431768211455";
+        assert_eq!(parse_bounded::u128(max, None, Some(r.start()), Some(r.end_incl()), false), Some(u128::MAX));
+
+        let r = RangeWrap(.., PhantomData::<i128>);
+        assert_eq!((r.start(), r.end_incl()), (i128::MIN, i128::MAX));
+        assert!(range_problem::i128(r.start(), r.end_incl()).is_none());
+        let min = b"-170141183460469231731687303715884105728";
+        let max = b"170141183460469231731687303715884105727";
+        assert_eq!(parse_bounded::i128(min, None, Some(r.start()), Some(r.end_incl()), false), Some(i128::MIN));
+        assert_eq!(parse_bounded::i128(max, None, Some(r.start()), Some(r.end_incl()), false), Some(i128::MAX));
+
+        let r = RangeWrap(1.., PhantomData::<u128>);
+        assert_eq!(r.end_incl(), u128::MAX);
+        let r = RangeWrap(..=i128::MAX, PhantomData::<i128>);
+        assert_eq!((r.start(), r.end_incl()), (i128::MIN, i128::MAX));
+    }
+
+    // A default only replaces a blank value, never a zero.
+    macro_rules! check_zero_isnt_blank {
+        ($($t:ident),* $(,)?) => {$(
+            for s in ["0", " 0 ", "-0", "+0", "0x0", "0b0", "0_0"] {
+                let unbounded = super::parse_bounded::$t(s.as_bytes(), Some(5), None, None, false);
+                assert_eq!(unbounded, Some(0), "input: {:?}", s);
+                let clamped = super::parse_bounded::$t(s.as_bytes(), Some(5), Some(-1), Some(1), true);
+                assert_eq!(clamped, Some(0), "input: {:?}", s);
+                assert_eq!(super::parsers::$t(s.as_bytes(), Some(5)), Some(0), "input: {:?}", s);
+            }
+            for s in ["", " ", "\t\n"] {
+                let blank = super::parse_bounded::$t(s.as_bytes(), Some(5), None, None, false);
+                assert_eq!(blank, Some(5), "input: {:?}", s);
+                assert_eq!(super::parse_bounded::$t(s.as_bytes(), None, None, None, false), None, "input: {:?}", s);
+            }
+        )*};
+    }
+
+    #[test]
+    fn test_zero_isnt_blank() {
+        check_zero_isnt_blank!(isize, i8, i16, i32, i64, i128);
+    }
+
+    #[test]
+    fn test_array_whitespace_and_empty_items() {
+        use super::{array_parsers, ArrayError};
+        assert!(matches!(array_parsers::u8::<3>(b" 2 , 3 , 5 ", None, false), Ok([2, 3, 5])));
+        assert!(matches!(array_parsers::u8::<3>(b"\t2,\t3 ,5\n", None, false), Ok([2, 3, 5])));
+        assert!(matches!(array_parsers::u8::<2>(b"2,3,", None, false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(array_parsers::u8::<3>(b"2,3, ", None, false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(array_parsers::u8::<3>(b"2,,5", None, false), Err(ArrayError::EmptyItem(1))));
+        assert!(matches!(array_parsers::u8::<3>(b",3,5", None, false), Err(ArrayError::EmptyItem(0))));
+        // With a default, empty items keep the default's value, but a trailing
+        // comma is still an error.
+        assert!(matches!(array_parsers::u8::<3>(b"2, ,5", Some([7, 8, 9]), false), Ok([2, 8, 5])));
+        assert!(matches!(array_parsers::u8::<3>(b"2,3", Some([7, 8, 9]), false), Ok([2, 3, 9])));
+        assert!(matches!(array_parsers::u8::<3>(b"2,3,", Some([7, 8, 9]), false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(array_parsers::u8::<2>(b"2,3,", Some([7, 8]), false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(array_parsers::u8::<3>(b"2,3, ", Some([7, 8, 9]), false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(array_parsers::u8::<2>(b" ", Some([7, 8]), false), Ok([7, 8])));
+        // `loose` arrays have no empty items.
+        assert!(matches!(array_parsers::u8::<2>(b"2,3,", None, true), Ok([2, 3])));
+    }
+
+    #[test]
+    fn test_list_trailing_comma() {
+        use super::{list_parsers, option_array_parsers, unique_list_parsers, ArrayError};
+        assert!(matches!(list_parsers::u8::<4>(b"1,2", None, None, false), Ok(([1, 2, 0, 0], 2))));
+        assert!(matches!(list_parsers::u8::<4>(b"1,2,", None, None, false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(list_parsers::u8::<4>(b"1,2,", None, Some(&[3]), false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(list_parsers::u8::<4>(b"1,2,", None, None, true), Ok(([1, 2, 0, 0], 2))));
+        assert!(matches!(unique_list_parsers::u8::<4>(b"2,1,", None, false), Err(ArrayError::EmptyItem(2))));
+        assert!(matches!(option_array_parsers::bool::<3>(b"1,,0"), Ok([Some(true), None, Some(false)])));
+        assert!(matches!(option_array_parsers::bool::<3>(b",0"), Ok([None, Some(false), None])));
+        assert!(matches!(option_array_parsers::bool::<3>(b"1,,0,"), Err(ArrayError::EmptyItem(3))));
+        assert!(matches!(option_array_parsers::bool::<3>(b",0,"), Err(ArrayError::EmptyItem(2))));
+    }
+
+    #[test]
+    fn test_count_delimited_matches_arrays() {
+        use super::{array_parsers, ArrayError};
+        use crate::parse::count_delimited;
+        // Without empty items, an array parses exactly when the count matches.
+        for s in [&b""[..], b"1", b"1,2", b" 1 , 2 ", b"1,2,3"] {
+            let n = count_delimited(s, b',');
+            assert_eq!(array_parsers::u8::<0>(s, None, false).is_ok(), n == 0, "{:?}", s);
+            assert_eq!(array_parsers::u8::<2>(s, None, false).is_ok(), n == 2, "{:?}", s);
+            assert_eq!(array_parsers::u8::<3>(s, None, false).is_ok(), n == 3, "{:?}", s);
+        }
+        // With them, the empty item is one of the counted ones.
+        for s in [&b"1,2,"[..], b"1,,2", b",", b"1,2, "] {
+            let n = count_delimited(s, b',');
+            match array_parsers::u8::<4>(s, None, false) {
+                Err(ArrayError::EmptyItem(i)) => assert!(i < n, "{:?}", s),
+                _ => panic!("{:?}", s),
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_or_negative() {
+        use super::auto_or;
+        use crate::parse::ParseError;
+        assert_eq!(auto_or::i16(b"12", 1, 64), Ok(Some(12)));
+        assert_eq!(auto_or::i16(b" auto ", 1, 64), Ok(None));
+        assert_eq!(auto_or::i16(b"-5", i16::MIN, i16::MAX), Err(Some(ParseError::UnexpectedSign)));
+        assert_eq!(auto_or::i64(b" -0", i64::MIN, i64::MAX), Err(Some(ParseError::UnexpectedSign)));
+        assert_eq!(auto_or::u8(b"-5", 0, u8::MAX), Err(Some(ParseError::UnexpectedSign)));
+    }
+
+    #[test]
+    fn test_unique_list() {
+        use super::{unique_list_parsers, ArrayError};
+        assert!(matches!(unique_list_parsers::u8::<4>(b"5,1,3", None, false), Ok(([1, 3, 5, 0], 3))));
+        assert!(matches!(unique_list_parsers::u8::<4>(b"3, 3,1,3 ,1", None, false), Ok(([1, 3, 0, 0], 2))));
+        assert!(matches!(unique_list_parsers::i32::<3>(b"0,-7,100", None, false), Ok(([-7, 0, 100], 3))));
+        assert!(matches!(unique_list_parsers::u64::<3>(b"9,8,7", None, false), Ok(([7, 8, 9], 3))));
+        // Duplicates don't count against the capacity.
+        assert!(matches!(unique_list_parsers::u8::<2>(b"2,2,2,1,1,2", None, false), Ok(([1, 2], 2))));
+        assert!(matches!(unique_list_parsers::u8::<0>(b" ", None, false), Ok(([], 0))));
+        assert!(matches!(
+            unique_list_parsers::u8::<2>(b"1,2,1,3", None, false),
+            Err(ArrayError::TooManyDistinct { expected: 2 })
+        ));
+        assert!(matches!(unique_list_parsers::u8::<4>(b"1,x", None, false), Err(ArrayError::Item(1, _))));
+        assert!(matches!(unique_list_parsers::u8::<4>(b"1,,2", None, false), Err(ArrayError::Item(1, _))));
+        assert!(matches!(unique_list_parsers::u8::<4>(b"1 3,,1", None, true), Ok(([1, 3, 0, 0], 2))));
+        // Defaults are sorted and deduplicated too.
+        assert!(matches!(unique_list_parsers::u16::<3>(b"", Some(&[30, 10, 30]), false), Ok(([10, 30, 0], 2))));
+        assert!(matches!(unique_list_parsers::u16::<3>(b"", None, false), Ok(([0, 0, 0], 0))));
+        assert!(matches!(
+            unique_list_parsers::u16::<1>(b"", Some(&[1, 2]), false),
+            Err(ArrayError::TooManyDistinct { expected: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_mixed_radix_items() {
+        use super::{array_parsers, list_parsers, unique_list_parsers};
+        // Each item is parsed on its own, so each can have its own prefix.
+        assert!(matches!(array_parsers::u8::<3>(b"0xff,0b1010,16", None, false), Ok([255, 10, 16])));
+        assert!(matches!(array_parsers::u32::<3>(b"0o17 0XFF 1_000", None, true), Ok([15, 255, 1000])));
+        assert!(matches!(array_parsers::i16::<3>(b"-0x10, 0b1, -8", None, false), Ok([-16, 1, -8])));
+        assert!(matches!(list_parsers::u8::<4>(b"0xff,0b1010,16", None, None, false), Ok(([255, 10, 16, 0], 3))));
+        assert!(matches!(unique_list_parsers::u8::<3>(b"0x10,16,0b10000", None, false), Ok(([16, 0, 0], 1))));
+    }
+}
@@ -0,0 +1,6 @@
+// error-pattern: has too few items for a `[i32; 4]`: expected 4, found 3.
+// rustc-env:MATRIX=1,2,3
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const MATRIX: [i32; 4] = envparse::parse_env!("MATRIX" as [i32; 4]);
@@ -0,0 +1,6 @@
+// error-pattern: has too many items for a `[i32; 4]`: expected 4, found 6.
+// rustc-env:MATRIX=1,2,3,4,5,6,
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const MATRIX: [i32; 4] = envparse::parse_env!("MATRIX" as [i32; 4]);
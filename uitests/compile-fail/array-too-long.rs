@@ -0,0 +1,6 @@
+// error-pattern: has too many items for a `[bool; 4]`: expected at most 4, found 5.
+// rustc-env:STAGES=1,1,1,1,1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const STAGES: [bool; 4] = envparse::parse_env!("STAGES" as [bool; 4] else [false; 4]);
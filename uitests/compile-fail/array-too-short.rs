@@ -0,0 +1,6 @@
+// error-pattern: has too few items for a `[u16; 3]`: expected 3, found 2.
+// rustc-env:PORTS=80,443
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const PORTS: [u16; 3] = envparse::parse_env!("PORTS" as [u16; 3]);
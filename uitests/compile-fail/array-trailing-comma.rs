@@ -0,0 +1,6 @@
+// error-pattern: the value in `SEEDS` has an empty item (at index 3) in a `[u32; 3]`. Remove any extra or trailing commas.
+// rustc-env:SEEDS=2,3,5,
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SEEDS: [u32; 3] = envparse::parse_env!("SEEDS" as [u32; 3]);
@@ -0,0 +1,5 @@
+// error-pattern: error: `"300"` fails to parse as a `u8`, but not with `InvalidDigit`.
+#![crate_type = "lib"]
+extern crate envparse;
+
+envparse::assert_fails!("300" as u8 => InvalidDigit);
@@ -0,0 +1,5 @@
+// error-pattern: error: `"123"` parses as a `u32`, but not as `124`.
+#![crate_type = "lib"]
+extern crate envparse;
+
+envparse::assert_parses!("123" as u32 => 124);
@@ -0,0 +1,6 @@
+// error-pattern: isn't `auto`, and doesn't parse as a `i32`, or is outside of the range `..`.
+// rustc-env:JOBS=-4
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const JOBS: Option<i32> = envparse::parse_env!("JOBS" as auto_or(i32));
@@ -0,0 +1,6 @@
+// error-pattern: isn't `auto`, and doesn't parse as a `usize`, or is outside of the range `1..=64`.
+// rustc-env:THREADS=100
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const THREADS: Option<usize> = envparse::parse_env!("THREADS" as auto_or(usize) (in 1..=64) else None);
@@ -0,0 +1,5 @@
+// error-pattern: `MAX_LEN=32` is not a valid environment variable name.
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const MAX_LEN: usize = envparse::parse_env!("MAX_LEN=32" as usize else 64);
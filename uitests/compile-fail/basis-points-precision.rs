@@ -0,0 +1,6 @@
+// error-pattern: the value in `RATE` doesn't parse as a `basis_points`: more than 4 digits after the decimal point.
+// rustc-env:RATE=0.03255
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const RATE: i64 = envparse::parse_env!("RATE" as basis_points);
@@ -0,0 +1,6 @@
+// rustc-env:VERBOSE=3
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const VERBOSE: bool = envparse::parse_env!("VERBOSE" as bool_at_least (-1));
+//~^ ERROR cannot apply unary operator `-` to type `u128`
@@ -0,0 +1,9 @@
+// rustc-env:VERBOSE=yes
+// rustc-env:NEGATIVE=-1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const VERBOSE: bool = envparse::parse_env!("VERBOSE" as bool_at_least 2);
+//~^ ERROR the value in `VERBOSE` doesn't parse as a `bool_at_least 2`: invalid digit `y` for base 10.
+pub const NEGATIVE: bool = envparse::parse_env!("NEGATIVE" as bool_at_least 1 else false);
+//~^ ERROR the value in `NEGATIVE` doesn't parse as a `bool_at_least 1`: expected a non-negative integer.
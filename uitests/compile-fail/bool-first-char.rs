@@ -0,0 +1,6 @@
+// rustc-env:YES=yes
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const YES: bool = envparse::parse_env!("YES" as bool_first_char);
+//~^ ERROR the value in `YES` doesn't parse as a `bool_first_char`
@@ -0,0 +1,12 @@
+// rustc-env:WRONG_NAME=+E
+// rustc-env:NO_SIGN=e
+// rustc-env:WORD=yes
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const WRONG_NAME: bool = envparse::parse_env!("WRONG_NAME" as bool_sign "e");
+//~^ ERROR the value in `WRONG_NAME` doesn't parse as a `bool_sign "e"`: expected `+`, `-`, `+e` or `-e`.
+pub const NO_SIGN: bool = envparse::parse_env!("NO_SIGN" as bool_sign "e" else false);
+//~^ ERROR the value in `NO_SIGN` doesn't parse as a `bool_sign "e"`: expected `+`, `-`, `+e` or `-e`.
+pub const WORD: bool = envparse::parse_env!("WORD" as bool_sign);
+//~^ ERROR the value in `WORD` doesn't parse as a `bool_sign`
@@ -0,0 +1,6 @@
+// error-pattern: error: the value in `ACK` doesn't parse as a `bool_tf`
+// rustc-env:ACK=true
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ACK: bool = envparse::parse_env!("ACK" as bool_tf);
@@ -0,0 +1,18 @@
+// rustc-env:MISMATCHED=[1, 2)
+// rustc-env:UNOPENED=1, 2}
+// rustc-env:UNCLOSED=(1, 2
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const MISMATCHED: [u8; 2] = envparse::parse_env!("MISMATCHED" as [u8; 2] bracketed);
+//~^ ERROR the value in `MISMATCHED` has brackets that don't match (expected `[...]`, `(...)` or `{...}`).
+pub const UNOPENED: ([u8; 2], usize) = envparse::parse_env!("UNOPENED" as list(u8; max 2) bracketed);
+//~^ ERROR the value in `UNOPENED` has brackets that don't match
+pub const UNCLOSED: [u8; 2] = envparse::parse_env!("UNCLOSED" as [u8; 2] loose bracketed else [0, 0]);
+//~^ ERROR the value in `UNCLOSED` has brackets that don't match
+pub const UNIQUE: ([u8; 2], usize) = envparse::parse_env!("MISMATCHED" as list(u8; max 2) unique bracketed);
+//~^ ERROR the value in `MISMATCHED` has brackets that don't match
+pub const OPTIONS: [Option<u8>; 2] = envparse::parse_env!("UNOPENED" as [Option<u8>; 2] bracketed);
+//~^ ERROR the value in `UNOPENED` has brackets that don't match
+pub const NOT_STRIPPED: [u8; 2] = envparse::parse_env!("MISMATCHED" as [u8; 2]);
+//~^ ERROR the value in `MISMATCHED` has an item (at index 0) which doesn't parse as a `u8`
@@ -0,0 +1,11 @@
+// rustc-env:SPAN_NUMBER=0x1g
+// rustc-env:SPAN_LIST=1,2,x
+#![crate_type = "lib"]
+extern crate envparse;
+
+// Each error should point at the `parse_env!` call, rather than somewhere
+// inside of envparse.
+
+pub const NUMBER: u32 = envparse::parse_env!("SPAN_NUMBER" as u32); //~ ERROR invalid digit `g` for base 16
+
+pub const LIST: [u8; 3] = envparse::parse_env!("SPAN_LIST" as [u8; 3]); //~ ERROR has an item (at index 2)
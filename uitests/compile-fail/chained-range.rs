@@ -0,0 +1,11 @@
+// error-pattern: error: the value in `THREADS` doesn't parse as a `u32`, or is outside of the range `MIN_THREADS..=MAX_THREADS`.
+// rustc-env:MIN_THREADS=2
+// rustc-env:MAX_THREADS=8
+// rustc-env:THREADS=12
+#![crate_type = "lib"]
+extern crate envparse;
+use envparse::parse_env;
+
+pub const MIN_THREADS: u32 = parse_env!("MIN_THREADS" as u32);
+pub const MAX_THREADS: u32 = parse_env!("MAX_THREADS" as u32 in MIN_THREADS..);
+pub const THREADS: u32 = parse_env!("THREADS" as u32 in MIN_THREADS..=MAX_THREADS);
@@ -0,0 +1,12 @@
+// rustc-env:SURROGATE=U+0041 U+D800 U+0043
+// rustc-env:TOO_BIG=U+0041 U+110000 U+0043
+// rustc-env:SHORT=U+0041 U+0042
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SURROGATE: [char; 3] = envparse::parse_env!("SURROGATE" as [char_code; 3] loose);
+//~^ ERROR the value in `SURROGATE` has an item (at index 1) which doesn't parse as a `char_code`: it's a surrogate code point (`U+D800` to `U+DFFF`), which isn't a valid `char`.
+pub const TOO_BIG: [char; 3] = envparse::parse_env!("TOO_BIG" as [char_code; 3] loose);
+//~^ ERROR the value in `TOO_BIG` has an item (at index 1) which doesn't parse as a `char_code`
+pub const SHORT: [char; 3] = envparse::parse_env!("SHORT" as [char_code; 3] loose);
+//~^ ERROR the value in `SHORT` has too few items for a `[char_code; 3]`: expected 3, found 2.
@@ -0,0 +1,6 @@
+// error-pattern: the value in `DELIM` doesn't parse as a `char_code`: it's a surrogate code point (`U+D800` to `U+DFFF`), which isn't a valid `char`.
+// rustc-env:DELIM=U+D83D
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const DELIM: char = envparse::parse_env!("DELIM" as char_code);
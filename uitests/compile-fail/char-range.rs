@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `char`, or is outside of the range `'a'..='z'`.
+// rustc-env:DELIM=;
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const DELIM: char = envparse::parse_env!("DELIM" as char in 'a'..='z');
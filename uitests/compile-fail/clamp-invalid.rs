@@ -0,0 +1,6 @@
+// error-pattern: the value in `NEG` doesn't parse as a `usize`
+// rustc-env:NEG=-five
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const NEG: usize = envparse::parse_env!("NEG" as usize clamp);
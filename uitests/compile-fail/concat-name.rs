@@ -0,0 +1,8 @@
+// rustc-env:MYAPP_THREADS=lots
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const THREADS: u32 = envparse::parse_env!(concat!("MYAPP", "_THREADS") as u32);
+//~^ ERROR the value in `MYAPP_THREADS` doesn't parse as a `u32`: invalid digit `l` for base 10.
+pub const BAD_NAME: u32 = envparse::parse_env!(concat!("MYAPP_THREADS=", "4") as u32 else 1);
+//~^ ERROR `MYAPP_THREADS=4` is not a valid environment variable name.
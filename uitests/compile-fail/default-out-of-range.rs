@@ -0,0 +1,9 @@
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const X: u32 = envparse::parse_env!("MISSING" as u32 (in 1..=10) else 42);
+//~^ ERROR the default `42` is outside of the range `1..=10`.
+pub const Y: Option<usize> = envparse::parse_env!("MISSING" as auto_or(usize) (in 1..=64) else Some(100));
+//~^ ERROR the default `Some(100)` is outside of the range `1..=64`.
+pub const Z: usize = envparse::parse_env!("MISSING" as pages(4096) (in 4096..) else 0);
+//~^ ERROR the default `0` is outside of the range `4096..`.
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `degrees`, or is outside of the range `0..360`.
+// rustc-env:ANGLE=400deg
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ANGLE: i32 = envparse::parse_env!("ANGLE" as degrees (in 0..360) else 0);
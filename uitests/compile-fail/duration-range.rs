@@ -0,0 +1,12 @@
+// rustc-env:REVERSED=2s..=1s
+// rustc-env:BARE=100..500ms
+// rustc-env:SINGLE=1s
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const REVERSED: (u64, u64) = envparse::parse_env!("REVERSED" as duration_range);
+//~^ ERROR the value in `REVERSED` doesn't parse as a `duration_range`: expected a non-empty range like `10..100` or `10..=99`.
+pub const BARE: (u64, u64) = envparse::parse_env!("BARE" as duration_range else (0, 0));
+//~^ ERROR the value in `BARE` doesn't parse as a `duration_range`: missing a time unit.
+pub const SINGLE: (u64, u64) = envparse::parse_env!("SINGLE" as duration_range);
+//~^ ERROR the value in `SINGLE` doesn't parse as a `duration_range`
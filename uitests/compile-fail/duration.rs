@@ -0,0 +1,13 @@
+// rustc-env:DAYS=1d
+// rustc-env:BARE=30
+// rustc-env:NEGATIVE=-5s
+#![crate_type = "lib"]
+extern crate envparse;
+use core::time::Duration;
+
+pub const DAYS: Duration = envparse::parse_env!("DAYS" as duration);
+//~^ ERROR the value in `DAYS` doesn't parse as a `duration`: unrecognized time unit.
+pub const BARE: Duration = envparse::parse_env!("BARE" as duration else Duration::ZERO);
+//~^ ERROR the value in `BARE` doesn't parse as a `duration`: missing a time unit.
+pub const NEGATIVE: Duration = envparse::parse_env!("NEGATIVE" as duration);
+//~^ ERROR the value in `NEGATIVE` doesn't parse as a `duration`
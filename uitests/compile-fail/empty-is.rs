@@ -0,0 +1,6 @@
+// error-pattern: the value in `THREADS` doesn't parse as a `u32`
+// rustc-env:THREADS=lots
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const THREADS: u32 = envparse::parse_env!("THREADS" as u32 empty_is 0 else 8);
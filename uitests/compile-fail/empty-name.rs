@@ -0,0 +1,5 @@
+// error-pattern: `` is not a valid environment variable name.
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const FLAGS: u64 = envparse::parse_env_set!("" in ["a", "b"] else 0);
@@ -0,0 +1,15 @@
+// rustc-env:LEVEL=5
+// rustc-env:LETTER=a
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TO_ZERO: usize = envparse::parse_env!("LEVEL" as usize in ..0);
+//~^ ERROR the range `..0` is empty, so no `usize` could be inside of it.
+pub const ZERO_ZERO: u8 = envparse::parse_env!("LEVEL" as u8 (in 0..0) else 0);
+//~^ ERROR the range `0..0` is empty, so no `u8` could be inside of it.
+pub const TO_MIN: i8 = envparse::parse_env!("LEVEL" as i8 in ..i8::MIN);
+//~^ ERROR the range `..i8::MIN` is empty, so no `i8` could be inside of it.
+pub const REVERSED: u32 = envparse::parse_env!("LEVEL" as u32 in 5..=3);
+//~^ ERROR the range `5..=3` is empty, so no `u32` could be inside of it.
+pub const TO_NUL: char = envparse::parse_env!("LETTER" as char in ..'\0');
+//~^ ERROR the range `..'\0'` is empty, so no `char` could be inside of it.
@@ -0,0 +1,6 @@
+// error-pattern: error: the range `5..5` is empty, so no `u8` could be inside of it.
+// rustc-env:LEVEL=5
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LEVEL: u8 = envparse::parse_env!("LEVEL" as u8 in 5..5);
@@ -0,0 +1,7 @@
+// error-pattern: the value in `MAX` doesn't parse as a `u32`
+// rustc-env:MIN=10
+// rustc-env:MAX=lots
+#![crate_type = "lib"]
+extern crate envparse;
+
+envparse::assert_env_relation!("MIN" <= "MAX" as u32);
@@ -0,0 +1,7 @@
+// error-pattern: the values in `MIN` and `MAX` don't satisfy `MIN <= MAX`.
+// rustc-env:MIN=10
+// rustc-env:MAX=4
+#![crate_type = "lib"]
+extern crate envparse;
+
+envparse::assert_env_relation!("MIN" <= "MAX" as u32);
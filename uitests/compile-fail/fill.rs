@@ -0,0 +1,15 @@
+// rustc-env:SHORT=0xAA*8
+// rustc-env:BARE=0xAA
+// rustc-env:WIDE=0x100*4
+// rustc-env:BADCOUNT=1*four
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SHORT: [u8; 16] = envparse::parse_env!("SHORT" as [u8; 16] fill);
+//~^ ERROR the value in `SHORT` doesn't parse as a `[u8; 16] fill`: expected exactly 16, found 8.
+pub const BARE: [u8; 4] = envparse::parse_env!("BARE" as [u8; 4] fill else [0; 4]);
+//~^ ERROR the value in `BARE` doesn't parse as a `[u8; 4] fill`: expected exactly 4, found 1.
+pub const WIDE: [u8; 4] = envparse::parse_env!("WIDE" as [u8; 4] fill);
+//~^ ERROR the value in `WIDE` doesn't parse as a `[u8; 4] fill`, or is out of range.
+pub const BADCOUNT: [u8; 4] = envparse::parse_env!("BADCOUNT" as [u8; 4] fill);
+//~^ ERROR the value in `BADCOUNT` doesn't parse as a `[u8; 4] fill`: invalid digit `f` for base 10.
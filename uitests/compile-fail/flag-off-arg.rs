@@ -0,0 +1,6 @@
+// error-pattern: the value in `CACHE` doesn't parse as a `flag(u32; on 64)`: only an enabled flag can have an argument.
+// rustc-env:CACHE=off:256
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const CACHE: Option<u32> = envparse::parse_env!("CACHE" as flag(u32; on 64));
@@ -0,0 +1,6 @@
+// error-pattern: is outside of the range
+// rustc-env:SMALL=128
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SMALL: u8 = envparse::parse_env!("SMALL" as u8 in ..=envparse::frac_of_max::<u8>(50));
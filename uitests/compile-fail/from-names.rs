@@ -0,0 +1,9 @@
+// rustc-env:WORD=extreme
+// rustc-env:BIG=300
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const WORD: u8 = envparse::parse_env!("WORD" as u8 from { "low" => 1, "high" => 9 });
+//~^ ERROR the value in `WORD` isn't one of ["low", "high"], and doesn't parse as a `u8`: invalid digit `e` for base 10.
+pub const BIG: u8 = envparse::parse_env!("BIG" as u8 from { "low" => 1 } else 1);
+//~^ ERROR the value in `BIG` isn't one of ["low"], and doesn't parse as a `u8`, or is out of range.
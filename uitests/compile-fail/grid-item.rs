@@ -0,0 +1,6 @@
+// error-pattern: the value in `GRID` has an item (at row 1, column 0) which doesn't parse as a `bool`
+// rustc-env:GRID=1,0;2,1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const GRID: [[bool; 2]; 2] = envparse::parse_env!("GRID" as [[bool; 2]; 2]);
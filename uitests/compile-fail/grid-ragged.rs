@@ -0,0 +1,6 @@
+// error-pattern: the value in `GRID` has a row (at index 1) with too few items for a `[bool; 2]`: expected 2, found 1.
+// rustc-env:GRID=1,0;1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const GRID: [[bool; 2]; 2] = envparse::parse_env!("GRID" as [[bool; 2]; 2]);
@@ -0,0 +1,6 @@
+// error-pattern: the value in `GRID` has too many rows for a `[[bool; 2]; 2]`: expected 2, found 3.
+// rustc-env:GRID=1,0;0,1;1,1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const GRID: [[bool; 2]; 2] = envparse::parse_env!("GRID" as [[bool; 2]; 2]);
@@ -0,0 +1,10 @@
+// rustc-env:ADDR=0x1000
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ADDR: usize = envparse::parse_env!("ADDR" as usize hex_required aligned 24);
+//~^ ERROR the alignment for `ADDR` must be a power of two, but is `24`.
+pub const ZERO: usize = envparse::parse_env!("ADDR" as usize hex_required aligned 0 else 0);
+//~^ ERROR the alignment for `ADDR` must be a power of two, but is `0`.
+pub const DEFAULT: usize = envparse::parse_env!("UNSET" as usize hex_required aligned 256 else 0x1010);
+//~^ ERROR the default for `UNSET` isn't aligned to `256`.
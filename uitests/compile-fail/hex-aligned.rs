@@ -0,0 +1,9 @@
+// rustc-env:MMIO_BASE=0x4000_1234
+// rustc-env:DECIMAL=4096
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const MMIO_BASE: usize = envparse::parse_env!("MMIO_BASE" as usize hex_required aligned 4096);
+//~^ ERROR the value in `MMIO_BASE` (`0x4000_1234`) isn't aligned to `4096`.
+pub const DECIMAL: u32 = envparse::parse_env!("DECIMAL" as u32 hex_required aligned 16 else 0);
+//~^ ERROR the value in `DECIMAL` doesn't parse as a `u32`: expected a hex number starting with `0x`.
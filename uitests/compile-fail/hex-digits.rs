@@ -0,0 +1,12 @@
+// rustc-env:SHORT=abc12
+// rustc-env:LONG=0xabc1234
+// rustc-env:WIDE=fff
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SHORT: u32 = envparse::parse_env!("SHORT" as u32 hex digits 6);
+//~^ ERROR the value in `SHORT` doesn't parse as a `u32 hex digits 6`: expected exactly 6, found 5.
+pub const LONG: u32 = envparse::parse_env!("LONG" as u32 hex digits 6 else 0);
+//~^ ERROR the value in `LONG` doesn't parse as a `u32 hex digits 6`: expected exactly 6, found 7.
+pub const WIDE: u8 = envparse::parse_env!("WIDE" as u8 hex digits 3);
+//~^ ERROR the value in `WIDE` doesn't parse as a `u8 hex digits 3`, or is out of range.
@@ -0,0 +1,9 @@
+// rustc-env:ADDR=deadbeef
+// rustc-env:COUNT=123
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ADDR: u32 = envparse::parse_env!("ADDR" as u32 hex_required);
+//~^ ERROR the value in `ADDR` doesn't parse as a `u32`: expected a hex number starting with `0x`.
+pub const COUNT: u32 = envparse::parse_env!("COUNT" as u32 hex_required else 0);
+//~^ ERROR the value in `COUNT` doesn't parse as a `u32`: expected a hex number starting with `0x`.
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a
+// rustc-env:I128MIN_MINUS_ONE=-170141183460469231731687303715884105729
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TOO_SMALL: i128 = envparse::parse_env!("I128MIN_MINUS_ONE" as i128);
@@ -0,0 +1,15 @@
+// rustc-env:RANGE=10..100
+// rustc-env:EMPTY_RANGE=10..10
+// rustc-env:BAD_RANGE=10-100
+// rustc-env:VALUE=100
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const VALUE: u32 = envparse::parse_env!("VALUE" in_env_range "RANGE" as u32);
+//~^ ERROR the value in `VALUE` doesn't parse as a `u32`, or is outside of the range in `RANGE`.
+pub const DEFAULT: u32 = envparse::parse_env!("MISSING" in_env_range "RANGE" as u32 else 5);
+//~^ ERROR the default `5` is outside of the range in `RANGE`.
+pub const EMPTY: u32 = envparse::parse_env!("VALUE" in_env_range "EMPTY_RANGE" as u32);
+//~^ ERROR the value in `EMPTY_RANGE` doesn't parse as a range of `u32`: expected a non-empty range like `10..100` or `10..=99`.
+pub const BAD: u32 = envparse::parse_env!("VALUE" in_env_range "BAD_RANGE" as u32);
+//~^ ERROR the value in `BAD_RANGE` doesn't parse as a range of `u32`: expected a non-empty range like `10..100` or `10..=99`.
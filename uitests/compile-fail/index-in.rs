@@ -0,0 +1,11 @@
+// rustc-env:REGION=sa
+// rustc-env:PARTIAL=e
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const REGION: usize = envparse::parse_env!("REGION" index_in ["us", "eu", "ap"]);
+//~^ ERROR the value in `REGION` isn't one of ["us", "eu", "ap"].
+pub const PARTIAL: usize = envparse::parse_env!("PARTIAL" index_in ["us", "eu", "ap"] else 0);
+//~^ ERROR the value in `PARTIAL` isn't one of ["us", "eu", "ap"].
+pub const DEFAULT: usize = envparse::parse_env!("UNSET" index_in ["us", "eu", "ap"] else 3);
+//~^ ERROR the default index `3` is out of bounds for ["us", "eu", "ap"].
@@ -0,0 +1,6 @@
+// error-pattern: the value in `NEGATIVE` doesn't parse as a `int`, or is outside of the range `0..`.
+// rustc-env:NEGATIVE=-1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const NEGATIVE: i128 = envparse::parse_env!("NEGATIVE" as int in 0..);
@@ -0,0 +1,6 @@
+// error-pattern: error: the value in `PIN_MODE` doesn't parse as a `u8`, or is outside of the range `0..=2`.
+// rustc-env:PIN_MODE=3
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const PIN_MODE: u8 = envparse::parse_env!("PIN_MODE" as u8 (in 0..=2) else 0);
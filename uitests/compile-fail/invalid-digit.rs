@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `u8`: invalid digit `2` for base 2.
+// rustc-env:BAD_BINARY=0b0120
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const BAD_BINARY: u8 = envparse::parse_env!("BAD_BINARY" as u8 in 0..10);
@@ -0,0 +1,7 @@
+// error-pattern: has an item (at index 1) which doesn't parse as a `Ipv4Addr`: expected four numbers from 0 to 255
+// rustc-env:DNS=8.8.8.8,1.1.1.256
+#![crate_type = "lib"]
+extern crate envparse;
+use core::net::Ipv4Addr;
+
+pub const DNS: [Ipv4Addr; 2] = envparse::parse_env!("DNS" as [Ipv4Addr; 2]);
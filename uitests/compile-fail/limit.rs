@@ -0,0 +1,9 @@
+// rustc-env:WORD=unlimited
+// rustc-env:BIG=300
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const WORD: envparse::Limit<u32> = envparse::parse_env!("WORD" as limit(u32));
+//~^ ERROR the value in `WORD` isn't `auto` or `max`, and doesn't parse as a `u32`: invalid digit `u` for base 10.
+pub const BIG: envparse::Limit<u8> = envparse::parse_env!("BIG" as limit(u8) else envparse::Limit::Auto);
+//~^ ERROR the value in `BIG` isn't `auto` or `max`, and doesn't parse as a `u8`, or is out of range.
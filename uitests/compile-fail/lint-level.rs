@@ -0,0 +1,6 @@
+// error-pattern: the value in `STRICTNESS` doesn't parse as a `lint_level`: unrecognized word.
+// rustc-env:STRICTNESS=error
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const STRICTNESS: envparse::LintLevel = envparse::parse_env!("STRICTNESS" as lint_level);
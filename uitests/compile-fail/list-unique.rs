@@ -0,0 +1,11 @@
+// rustc-env:PORTS=80,443,80,8080
+// rustc-env:BAD=1,2,x
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const PORTS: ([u16; 2], usize) = envparse::parse_env!("PORTS" as list(u16; max 2) unique);
+//~^ ERROR the value in `PORTS` has more than 2 distinct items for a `list(u16; max 2) unique`.
+pub const BAD: ([u8; 4], usize) = envparse::parse_env!("BAD" as list(u8; max 4) unique);
+//~^ ERROR the value in `BAD` has an item (at index 2) which doesn't parse as a `u8`
+pub const DEFAULT: ([u8; 2], usize) = envparse::parse_env!("UNSET" as list(u8; max 2) unique else &[1, 1, 2, 3]);
+//~^ ERROR the default `&[1, 1, 2, 3]` has more than 2 distinct items.
@@ -0,0 +1,14 @@
+// rustc-env:LONG=1,2,3
+// rustc-env:BAD=1,x,3
+// rustc-env:HOLE=1,,3
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LONG: ([u32; 2], usize) = envparse::parse_env!("LONG" as list(u32; max 2));
+//~^ ERROR the value in `LONG` has too many items for a `list(u32; max 2)`: expected at most 2, found 3.
+pub const BAD: ([u32; 4], usize) = envparse::parse_env!("BAD" as list(u32; max 4) else &[]);
+//~^ ERROR the value in `BAD` has an item (at index 1) which doesn't parse as a `u32`: invalid digit `x` for base 10.
+pub const HOLE: ([u32; 4], usize) = envparse::parse_env!("HOLE" as list(u32; max 4, fill 1));
+//~^ ERROR the value in `HOLE` has an item (at index 1) which doesn't parse as a `u32`, or is out of range.
+pub const BIG_DEFAULT: ([u32; 2], usize) = envparse::parse_env!("MISSING" as list(u32; max 2) else &[1, 2, 3]);
+//~^ ERROR the default `&[1, 2, 3]` has more than 2 items.
@@ -0,0 +1,5 @@
+// error-pattern: is outside of the range
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SHIFT: u8 = envparse::parse_env!(lit "8" as u8 in 0..8);
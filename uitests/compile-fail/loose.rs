@@ -0,0 +1,9 @@
+// rustc-env:LONG=1 2,3  4
+// rustc-env:BAD=1 x
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LONG: [u32; 3] = envparse::parse_env!("LONG" as [u32; 3] loose);
+//~^ ERROR the value in `LONG` has too many items for a `[u32; 3]`: expected 3, found 4.
+pub const BAD: ([u32; 3], usize) = envparse::parse_env!("BAD" as list(u32; max 3) loose);
+//~^ ERROR the value in `BAD` has an item (at index 1) which doesn't parse as a `u32`: invalid digit `x` for base 10.
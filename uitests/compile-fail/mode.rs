@@ -0,0 +1,9 @@
+// rustc-env:NOT_OCTAL=0o999
+// rustc-env:TOO_BIG=17777
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const NOT_OCTAL: u32 = envparse::parse_env!("NOT_OCTAL" as mode);
+//~^ ERROR the value in `NOT_OCTAL` doesn't parse as a `mode`: invalid digit `9` for base 8.
+pub const TOO_BIG: u32 = envparse::parse_env!("TOO_BIG" as mode);
+//~^ ERROR the value in `TOO_BIG` doesn't parse as a `mode`, or is out of range.
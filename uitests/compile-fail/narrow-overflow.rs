@@ -0,0 +1,15 @@
+// rustc-env:U8_PAST=256
+// rustc-env:I8_PAST=-129
+// rustc-env:U16_PAST=65536
+// rustc-env:I32_PAST=2147483648
+// rustc-env:U64_PAST=18446744073709551616
+#![crate_type = "lib"]
+extern crate envparse;
+
+// One past the end of each type shouldn't sneak through the narrowing casts.
+
+pub const U8_PAST: u8 = envparse::parse_env!("U8_PAST" as u8); //~ ERROR doesn't parse as a `u8`, or is out of range.
+pub const I8_PAST: i8 = envparse::parse_env!("I8_PAST" as i8); //~ ERROR doesn't parse as a `i8`, or is out of range.
+pub const U16_PAST: u16 = envparse::parse_env!("U16_PAST" as u16 else 0); //~ ERROR doesn't parse as a `u16`, or is out of range.
+pub const I32_PAST: i32 = envparse::parse_env!("I32_PAST" as i32 in ..); //~ ERROR doesn't parse as a `i32`, or is outside of the range `..`.
+pub const U64_PAST: Option<u64> = envparse::parse_env!(try "U64_PAST" as u64); //~ ERROR doesn't parse as a `u64`, or is out of range.
@@ -0,0 +1,7 @@
+// error-pattern: error: the range `0..=16` includes zero, which a `NonZeroU32` can't be.
+#![crate_type = "lib"]
+extern crate envparse;
+use core::num::NonZeroU32;
+
+// Checked even though `MISSING` isn't set.
+pub const WORKERS: NonZeroU32 = envparse::parse_env!("MISSING" as NonZeroU32 (in 0..=16) else NonZeroU32::MIN);
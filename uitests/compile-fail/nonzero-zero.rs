@@ -0,0 +1,7 @@
+// error-pattern: doesn't parse as a `NonZeroU32`, or is out of range.
+// rustc-env:WORKERS=0
+#![crate_type = "lib"]
+extern crate envparse;
+use core::num::NonZeroU32;
+
+pub const WORKERS: NonZeroU32 = envparse::parse_env!("WORKERS" as NonZeroU32);
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `on_off_auto`: unrecognized word.
+// rustc-env:COLOR=yes
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const COLOR: envparse::OnOffAuto = envparse::parse_env!("COLOR" as on_off_auto);
@@ -0,0 +1,6 @@
+// error-pattern: has too many items for a `[Option < bool > ; 3]`: expected at most 3, found 4.
+// rustc-env:OVERRIDES=1,,0,,
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const OVERRIDES: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES" as [Option<bool>; 3]);
@@ -0,0 +1,6 @@
+// error-pattern: error: the value in `ARENA` doesn't parse as a `pages(usize::MAX)`, or the size is outside of the range `..`.
+// rustc-env:ARENA=2
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ARENA: usize = envparse::parse_env!("ARENA" as pages(usize::MAX));
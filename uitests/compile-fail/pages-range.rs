@@ -0,0 +1,6 @@
+// error-pattern: error: the value in `ARENA` doesn't parse as a `pages(4096)`, or the size is outside of the range `..=1 << 16`.
+// rustc-env:ARENA=17
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ARENA: usize = envparse::parse_env!("ARENA" as pages(4096) in ..=1 << 16);
@@ -0,0 +1,6 @@
+// error-pattern: error: `ENTRY` has a key whose length is outside of the range `1..=4`.
+// rustc-env:ENTRY=timeout:500
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ENTRY: (&str, u32) = envparse::parse_env!("ENTRY" as (str (in 1..=4) ':' u32));
@@ -0,0 +1,6 @@
+// error-pattern: error: `ENTRY` is missing the ':' between the key and value.
+// rustc-env:ENTRY=timeout=500
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ENTRY: (&str, u32) = envparse::parse_env!("ENTRY" as (str ':' u32));
@@ -0,0 +1,6 @@
+// error-pattern: error: `ENTRY` has a value (after the ':') which doesn't parse as a `u32`: invalid digit `m` for base 10.
+// rustc-env:ENTRY=timeout:500ms
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ENTRY: (&str, u32) = envparse::parse_env!("ENTRY" as (str ':' u32));
@@ -0,0 +1,21 @@
+// rustc-env:SHORT=a:3,b:1
+// rustc-env:LONG=a:3,b:1,c:5,d:2
+// rustc-env:NO_SEP=a:3,b1,c:5
+// rustc-env:BAD_VALUE=a:3,b:x,c:5
+// rustc-env:NO_KEY=a:3, :1,c:5
+// rustc-env:TRAILING=a:3,b:1,c:5,
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SHORT: ([&str; 3], [u32; 3]) = envparse::parse_env!("SHORT" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `SHORT` has too few pairs for a `pairs(str ':' u32; 3)`: expected 3, found 2.
+pub const LONG: ([&str; 3], [u32; 3]) = envparse::parse_env!("LONG" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `LONG` has too many pairs for a `pairs(str ':' u32; 3)`: expected 3, found 4.
+pub const NO_SEP: ([&str; 3], [u32; 3]) = envparse::parse_env!("NO_SEP" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `NO_SEP` has a pair (at index 1) which is missing the ':' between the key and value.
+pub const BAD_VALUE: ([&str; 3], [u32; 3]) = envparse::parse_env!("BAD_VALUE" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `BAD_VALUE` has a pair (at index 1) with a value which doesn't parse as a `u32`: invalid digit `x` for base 10.
+pub const NO_KEY: ([&str; 3], [u32; 3]) = envparse::parse_env!("NO_KEY" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `NO_KEY` has a pair (at index 1) with an empty key.
+pub const TRAILING: ([&str; 3], [u32; 3]) = envparse::parse_env!("TRAILING" as pairs(str ':' u32; 3));
+//~^ ERROR the value in `TRAILING` has an empty item (at index 3) in a `pairs(str ':' u32; 3)`. Remove any extra or trailing commas.
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `prompt_policy`: unrecognized word.
+// rustc-env:CONFIRM=true
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const CONFIRM: envparse::PromptPolicy = envparse::parse_env!("CONFIRM" as prompt_policy);
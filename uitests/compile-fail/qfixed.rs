@@ -0,0 +1,13 @@
+// rustc-env:LOUD=1
+// rustc-env:BAD=1,5
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LOUD: i128 = envparse::parse_env!("LOUD" as q(1, 15));
+//~^ ERROR the value in `LOUD` doesn't parse as a `q(1, 15)`, or is out of range.
+pub const BAD: i128 = envparse::parse_env!("BAD" as q(16, 16) else 0);
+//~^ ERROR the value in `BAD` doesn't parse as a `q(16, 16)`: invalid digit `,` for base 10.
+pub const WIDE: i128 = envparse::parse_env!("LOUD" as q(64, 65));
+//~^ ERROR `q(64, 65)` must have from 1 to 128 bits in total.
+pub const DEFAULT: i128 = envparse::parse_env!("UNSET" as q(1, 15) else 0x8000);
+//~^ ERROR the default `0x8000` doesn't fit in a `q(1, 15)`.
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `rate`: expected a `/` and a time unit after the size, like `10MB/s`.
+// rustc-env:UPLOAD=10MB
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const UPLOAD: u64 = envparse::parse_env!("UPLOAD" as rate else 0);
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `rate`: unrecognized time unit (expected `ms`, `s`, `min` or `h`).
+// rustc-env:UPLOAD=10MB/day
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const UPLOAD: u64 = envparse::parse_env!("UPLOAD" as rate);
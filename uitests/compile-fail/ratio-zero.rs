@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `ratio`: the denominator is zero.
+// rustc-env:GAIN=3/0
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const GAIN: (i128, i128) = envparse::parse_env!("GAIN" as ratio);
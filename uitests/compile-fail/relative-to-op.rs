@@ -0,0 +1,7 @@
+// error-pattern: unknown operator `%` (expected `+`, `-`, `*` or `/`).
+// rustc-env:THREADS=N%2
+#![crate_type = "lib"]
+extern crate envparse;
+
+const CORES: usize = 8;
+pub const THREADS: usize = envparse::parse_env!("THREADS" as usize relative_to CORES);
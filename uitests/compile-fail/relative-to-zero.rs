@@ -0,0 +1,7 @@
+// error-pattern: the value in `THREADS` doesn't parse as a `usize relative_to CORES`: the denominator is zero.
+// rustc-env:THREADS=N/0
+#![crate_type = "lib"]
+extern crate envparse;
+
+const CORES: usize = 8;
+pub const THREADS: usize = envparse::parse_env!("THREADS" as usize relative_to CORES);
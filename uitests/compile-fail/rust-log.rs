@@ -0,0 +1,8 @@
+// rustc-env:LOG=info,my_crate=loud
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const GLOBAL: u8 = envparse::parse_env!("LOG" as rust_log);
+//~^ ERROR the value in `LOG` doesn't parse as a `rust_log`: unrecognized word.
+pub const NET: u8 = envparse::parse_env!("LOG" as rust_log "my_crate::net" else 2);
+//~^ ERROR the value in `LOG` doesn't parse as a `rust_log "my_crate::net"`: unrecognized word.
@@ -0,0 +1,18 @@
+// rustc-env:OUTSIDE=100:1:64
+// rustc-env:SHORT=8:1
+// rustc-env:LONG=8:1:64:128
+// rustc-env:BAD_MIN=8:x:64
+// rustc-env:INVERTED=8:64:1
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const OUTSIDE: usize = envparse::parse_env!("OUTSIDE" as self_bounded(usize));
+//~^ ERROR the value in `OUTSIDE` has a value outside of its own `min:max` bounds.
+pub const SHORT: usize = envparse::parse_env!("SHORT" as self_bounded(usize));
+//~^ ERROR the value in `SHORT` isn't of the form `value:min:max`.
+pub const LONG: usize = envparse::parse_env!("LONG" as self_bounded(usize) else 0);
+//~^ ERROR the value in `LONG` isn't of the form `value:min:max`.
+pub const BAD_MIN: u8 = envparse::parse_env!("BAD_MIN" as self_bounded(u8));
+//~^ ERROR the value in `BAD_MIN` has a min which doesn't parse as a `u8`: invalid digit `x` for base 10.
+pub const INVERTED: u8 = envparse::parse_env!("INVERTED" as self_bounded(u8));
+//~^ ERROR the value in `INVERTED` has a min which is greater than its max.
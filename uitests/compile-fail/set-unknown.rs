@@ -0,0 +1,6 @@
+// error-pattern: contains an item which is not one of
+// rustc-env:ENABLED=alpha,delta
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ENABLED: u64 = envparse::parse_env_set!("ENABLED" in ["alpha", "beta", "gamma"]);
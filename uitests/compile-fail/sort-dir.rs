@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `sort_dir`: unrecognized word.
+// rustc-env:ORDER=up
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ORDER: envparse::SortDir = envparse::parse_env!("ORDER" as sort_dir);
@@ -0,0 +1,11 @@
+// rustc-env:URL=http://example.com
+// rustc-env:CONFIG=settings.json
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const URL: &str = envparse::parse_env!("URL" as str starts_with "https://");
+//~^ ERROR the value in `URL` doesn't satisfy `starts_with "https://"`.
+pub const CONFIG: &str = envparse::parse_env!("CONFIG" as str ends_with ".toml" else "x.toml");
+//~^ ERROR the value in `CONFIG` doesn't satisfy `ends_with ".toml"`.
+pub const DEFAULT: &str = envparse::parse_env!("MISSING" as str starts_with_ignore_case "https://" else "ftp://x");
+//~^ ERROR the default `"ftp://x"` doesn't satisfy `starts_with_ignore_case "https://"`.
@@ -0,0 +1,16 @@
+// rustc-env:NAME=my-crate
+// rustc-env:SPACED=a b
+// rustc-env:ACCENT=café
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const NAME: &str = envparse::parse_env!("NAME" as str chars "a-zA-Z0-9_");
+//~^ ERROR the value in `NAME` has `-` at byte 2, which isn't allowed by `chars "a-zA-Z0-9_"`.
+pub const SPACED: &str = envparse::parse_env!("SPACED" as str chars "a-z" else "x");
+//~^ ERROR the value in `SPACED` has `\x20` at byte 1, which isn't allowed by `chars "a-z"`.
+pub const ACCENT: &str = envparse::parse_env!("ACCENT" as str chars "a-z");
+//~^ ERROR the value in `ACCENT` has `\xc3` at byte 3, which isn't allowed by `chars "a-z"`.
+pub const DEFAULT: &str = envparse::parse_env!("MISSING" as str chars "a-z" else "Bad");
+//~^ ERROR the default `"Bad"` has `B` at byte 0, which isn't allowed by `chars "a-z"`.
+pub const REVERSED: &str = envparse::parse_env!("NAME" as str chars "z-a");
+//~^ ERROR the character class `z-a` is empty or has a reversed range.
@@ -0,0 +1,11 @@
+// rustc-env:TABBED=a	b
+// rustc-env:ACCENT=café
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TABBED: &str = envparse::parse_env!("TABBED" as str printable);
+//~^ ERROR the value in `TABBED` has `\x09` at byte 1, which isn't allowed by `printable`.
+pub const ACCENT: &str = envparse::parse_env!("ACCENT" as trimmed_str printable else "cafe");
+//~^ ERROR the value in `ACCENT` has `\xc3` at byte 3, which isn't allowed by `printable`.
+pub const DEFAULT: &str = envparse::parse_env!("MISSING" as str printable else "bell\x07");
+//~^ ERROR the default `"bell\x07"` has `\x07` at byte 4, which isn't allowed by `printable`.
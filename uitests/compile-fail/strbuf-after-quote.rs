@@ -0,0 +1,6 @@
+// error-pattern: the value in `BANNER` doesn't parse as a `strbuf[8]`: unexpected text after the closing `"` of a quoted string.
+// rustc-env:BANNER="ab"c
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const BANNER: ([u8; 8], usize) = envparse::parse_env!("BANNER" as strbuf[8]);
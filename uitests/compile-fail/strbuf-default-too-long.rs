@@ -0,0 +1,5 @@
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const BANNER: ([u8; 4], usize) = envparse::parse_env!("BANNER" as strbuf[4] else "hello");
+//~^ ERROR is longer than 4 bytes
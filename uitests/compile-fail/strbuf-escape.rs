@@ -0,0 +1,6 @@
+// error-pattern: the value in `BANNER` doesn't parse as a `strbuf[8]`: invalid escape `\q` in a quoted string.
+// rustc-env:BANNER="a\qb"
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const BANNER: ([u8; 8], usize) = envparse::parse_env!("BANNER" as strbuf[8]);
@@ -0,0 +1,6 @@
+// error-pattern: the value in `BANNER` doesn't parse as a `strbuf[4]`: it's longer than 4 bytes.
+// rustc-env:BANNER=hello
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const BANNER: ([u8; 4], usize) = envparse::parse_env!("BANNER" as strbuf[4]);
@@ -0,0 +1,6 @@
+// error-pattern: the value in `LEVEL` doesn't parse as a `u8`
+// rustc-env:LEVEL=high # not a number
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LEVEL: u8 = envparse::parse_env!("LEVEL" as u8 strip_comments);
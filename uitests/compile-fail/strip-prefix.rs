@@ -0,0 +1,6 @@
+// error-pattern: the value in `TIMEOUT` doesn't parse as a `u32`
+// rustc-env:TIMEOUT=fallback:30
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TIMEOUT: u32 = envparse::parse_env!("TIMEOUT" as u32 strip_prefix "default:");
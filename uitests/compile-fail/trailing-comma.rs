@@ -0,0 +1,13 @@
+// rustc-env:SEEDS=2,3,
+// rustc-env:OVERRIDES=1,,0,
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const SHORT: [u32; 3] = envparse::parse_env!("SEEDS" as [u32; 3] else [0, 0, 7]);
+//~^ ERROR the value in `SEEDS` has an empty item (at index 2) in a `[u32; 3]`. Remove any extra or trailing commas.
+pub const EXACT: [u32; 2] = envparse::parse_env!("SEEDS" as [u32; 2] else [0, 0]);
+//~^ ERROR the value in `SEEDS` has an empty item (at index 2) in a `[u32; 2]`.
+pub const LIST: ([u32; 4], usize) = envparse::parse_env!("SEEDS" as list(u32; max 4));
+//~^ ERROR the value in `SEEDS` has an empty item (at index 2) in a `list(u32; max 4)`.
+pub const OPTIONS: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES" as [Option<bool>; 3]);
+//~^ ERROR the value in `OVERRIDES` has an empty item (at index 3) in a `
@@ -0,0 +1,10 @@
+// rustc-env:BAD=12x
+// rustc-env:BIG=300
+#![crate_type = "lib"]
+extern crate envparse;
+
+// Set but invalid: an error, even with a default.
+pub const BAD: Option<u32> = envparse::parse_env!(try "BAD" as u32 else Some(4));
+//~^ ERROR the value in `BAD` doesn't parse as a `u32`: invalid digit `x` for base 10.
+pub const BIG: Option<u8> = envparse::parse_env!(try "BIG" as u8 else None);
+//~^ ERROR the value in `BIG` doesn't parse as a `u8`, or is out of range.
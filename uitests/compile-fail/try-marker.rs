@@ -0,0 +1,6 @@
+// error-pattern: the value in `LEVEL` doesn't parse as a `u8`, or is out of range.
+// rustc-env:LEVEL=9000
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LEVEL: u8 = envparse::parse_env!("LEVEL" as u8 try_marker else 3);
@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a
+// rustc-env:U128MAX_PLUS_ONE=340282366920938463463374607431768211456
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TOO_BIG: u128 = envparse::parse_env!("U128MAX_PLUS_ONE" as u128);
@@ -0,0 +1,11 @@
+// rustc-env:TOOL_VERSION=1.75
+// rustc-env:GOOD=1.75.0
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const NEW: bool = envparse::parse_env!("TOOL_VERSION" satisfies >=1.70);
+//~^ ERROR the value in `TOOL_VERSION` doesn't parse as a `version`: expected a version like `1.2.3`.
+pub const CARET: bool = envparse::parse_env!("GOOD" satisfies ^1.70);
+//~^ ERROR `^1.70` isn't a valid version requirement (like `>=1.70`).
+pub const BARE: bool = envparse::parse_env!("GOOD" satisfies (1.70) else false);
+//~^ ERROR `1.70` isn't a valid version requirement (like `>=1.70`).
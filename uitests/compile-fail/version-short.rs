@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `version`: expected a version like `1.2.3`.
+// rustc-env:TAG=1.2
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TAG: (u64, u64, u64) = envparse::parse_env!("TAG" as version);
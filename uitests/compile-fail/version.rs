@@ -0,0 +1,6 @@
+// error-pattern: doesn't parse as a `version`: invalid digit `v` for base 10.
+// rustc-env:TAG=vv1.2.3
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const TAG: (u64, u64, u64) = envparse::parse_env!("TAG" as version);
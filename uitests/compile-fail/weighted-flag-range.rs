@@ -0,0 +1,6 @@
+// error-pattern: the value in `ROLLOUT` doesn't parse as a `weighted_flag`
+// rustc-env:ROLLOUT=true:150
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const ROLLOUT: (bool, u8) = envparse::parse_env!("ROLLOUT" as weighted_flag);
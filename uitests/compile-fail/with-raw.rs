@@ -0,0 +1,6 @@
+// error-pattern: the value in `LIMIT` doesn't parse as a `u32`
+// rustc-env:LIMIT=lots
+#![crate_type = "lib"]
+extern crate envparse;
+
+pub const LIMIT: (u32, &str) = envparse::parse_env!("LIMIT" as u32 with_raw);
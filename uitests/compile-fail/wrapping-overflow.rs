@@ -0,0 +1,7 @@
+// error-pattern: the value in `SEED` doesn't parse as a `u8`
+// rustc-env:SEED=256
+#![crate_type = "lib"]
+extern crate envparse;
+use core::num::Wrapping;
+
+pub const SEED: Wrapping<u8> = envparse::parse_env!("SEED" as Wrapping<u8>);
@@ -0,0 +1,19 @@
+// compile-flags: --error-format=human
+// rustc-env:NO_FOO=1
+// rustc-env:NO_BAR=0
+// rustc-env:NO_BAZ=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const FOO: bool = envparse::parse_env!(absent "NO_FOO");
+const BAR: bool = envparse::parse_env!(absent "NO_BAR");
+const BAZ: bool = envparse::parse_env!(absent "NO_BAZ");
+const QUX: bool = envparse::parse_env!(absent "NO_QUX");
+
+fn main() {
+    // Set to anything, even `0` or nothing, means disabled.
+    assert!(!FOO);
+    assert!(!BAR);
+    assert!(!BAZ);
+    assert!(QUX);
+}
@@ -0,0 +1,16 @@
+// compile-flags: --error-format=human
+// rustc-env:SEEDS= 2 , 3 , 5 
+// rustc-env:TABBED=	1,	2 ,3	
+// rustc-env:PARTIAL=2,3
+#![crate_type = "bin"]
+extern crate envparse;
+
+const SEEDS: [u32; 3] = envparse::parse_env!("SEEDS" as [u32; 3]);
+const TABBED: [u8; 3] = envparse::parse_env!("TABBED" as [u8; 3]);
+const PARTIAL: [u32; 3] = envparse::parse_env!("PARTIAL" as [u32; 3] else [0, 0, 7]);
+
+fn main() {
+    assert_eq!(SEEDS, [2, 3, 5]);
+    assert_eq!(TABBED, [1, 2, 3]);
+    assert_eq!(PARTIAL, [2, 3, 7]);
+}
@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:STAGES_SHORT=0,1
+// rustc-env:STAGES_FULL=1, 0, 1, 1
+// rustc-env:STAGES_HOLE=0,,1
+// rustc-env:SIZES=16, 0x20, 64
+#![crate_type = "bin"]
+extern crate envparse;
+
+const STAGES_SHORT: [bool; 4] = envparse::parse_env!("STAGES_SHORT" as [bool; 4] else [true, true, false, false]);
+const STAGES_FULL: [bool; 4] = envparse::parse_env!("STAGES_FULL" as [bool; 4] else [false; 4]);
+const STAGES_HOLE: [bool; 4] = envparse::parse_env!("STAGES_HOLE" as [bool; 4] else [true; 4]);
+const STAGES_MISSING: [bool; 4] = envparse::parse_env!("MISSING" as [bool; 4] else [true, false, true, false]);
+const STAGES_REQUIRED: [bool; 4] = envparse::parse_env!("STAGES_FULL" as [bool; 4]);
+
+const SIZES: [u16; 3] = envparse::parse_env!("SIZES" as [u16; 3]);
+const SIZES_DEF: [u32; 4] = envparse::parse_env!("SIZES" as [u32; 4] else [1, 2, 3, 4]);
+
+fn main() {
+    assert_eq!(STAGES_SHORT, [false, true, false, false]);
+    assert_eq!(STAGES_FULL, [true, false, true, true]);
+    assert_eq!(STAGES_HOLE, [false, true, true, true]);
+    assert_eq!(STAGES_MISSING, [true, false, true, false]);
+    assert_eq!(STAGES_REQUIRED, [true, false, true, true]);
+    assert_eq!(SIZES, [16, 32, 64]);
+    assert_eq!(SIZES_DEF, [16, 32, 64, 4]);
+}
@@ -0,0 +1,27 @@
+// compile-flags: --error-format=human
+#![crate_type = "bin"]
+extern crate envparse;
+use core::net::Ipv4Addr;
+use envparse::OnOffAuto;
+
+envparse::assert_parses!("0x7b" as u32 => 123);
+envparse::assert_parses!(" -5 " as i8 => -5);
+envparse::assert_parses!("enabled" as bool => true);
+envparse::assert_parses!("4K" as cased_size => 4096);
+envparse::assert_parses!("6/8" as ratio => (6, 8));
+envparse::assert_parses!("AUTO" as on_off_auto => OnOffAuto::Auto);
+envparse::assert_parses!("10.0.0.1" as Ipv4Addr => Ipv4Addr::new(10, 0, 0, 1));
+envparse::assert_parses!(" x\n" as trimmed_str => "x");
+envparse::assert_parses!("λ" as char);
+
+envparse::assert_fails!("abc" as u32 => InvalidDigit);
+envparse::assert_fails!("256" as u8 => OutOfRange);
+envparse::assert_fails!("-1" as u64 => UnexpectedSign);
+envparse::assert_fails!("" as i32 => Empty);
+envparse::assert_fails!("1/0" as ratio => ZeroDenominator);
+envparse::assert_fails!("maybe" as bool);
+
+fn main() {
+    envparse::assert_parses!("1" as bool => true);
+    envparse::assert_fails!("ab" as char => NotAChar);
+}
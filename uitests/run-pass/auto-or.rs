@@ -0,0 +1,29 @@
+// compile-flags: --error-format=human
+// rustc-env:THREADS=auto
+// rustc-env:SHOUTY=AUTO
+// rustc-env:ZERO=0
+// rustc-env:WORKERS=12
+// rustc-env:JOBS=8
+// rustc-env:EMPTY=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const THREADS: Option<usize> = envparse::parse_env!("THREADS" as auto_or(usize));
+const SHOUTY: Option<u8> = envparse::parse_env!("SHOUTY" as auto_or(u8));
+const ZERO: Option<u32> = envparse::parse_env!("ZERO" as auto_or(u32) in 1..=64);
+const WORKERS: Option<usize> = envparse::parse_env!("WORKERS" as auto_or(usize));
+const JOBS: Option<i16> = envparse::parse_env!("JOBS" as auto_or(i16) in 1..=64);
+const EMPTY: Option<u64> = envparse::parse_env!("EMPTY" as auto_or(u64));
+const MISSING: Option<usize> = envparse::parse_env!("MISSING" as auto_or(usize) else Some(4));
+const MISSING_RANGE: Option<usize> = envparse::parse_env!("MISSING" as auto_or(usize) (in 1..=64) else None);
+
+fn main() {
+    assert_eq!(THREADS, None);
+    assert_eq!(SHOUTY, None);
+    assert_eq!(ZERO, None);
+    assert_eq!(WORKERS, Some(12));
+    assert_eq!(JOBS, Some(8));
+    assert_eq!(EMPTY, None);
+    assert_eq!(MISSING, Some(4));
+    assert_eq!(MISSING_RANGE, None);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:RATE=0.0325
+// rustc-env:NEG=-1.5
+// rustc-env:TRAILING=0.01000
+// rustc-env:RATES=0.01, .5, 1
+#![crate_type = "bin"]
+extern crate envparse;
+
+const RATE: i64 = envparse::parse_env!("RATE" as basis_points);
+const NEG: i64 = envparse::parse_env!("NEG" as basis_points (in -20000..=20000) else 0);
+const TRAILING: i64 = envparse::parse_env!("TRAILING" as basis_points else 0);
+const MISSING: i64 = envparse::parse_env!("MISSING" as basis_points else 25);
+const RATES: [i64; 3] = envparse::parse_env!("RATES" as [basis_points; 3]);
+
+fn main() {
+    assert_eq!(RATE, 325);
+    assert_eq!(NEG, -15000);
+    assert_eq!(TRAILING, 100);
+    assert_eq!(MISSING, 25);
+    assert_eq!(RATES, [100, 5000, 10000]);
+}
@@ -0,0 +1,24 @@
+// compile-flags: --error-format=human
+// rustc-env:LOUD=3
+// rustc-env:QUIET=1
+// rustc-env:EXACT=2
+// rustc-env:ZERO=0
+#![crate_type = "bin"]
+extern crate envparse;
+
+const LOUD: bool = envparse::parse_env!("LOUD" as bool_at_least 2);
+const QUIET: bool = envparse::parse_env!("QUIET" as bool_at_least 2);
+const EXACT: bool = envparse::parse_env!("EXACT" as bool_at_least 2);
+const ZERO: bool = envparse::parse_env!("ZERO" as bool_at_least 0);
+const LEVEL: u128 = 3;
+const CONST_LEVEL: bool = envparse::parse_env!("LOUD" as bool_at_least LEVEL else false);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool_at_least 2 else true);
+
+fn main() {
+    assert!(LOUD);
+    assert!(!QUIET);
+    assert!(EXACT);
+    assert!(ZERO);
+    assert!(CONST_LEVEL);
+    assert!(MISSING);
+}
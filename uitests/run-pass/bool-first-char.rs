@@ -0,0 +1,23 @@
+// compile-flags: --error-format=human
+// rustc-env:TRUTHY=truthy
+// rustc-env:FALSEY= falsey
+// rustc-env:ONE=1000
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const TRUTHY: bool = envparse::parse_env!("TRUTHY" as bool_first_char);
+const FALSEY: bool = envparse::parse_env!("FALSEY" as bool_first_char else true);
+const ONE: bool = envparse::parse_env!("ONE" as bool_first_char);
+const BLANK: bool = envparse::parse_env!("BLANK" as bool_first_char else true);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool_first_char else false);
+const FLAGS: [bool; 3] = envparse::parse_env!("TRUTHY" as [bool_first_char; 3] else [false; 3]);
+
+fn main() {
+    assert!(TRUTHY);
+    assert!(!FALSEY);
+    assert!(ONE);
+    assert!(BLANK);
+    assert!(!MISSING);
+    assert_eq!(FLAGS, [true, false, false]);
+}
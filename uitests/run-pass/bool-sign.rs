@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:PLUS=+
+// rustc-env:MINUS=-
+// rustc-env:ERREXIT=-e
+// rustc-env:XTRACE=+x
+// rustc-env:FLAGS=+, -, +
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PLUS: bool = envparse::parse_env!("PLUS" as bool_sign);
+const MINUS: bool = envparse::parse_env!("MINUS" as bool_sign else true);
+const ERREXIT: bool = envparse::parse_env!("ERREXIT" as bool_sign "e");
+const XTRACE: bool = envparse::parse_env!("XTRACE" as bool_sign "x" else false);
+const BARE: bool = envparse::parse_env!("PLUS" as bool_sign "e");
+const FLAGS: [bool; 3] = envparse::parse_env!("FLAGS" as [bool_sign; 3]);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool_sign "e" else true);
+
+fn main() {
+    assert!(PLUS);
+    assert!(!MINUS);
+    assert!(!ERREXIT);
+    assert!(XTRACE);
+    assert!(BARE);
+    assert_eq!(FLAGS, [true, false, true]);
+    assert!(MISSING);
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:ACK=T
+// rustc-env:NAK=F
+// rustc-env:FLAGS=T,F,,T
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ACK: bool = envparse::parse_env!("ACK" as bool_tf);
+const NAK: bool = envparse::parse_env!("NAK" as bool_tf else true);
+const FLAGS: [Option<bool>; 4] = envparse::parse_env!("FLAGS" as [Option<bool_tf>; 4]);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool_tf else true);
+
+fn main() {
+    assert!(ACK);
+    assert!(!NAK);
+    assert_eq!(FLAGS, [Some(true), Some(false), None, Some(true)]);
+    assert!(MISSING);
+}
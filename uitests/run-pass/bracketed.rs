@@ -0,0 +1,46 @@
+// compile-flags: --error-format=human
+// rustc-env:SQUARE=[1, 2, 3]
+// rustc-env:PAREN= (true,false) 
+// rustc-env:CURLY={0x10 0x20}
+// rustc-env:BARE=4,5
+// rustc-env:EMPTY=[]
+// rustc-env:LIST=(7, 8)
+// rustc-env:LOOSE_LIST={ a b }
+// rustc-env:UNIQUE=[3, 1, 3]
+// rustc-env:OPTIONS=(1,,0)
+#![crate_type = "bin"]
+extern crate envparse;
+
+const SQUARE: [u8; 3] = envparse::parse_env!("SQUARE" as [u8; 3] bracketed);
+const PAREN: [bool; 2] = envparse::parse_env!("PAREN" as [bool; 2] bracketed);
+const CURLY: [u16; 2] = envparse::parse_env!("CURLY" as [u16; 2] loose bracketed);
+const BARE: [i32; 2] = envparse::parse_env!("BARE" as [i32; 2] bracketed else [0, 0]);
+const EMPTY: [u8; 2] = envparse::parse_env!("EMPTY" as [u8; 2] bracketed else [9, 9]);
+const MISSING: [u8; 2] = envparse::parse_env!("MISSING" as [u8; 2] loose bracketed else [1, 2]);
+const LIST: ([u32; 4], usize) = envparse::parse_env!("LIST" as list(u32; max 4) bracketed);
+const LOOSE_LIST: ([char; 3], usize) = envparse::parse_env!("LOOSE_LIST" as list(char; max 3) loose bracketed);
+const EMPTY_LIST: ([u8; 2], usize) = envparse::parse_env!("EMPTY" as list(u8; max 2) bracketed else &[3]);
+const MISSING_LIST: ([u8; 2], usize) = envparse::parse_env!("MISSING" as list(u8; max 2) loose bracketed else &[4]);
+const FILL_LIST: ([u8; 3], usize) = envparse::parse_env!("LIST" as list(u8; max 3, fill 255) bracketed);
+const UNIQUE: ([u8; 3], usize) = envparse::parse_env!("UNIQUE" as list(u8; max 3) unique bracketed);
+const LOOSE_UNIQUE: ([u16; 2], usize) = envparse::parse_env!("CURLY" as list(u16; max 2) loose unique bracketed else &[]);
+const OPTIONS: [Option<bool>; 3] = envparse::parse_env!("OPTIONS" as [Option<bool>; 3] bracketed);
+const EMPTY_OPTIONS: [Option<u8>; 2] = envparse::parse_env!("EMPTY" as [Option<u8>; 2] bracketed else [Some(1), None]);
+
+fn main() {
+    assert_eq!(SQUARE, [1, 2, 3]);
+    assert_eq!(PAREN, [true, false]);
+    assert_eq!(CURLY, [0x10, 0x20]);
+    assert_eq!(BARE, [4, 5]);
+    assert_eq!(EMPTY, [9, 9]);
+    assert_eq!(MISSING, [1, 2]);
+    assert_eq!(LIST, ([7, 8, 0, 0], 2));
+    assert_eq!(LOOSE_LIST, (['a', 'b', '\0'], 2));
+    assert_eq!(EMPTY_LIST, ([3, 0], 1));
+    assert_eq!(MISSING_LIST, ([4, 0], 1));
+    assert_eq!(FILL_LIST, ([7, 8, 255], 2));
+    assert_eq!(UNIQUE, ([1, 3, 0], 2));
+    assert_eq!(LOOSE_UNIQUE, ([0x10, 0x20], 2));
+    assert_eq!(OPTIONS, [Some(true), None, Some(false)]);
+    assert_eq!(EMPTY_OPTIONS, [None, None]);
+}
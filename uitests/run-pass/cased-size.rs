@@ -0,0 +1,24 @@
+// compile-flags: --error-format=human
+// rustc-env:SI=4k
+// rustc-env:BINARY=4K
+// rustc-env:PLAIN=123
+#![crate_type = "bin"]
+extern crate envparse;
+
+const SI: usize = envparse::parse_env!("SI" as cased_size);
+const BINARY: usize = envparse::parse_env!("BINARY" as cased_size);
+const PLAIN: usize = envparse::parse_env!("PLAIN" as cased_size);
+const BINARY_IN: usize = envparse::parse_env!("BINARY" as cased_size in 4001..=4096);
+const MISSING: usize = envparse::parse_env!("MISSING" as cased_size (in ..=8192) else 1024);
+const TRY_SI: Option<usize> = envparse::parse_env!(try "SI" as cased_size in ..);
+const LIT: usize = envparse::parse_env!(lit "2M" as cased_size);
+
+fn main() {
+    assert_eq!(SI, 4000);
+    assert_eq!(BINARY, 4096);
+    assert_eq!(PLAIN, 123);
+    assert_eq!(BINARY_IN, 4096);
+    assert_eq!(MISSING, 1024);
+    assert_eq!(TRY_SI, Some(4000));
+    assert_eq!(LIT, 2 << 20);
+}
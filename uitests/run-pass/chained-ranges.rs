@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:MIN_THREADS=2
+// rustc-env:MAX_THREADS=8
+// rustc-env:THREADS=6
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::parse_env;
+
+// Bounds which come from other variables.
+const MIN_THREADS: u32 = parse_env!("MIN_THREADS" as u32 in 1..);
+const MAX_THREADS: u32 = parse_env!("MAX_THREADS" as u32 in MIN_THREADS..=64);
+const THREADS: u32 = parse_env!("THREADS" as u32 in MIN_THREADS..=MAX_THREADS);
+const DEFAULT_THREADS: u32 = parse_env!("MISSING" as u32 (in MIN_THREADS..=MAX_THREADS) else MAX_THREADS);
+const TRY_THREADS: Option<u32> = parse_env!(try "THREADS" as u32 in MIN_THREADS..);
+
+fn main() {
+    assert_eq!(MIN_THREADS, 2);
+    assert_eq!(MAX_THREADS, 8);
+    assert_eq!(THREADS, 6);
+    assert_eq!(DEFAULT_THREADS, 8);
+    assert_eq!(TRY_THREADS, Some(6));
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:ALLOWED=U+0041 U+0042 U+0043
+// rustc-env:MIXED=  U+1F600,0x1e  31 
+// rustc-env:COMMAS=U+41,U+10FFFF
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ALLOWED: [char; 3] = envparse::parse_env!("ALLOWED" as [char_code; 3] loose);
+const MIXED: [char; 3] = envparse::parse_env!("MIXED" as [char_code; 3] loose);
+const COMMAS: [char; 2] = envparse::parse_env!("COMMAS" as [char_code; 2]);
+const MISSING: [char; 2] = envparse::parse_env!("MISSING" as [char_code; 2] loose else ['x', 'y']);
+
+fn main() {
+    assert_eq!(ALLOWED, ['A', 'B', 'C']);
+    assert_eq!(MIXED, ['\u{1f600}', '\x1e', '\x1f']);
+    assert_eq!(COMMAS, ['A', '\u{10ffff}']);
+    assert_eq!(MISSING, ['x', 'y']);
+}
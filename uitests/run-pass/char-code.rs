@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:DELIM=U+003B
+// rustc-env:HEX=0x1F600
+// rustc-env:SEPS=U+001E, 31
+#![crate_type = "bin"]
+extern crate envparse;
+
+const DELIM: char = envparse::parse_env!("DELIM" as char_code);
+const HEX: char = envparse::parse_env!("HEX" as char_code);
+const SEPS: [char; 2] = envparse::parse_env!("SEPS" as [char_code; 2]);
+const MISSING: char = envparse::parse_env!("MISSING" as char_code else '\t');
+
+fn main() {
+    assert_eq!(DELIM, ';');
+    assert_eq!(HEX, '😀');
+    assert_eq!(SEPS, ['\x1e', '\x1f']);
+    assert_eq!(MISSING, '\t');
+}
@@ -0,0 +1,29 @@
+// compile-flags: --error-format=human
+// rustc-env:DELIM=;
+// rustc-env:LETTER= q
+// rustc-env:DELIMS=a, b ,c
+// rustc-env:SNOWMAN=☃
+#![crate_type = "bin"]
+extern crate envparse;
+
+const DELIM: char = envparse::parse_env!("DELIM" as char);
+const LETTER: char = envparse::parse_env!("LETTER" as char in 'a'..='z');
+const LETTER_EXCL: char = envparse::parse_env!("LETTER" as char in 'a'..'r');
+const LETTER_FROM: char = envparse::parse_env!("LETTER" as char in 'q'..);
+const SPACE: char = envparse::parse_env!(lit " " as char);
+const SNOWMAN: char = envparse::parse_env!("SNOWMAN" as char in '\u{2000}'..'\u{e001}');
+const MISSING: char = envparse::parse_env!("MISSING" as char (in 'a'..='z') else 'x');
+const TRY_DELIM: Option<char> = envparse::parse_env!(try "DELIM" as char in ..);
+const DELIMS: [char; 3] = envparse::parse_env!("DELIMS" as [char; 3]);
+
+fn main() {
+    assert_eq!(DELIM, ';');
+    assert_eq!(LETTER, 'q');
+    assert_eq!(LETTER_EXCL, 'q');
+    assert_eq!(LETTER_FROM, 'q');
+    assert_eq!(SPACE, ' ');
+    assert_eq!(SNOWMAN, '☃');
+    assert_eq!(MISSING, 'x');
+    assert_eq!(TRY_DELIM, Some(';'));
+    assert_eq!(DELIMS, ['a', 'b', 'c']);
+}
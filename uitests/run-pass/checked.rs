@@ -0,0 +1,27 @@
+// compile-flags: --error-format=human
+// rustc-env:GOOD=0x20
+// rustc-env:BAD=12x
+// rustc-env:HUGE=300
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::Checked;
+
+const GOOD: Checked<u32> = envparse::parse_env!(checked "GOOD" as u32);
+const BAD: Checked<u32> = envparse::parse_env!(checked "BAD" as u32);
+const HUGE: Checked<u8> = envparse::parse_env!(checked "HUGE" as u8 else 1);
+const BLANK: Checked<bool> = envparse::parse_env!(checked "BLANK" as bool else true);
+const UNSET: Checked<u32> = envparse::parse_env!(checked "MISSING" as u32);
+const UNSET_DEFAULT: Checked<u32> = envparse::parse_env!(checked "MISSING" as u32 else 7);
+const FALLBACK: u8 = HUGE.unwrap_or(9);
+
+fn main() {
+    assert_eq!(GOOD, Checked::Value(32));
+    assert_eq!(BAD, Checked::Invalid("the value in `BAD` doesn't parse as a `u32`: invalid digit `x` for base 10."));
+    assert_eq!(HUGE.message(), Some("the value in `HUGE` doesn't parse as a `u8`, or is out of range."));
+    assert_eq!(FALLBACK, 9);
+    assert_eq!(BLANK.value(), Some(true));
+    assert!(!UNSET.is_valid());
+    assert_eq!(UNSET.message(), Some("`MISSING` isn't set."));
+    assert_eq!(UNSET_DEFAULT.unwrap_or(0), 7);
+}
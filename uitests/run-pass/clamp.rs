@@ -0,0 +1,27 @@
+// compile-flags: --error-format=human
+// rustc-env:NEG=-5
+// rustc-env:HUGE=300
+// rustc-env:LOW=0
+// rustc-env:OK=7
+#![crate_type = "bin"]
+extern crate envparse;
+
+const NEG: usize = envparse::parse_env!("NEG" as usize clamp);
+const NEG_ELSE: usize = envparse::parse_env!("NEG" as usize clamp else 3);
+const HUGE: u8 = envparse::parse_env!("HUGE" as u8 clamp);
+const MISSING: usize = envparse::parse_env!("MISSING" as usize clamp else 3);
+const LOW: u32 = envparse::parse_env!("LOW" as u32 (in 1..=10) clamp);
+const HIGH: u32 = envparse::parse_env!("HUGE" as u32 (in 1..=10) clamp else 5);
+const NEG_IN: u32 = envparse::parse_env!("NEG" as u32 (in 2..) clamp);
+const OK: i8 = envparse::parse_env!("OK" as i8 (in -4..10) clamp else 0);
+
+fn main() {
+    assert_eq!(NEG, 0);
+    assert_eq!(NEG_ELSE, 0);
+    assert_eq!(HUGE, 255);
+    assert_eq!(MISSING, 3);
+    assert_eq!(LOW, 1);
+    assert_eq!(HIGH, 10);
+    assert_eq!(NEG_IN, 2);
+    assert_eq!(OK, 7);
+}
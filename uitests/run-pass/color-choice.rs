@@ -0,0 +1,29 @@
+// compile-flags: --error-format=human
+// rustc-env:NO_COLOR=1
+// rustc-env:CLICOLOR_FORCE=1
+// rustc-env:A_NO_COLOR=
+// rustc-env:A_CLICOLOR=0
+// rustc-env:A_FORCE=1
+// rustc-env:B_CLICOLOR= 0 
+// rustc-env:C_CLICOLOR=1
+// rustc-env:C_FORCE=0
+#![crate_type = "bin"]
+extern crate envparse;
+
+use envparse::ColorChoice;
+
+const DEFAULT: ColorChoice = envparse::color_choice!();
+const A: ColorChoice = envparse::color_choice!("A_NO_COLOR", "A_CLICOLOR", "A_FORCE");
+const B: ColorChoice = envparse::color_choice!("B_NO_COLOR", "B_CLICOLOR", "B_FORCE");
+const C: ColorChoice = envparse::color_choice!("C_NO_COLOR", "C_CLICOLOR", "C_FORCE",);
+const UNSET: ColorChoice = envparse::color_choice!("X_NO_COLOR", "X_CLICOLOR", "X_FORCE");
+
+fn main() {
+    // `NO_COLOR` wins over `CLICOLOR_FORCE`.
+    assert_eq!(DEFAULT, ColorChoice::Never);
+    // A blank `NO_COLOR` is ignored, and `CLICOLOR_FORCE` wins over `CLICOLOR`.
+    assert_eq!(A, ColorChoice::Always);
+    assert_eq!(B, ColorChoice::Never);
+    assert_eq!(C, ColorChoice::Auto);
+    assert_eq!(UNSET, ColorChoice::Auto);
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:PREFIX=MYAPP
+// rustc-env:MYAPP_THREADS=12
+// rustc-env:MYAPP_PORT=8080
+#![crate_type = "bin"]
+extern crate envparse;
+
+const THREADS: u32 = envparse::parse_env!(concat!("MYAPP", "_THREADS") as u32);
+const PORT: u16 = envparse::parse_env!(concat!(env!("PREFIX"), "_PORT") as u16 in 1024..);
+const DEPTH: u8 = envparse::parse_env!(concat!(env!("PREFIX"), "_DEPTH") as u8 else 3);
+const LEVEL: u8 = envparse::parse_env!(concat!(env!("PREFIX"), "_LEVEL") as u8 (in 1..=9) else 5);
+
+fn main() {
+    assert_eq!(THREADS, 12);
+    assert_eq!(PORT, 8080);
+    assert_eq!(DEPTH, 3);
+    assert_eq!(LEVEL, 5);
+}
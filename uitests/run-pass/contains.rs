@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:FEATURES= simd , Tracing,fast-math
+// rustc-env:EMPTY=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const SIMD: bool = envparse::parse_env!(contains "FEATURES" "simd");
+const TRACING: bool = envparse::parse_env!(contains "FEATURES" "tracing");
+const FAST_MATH: bool = envparse::parse_env!(contains "FEATURES" "FAST-MATH");
+const FAST: bool = envparse::parse_env!(contains "FEATURES" "fast");
+const EMPTY: bool = envparse::parse_env!(contains "EMPTY" "simd");
+const UNSET: bool = envparse::parse_env!(contains "UNSET" "simd");
+
+fn main() {
+    assert!(SIMD);
+    assert!(TRACING);
+    assert!(FAST_MATH);
+    assert!(!FAST);
+    assert!(!EMPTY);
+    assert!(!UNSET);
+}
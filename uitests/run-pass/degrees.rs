@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:ANGLE=90deg
+// rustc-env:TEMP=-40°
+// rustc-env:HEADINGS=0, 90 DEG, 180°
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ANGLE: i32 = envparse::parse_env!("ANGLE" as degrees);
+const TEMP: i32 = envparse::parse_env!("TEMP" as degrees in -50..=150);
+const HEADINGS: [i32; 3] = envparse::parse_env!("HEADINGS" as [degrees; 3]);
+const MISSING: i32 = envparse::parse_env!("MISSING" as degrees (in 0..360) else 270);
+
+fn main() {
+    assert_eq!(ANGLE, 90);
+    assert_eq!(TEMP, -40);
+    assert_eq!(HEADINGS, [0, 90, 180]);
+    assert_eq!(MISSING, 270);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:RETRY=100ms..=500ms
+// rustc-env:EXCLUSIVE=1s..2s
+// rustc-env:MIXED= 500ms ..= 1m 
+// rustc-env:OPEN=1h..
+#![crate_type = "bin"]
+extern crate envparse;
+
+const RETRY: (u64, u64) = envparse::parse_env!("RETRY" as duration_range);
+const EXCLUSIVE: (u64, u64) = envparse::parse_env!("EXCLUSIVE" as duration_range);
+const MIXED: (u64, u64) = envparse::parse_env!("MIXED" as duration_range else (0, 0));
+const OPEN: (u64, u64) = envparse::parse_env!("OPEN" as duration_range);
+const MISSING: (u64, u64) = envparse::parse_env!("MISSING" as duration_range else (1, 2));
+
+fn main() {
+    assert_eq!(RETRY, (100_000_000, 500_000_000));
+    assert_eq!(EXCLUSIVE, (1_000_000_000, 1_999_999_999));
+    assert_eq!(MIXED, (500_000_000, 60_000_000_000));
+    assert_eq!(OPEN, (3_600_000_000_000, u64::MAX));
+    assert_eq!(MISSING, (1, 2));
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:TIMEOUT=1h30m
+// rustc-env:POLL=1.5s
+// rustc-env:TINY=12µs
+// rustc-env:BACKOFF=100ms, 1s, 1m
+#![crate_type = "bin"]
+extern crate envparse;
+use core::time::Duration;
+
+const TIMEOUT: Duration = envparse::parse_env!("TIMEOUT" as duration);
+const POLL: Duration = envparse::parse_env!("POLL" as duration else Duration::ZERO);
+const TINY: Duration = envparse::parse_env!("TINY" as duration);
+const BACKOFF: [Duration; 3] = envparse::parse_env!("BACKOFF" as [duration; 3]);
+const MISSING: Duration = envparse::parse_env!("MISSING" as duration else Duration::from_secs(30));
+
+fn main() {
+    assert_eq!(TIMEOUT, Duration::from_secs(90 * 60));
+    assert_eq!(POLL, Duration::from_millis(1500));
+    assert_eq!(TINY, Duration::from_micros(12));
+    assert_eq!(BACKOFF, [Duration::from_millis(100), Duration::from_secs(1), Duration::from_secs(60)]);
+    assert_eq!(MISSING, Duration::from_secs(30));
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:EMPTY=
+// rustc-env:BLANK=
+// rustc-env:PRESENT=3
+#![crate_type = "bin"]
+extern crate envparse;
+
+const UNSET: u32 = envparse::parse_env!("UNSET" as u32 empty_is 0 else 8);
+const EMPTY: u32 = envparse::parse_env!("EMPTY" as u32 empty_is 0 else 8);
+const BLANK: i32 = envparse::parse_env!("BLANK" as i32 empty_is (-1) else 8);
+const PRESENT: u32 = envparse::parse_env!("PRESENT" as u32 empty_is 0 else 8);
+const EMPTY_NO_ELSE: u32 = envparse::parse_env!("EMPTY" as u32 empty_is 0);
+const PRESENT_NO_ELSE: u32 = envparse::parse_env!("PRESENT" as u32 empty_is 0);
+
+fn main() {
+    assert_eq!(UNSET, 8);
+    assert_eq!(EMPTY, 0);
+    assert_eq!(BLANK, -1);
+    assert_eq!(PRESENT, 3);
+    assert_eq!(EMPTY_NO_ELSE, 0);
+    assert_eq!(PRESENT_NO_ELSE, 3);
+}
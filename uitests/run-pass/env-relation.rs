@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:MIN=2
+// rustc-env:MAX=0x10
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+envparse::assert_env_relation!("MIN" < "MAX" as u32);
+envparse::assert_env_relation!("MIN" <= "MAX" as u32);
+envparse::assert_env_relation!("MIN" != "MAX" as u8);
+envparse::assert_env_relation!("MAX" >= "MIN" as i64);
+envparse::assert_env_relation!("MAX" > "MIN" as usize);
+envparse::assert_env_relation!("MIN" == "MIN" as u32);
+// Skipped, since one isn't set.
+envparse::assert_env_relation!("MAX" < "UNSET" as u32);
+envparse::assert_env_relation!("MAX" < "BLANK" as u32);
+
+fn main() {}
@@ -0,0 +1,44 @@
+// compile-flags: --error-format=human
+// rustc-env:U128MAX=340282366920938463463374607431768211455
+// rustc-env:I128MIN=-170141183460469231731687303715884105728
+// rustc-env:I128MAX=170141183460469231731687303715884105727
+// rustc-env:U64MAX=18446744073709551615
+// rustc-env:I64MIN=-9223372036854775808
+#![crate_type = "bin"]
+extern crate envparse;
+
+const U128MAX: u128 = envparse::parse_env!("U128MAX" as u128);
+const I128MIN: i128 = envparse::parse_env!("I128MIN" as i128);
+const I128MAX: i128 = envparse::parse_env!("I128MAX" as i128);
+
+const U128MAX_IN: u128 = envparse::parse_env!("U128MAX" as u128 in ..);
+const I128MIN_IN: i128 = envparse::parse_env!("I128MIN" as i128 in ..=0);
+const I128MAX_IN: i128 = envparse::parse_env!("I128MAX" as i128 in 0..);
+
+const U128MAX_DEF: u128 = envparse::parse_env!("U128MAX" as u128 else 0);
+const I128MIN_DEF: i128 = envparse::parse_env!("I128MIN" as i128 (in ..) else 0);
+
+const TRY_U128MAX: Option<u128> = envparse::parse_env!(try "U128MAX" as u128);
+const TRY_I128MIN: Option<i128> = envparse::parse_env!(try "I128MIN" as i128 in ..);
+
+const U64MAX: u64 = envparse::parse_env!("U64MAX" as u64);
+const I64MIN: i64 = envparse::parse_env!("I64MIN" as i64);
+
+fn main() {
+    assert_eq!(U128MAX, u128::MAX);
+    assert_eq!(I128MIN, i128::MIN);
+    assert_eq!(I128MAX, i128::MAX);
+
+    assert_eq!(U128MAX_IN, u128::MAX);
+    assert_eq!(I128MIN_IN, i128::MIN);
+    assert_eq!(I128MAX_IN, i128::MAX);
+
+    assert_eq!(U128MAX_DEF, u128::MAX);
+    assert_eq!(I128MIN_DEF, i128::MIN);
+
+    assert_eq!(TRY_U128MAX, Some(u128::MAX));
+    assert_eq!(TRY_I128MIN, Some(i128::MIN));
+
+    assert_eq!(U64MAX, u64::MAX);
+    assert_eq!(I64MIN, i64::MIN);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:POISON=0xAA*16
+// rustc-env:SPACED= 7 * 0b11 
+// rustc-env:SINGLE=0x5a
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const POISON: [u8; 16] = envparse::parse_env!("POISON" as [u8; 16] fill);
+const SPACED: [u8; 3] = envparse::parse_env!("SPACED" as [u8; 3] fill else [0; 3]);
+const SINGLE: [u8; 1] = envparse::parse_env!("SINGLE" as [u8; 1] fill);
+const BLANK: [u8; 4] = envparse::parse_env!("BLANK" as [u8; 4] fill else [1, 2, 3, 4]);
+const MISSING: [u8; 2] = envparse::parse_env!("MISSING" as [u8; 2] fill else [0xff; 2]);
+
+fn main() {
+    assert_eq!(POISON, [0xaa; 16]);
+    assert_eq!(SPACED, [7; 3]);
+    assert_eq!(SINGLE, [0x5a]);
+    assert_eq!(BLANK, [1, 2, 3, 4]);
+    assert_eq!(MISSING, [0xff; 2]);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:WITH_ARG=on:256
+// rustc-env:BARE=yes
+// rustc-env:OFF=off
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const WITH_ARG: Option<u32> = envparse::parse_env!("WITH_ARG" as flag(u32; on 64));
+const BARE: Option<u32> = envparse::parse_env!("BARE" as flag(u32; on 64));
+const OFF: Option<u32> = envparse::parse_env!("OFF" as flag(u32; on 64) else Some(1));
+const BLANK: Option<i8> = envparse::parse_env!("BLANK" as flag(i8; on -1) else Some(3));
+const MISSING: Option<usize> = envparse::parse_env!("MISSING" as flag(usize; on 8) else None);
+
+fn main() {
+    assert_eq!(WITH_ARG, Some(256));
+    assert_eq!(BARE, Some(64));
+    assert_eq!(OFF, None);
+    assert_eq!(BLANK, Some(3));
+    assert_eq!(MISSING, None);
+}
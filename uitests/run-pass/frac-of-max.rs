@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:SMALL=100
+// rustc-env:NEGATIVE=-5
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::frac_of_max;
+
+const SMALL: u8 = envparse::parse_env!("SMALL" as u8 in ..=frac_of_max::<u8>(50));
+const NEGATIVE: i32 = envparse::parse_env!("NEGATIVE" as i32 in -frac_of_max::<i32>(10)..=frac_of_max::<i32>(10));
+const BIG: u128 = envparse::parse_env!("MISSING" as u128 (in frac_of_max::<u128>(25)..) else u128::MAX);
+
+fn main() {
+    assert_eq!(SMALL, 100);
+    assert_eq!(NEGATIVE, -5);
+    assert_eq!(BIG, u128::MAX);
+
+    assert_eq!(frac_of_max::<u8>(0), 0);
+    assert_eq!(frac_of_max::<u8>(50), 127);
+    assert_eq!(frac_of_max::<u8>(100), u8::MAX);
+    assert_eq!(frac_of_max::<i8>(50), 63);
+    assert_eq!(frac_of_max::<u128>(50), u128::MAX / 2);
+    assert_eq!(frac_of_max::<u128>(100), u128::MAX);
+    assert_eq!(frac_of_max::<i128>(100), i128::MAX);
+    assert_eq!(frac_of_max::<u64>(33), (u64::MAX as u128 * 33 / 100) as u64);
+    assert_eq!(frac_of_max::<usize>(1), (usize::MAX as u128 / 100) as usize);
+}
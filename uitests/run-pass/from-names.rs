@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:NAMED= High 
+// rustc-env:NUMBER=3
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const NAMED: u8 = envparse::parse_env!("NAMED" as u8 from { "low" => 1, "med" => 5, "high" => 9 });
+const NUMBER: u8 = envparse::parse_env!("NUMBER" as u8 from { "low" => 1, "med" => 5, "high" => 9 } else 5);
+const BLANK: i32 = envparse::parse_env!("BLANK" as i32 from { "off" => -1 } else 7);
+const MISSING: u16 = envparse::parse_env!("MISSING" as u16 from { "max" => u16::MAX, } else 100);
+const BOOL: bool = envparse::parse_env!("NAMED" as bool from { "high" => true, "low" => false });
+
+fn main() {
+    assert_eq!(NAMED, 9);
+    assert_eq!(NUMBER, 3);
+    assert_eq!(BLANK, 7);
+    assert_eq!(MISSING, 100);
+    assert!(BOOL);
+}
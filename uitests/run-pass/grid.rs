@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:GRID=1,0;0,1
+// rustc-env:SPACED= 1, 1 ; 0, 0 ;
+// rustc-env:PARTIAL=1;;0
+// rustc-env:NUMS=1,2,3;4,5,6
+#![crate_type = "bin"]
+extern crate envparse;
+
+const GRID: [[bool; 2]; 2] = envparse::parse_env!("GRID" as [[bool; 2]; 2]);
+const SPACED: [[bool; 2]; 2] = envparse::parse_env!("SPACED" as [[bool; 2]; 2]);
+const PARTIAL: [[bool; 2]; 3] = envparse::parse_env!("PARTIAL" as [[bool; 2]; 3] else [[false, true]; 3]);
+const NUMS: [[u8; 3]; 2] = envparse::parse_env!("NUMS" as [[u8; 3]; 2]);
+const MISSING: [[bool; 2]; 2] = envparse::parse_env!("MISSING" as [[bool; 2]; 2] else [[true, false], [false, true]]);
+
+fn main() {
+    assert_eq!(GRID, [[true, false], [false, true]]);
+    assert_eq!(SPACED, [[true, true], [false, false]]);
+    assert_eq!(PARTIAL, [[true, true], [false, true], [false, true]]);
+    assert_eq!(NUMS, [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(MISSING, [[true, false], [false, true]]);
+}
@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:MMIO_BASE=0x4000_1000
+// rustc-env:PAGE=  0X2000 
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PAGE_SIZE: usize = 4096;
+
+const MMIO_BASE: usize = envparse::parse_env!("MMIO_BASE" as usize hex_required aligned 4096);
+const PAGE: u64 = envparse::parse_env!("PAGE" as u64 hex_required aligned (PAGE_SIZE as u64) else 0);
+const BLANK: u32 = envparse::parse_env!("BLANK" as u32 hex_required aligned 16 else 0x100);
+const MISSING: u16 = envparse::parse_env!("MISSING" as u16 hex_required aligned 1 else 0x123);
+
+fn main() {
+    assert_eq!(MMIO_BASE, 0x4000_1000);
+    assert_eq!(PAGE, 0x2000);
+    assert_eq!(BLANK, 0x100);
+    assert_eq!(MISSING, 0x123);
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:HASH=abc123
+// rustc-env:PREFIXED=0xDEADBEEF
+// rustc-env:ZEROS=0000
+#![crate_type = "bin"]
+extern crate envparse;
+
+const HASH: u32 = envparse::parse_env!("HASH" as u32 hex digits 6);
+const PREFIXED: u64 = envparse::parse_env!("PREFIXED" as u64 hex digits 8);
+const ZEROS: u16 = envparse::parse_env!("ZEROS" as u16 hex digits 4 else 0xffff);
+const MISSING: u32 = envparse::parse_env!("MISSING" as u32 hex digits 6 else 0xfedcba);
+
+fn main() {
+    assert_eq!(HASH, 0xabc123);
+    assert_eq!(PREFIXED, 0xdeadbeef);
+    assert_eq!(ZEROS, 0);
+    assert_eq!(MISSING, 0xfedcba);
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:ADDR=0xdeadbeef
+// rustc-env:OFFSET=-0X10
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ADDR: u32 = envparse::parse_env!("ADDR" as u32 hex_required);
+const OFFSET: i64 = envparse::parse_env!("OFFSET" as i64 hex_required);
+const BLANK: u16 = envparse::parse_env!("BLANK" as u16 hex_required else 0xff);
+const MISSING: usize = envparse::parse_env!("MISSING" as usize hex_required else 0x1000);
+
+fn main() {
+    assert_eq!(ADDR, 0xdeadbeef);
+    assert_eq!(OFFSET, -16);
+    assert_eq!(BLANK, 0xff);
+    assert_eq!(MISSING, 0x1000);
+}
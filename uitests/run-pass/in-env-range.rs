@@ -0,0 +1,30 @@
+// compile-flags: --error-format=human
+// rustc-env:RANGE=10..100
+// rustc-env:RANGE_INCL=-5..=5
+// rustc-env:RANGE_FROM=8..
+// rustc-env:RANGE_BLANK= 
+// rustc-env:VALUE=50
+// rustc-env:BIG=500
+// rustc-env:NEG=-20
+#![crate_type = "bin"]
+extern crate envparse;
+
+const VALUE: u32 = envparse::parse_env!("VALUE" in_env_range "RANGE" as u32);
+const CLAMPED: u32 = envparse::parse_env!("BIG" in_env_range "RANGE" as u32 clamp);
+const CLAMPED_NEG: i8 = envparse::parse_env!("NEG" in_env_range "RANGE_INCL" as i8 clamp else 0);
+const FROM: usize = envparse::parse_env!("BIG" in_env_range "RANGE_FROM" as usize);
+const BLANK: u32 = envparse::parse_env!("BIG" in_env_range "RANGE_BLANK" as u32);
+const UNSET_RANGE: u32 = envparse::parse_env!("BIG" in_env_range "MISSING_RANGE" as u32);
+const DEFAULT: u32 = envparse::parse_env!("MISSING" in_env_range "RANGE" as u32 else 20);
+const DEFAULT_CLAMPED: u32 = envparse::parse_env!("MISSING" in_env_range "RANGE" as u32 clamp else 0);
+
+fn main() {
+    assert_eq!(VALUE, 50);
+    assert_eq!(CLAMPED, 99);
+    assert_eq!(CLAMPED_NEG, -5);
+    assert_eq!(FROM, 500);
+    assert_eq!(BLANK, 500);
+    assert_eq!(UNSET_RANGE, 500);
+    assert_eq!(DEFAULT, 20);
+    assert_eq!(DEFAULT_CLAMPED, 10);
+}
@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:REGION=eu
+// rustc-env:LOUD= AP 
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const REGION: usize = envparse::parse_env!("REGION" index_in ["us", "eu", "ap"]);
+const LOUD: usize = envparse::parse_env!("LOUD" index_in ["us", "eu", "ap",]);
+const BLANK: usize = envparse::parse_env!("BLANK" index_in ["us", "eu", "ap"] else 2);
+const MISSING: usize = envparse::parse_env!("MISSING" index_in ["us", "eu", "ap"] else 1);
+const WITH_DEFAULT: usize = envparse::parse_env!("REGION" index_in ["us", "eu", "ap"] else 0);
+
+fn main() {
+    assert_eq!(REGION, 1);
+    assert_eq!(LOUD, 2);
+    assert_eq!(BLANK, 2);
+    assert_eq!(MISSING, 1);
+    assert_eq!(WITH_DEFAULT, 1);
+}
@@ -0,0 +1,15 @@
+// compile-flags: --error-format=human
+// rustc-env:PIN_MODE=2
+// rustc-env:ZERO=0
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PIN_MODE: u8 = envparse::parse_env!("PIN_MODE" as u8 (in 0..=2) else 0);
+const ZERO: u8 = envparse::parse_env!("ZERO" as u8 in 0..=2);
+const MISSING: u8 = envparse::parse_env!("MISSING" as u8 (in 0..=2) else 1);
+
+fn main() {
+    assert_eq!(PIN_MODE, 2);
+    assert_eq!(ZERO, 0);
+    assert_eq!(MISSING, 1);
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:NEGATIVE=-0x10
+// rustc-env:HUGE=170141183460469231731687303715884105727
+// rustc-env:LIST=1, -2, 3
+#![crate_type = "bin"]
+extern crate envparse;
+
+const NEGATIVE: i128 = envparse::parse_env!("NEGATIVE" as int);
+const HUGE: i128 = envparse::parse_env!("HUGE" as int);
+const RANGED: i128 = envparse::parse_env!("NEGATIVE" as int in -100..0);
+const LIST: [i128; 3] = envparse::parse_env!("LIST" as [int; 3]);
+const TRY: Option<i128> = envparse::parse_env!(try "MISSING" as int);
+const MISSING: i128 = envparse::parse_env!("MISSING" as int (in -5..=5) else -5);
+
+fn main() {
+    assert_eq!(NEGATIVE, -16);
+    assert_eq!(HUGE, i128::MAX);
+    assert_eq!(RANGED, -16);
+    assert_eq!(LIST, [1, -2, 3]);
+    assert_eq!(TRY, None);
+    assert_eq!(MISSING, -5);
+}
@@ -0,0 +1,19 @@
+// compile-flags: --error-format=human
+// rustc-env:DNS=8.8.8.8, 1.1.1.1
+// rustc-env:BIND=0.0.0.0
+// rustc-env:PEERS=10.0.0.1,,10.0.0.3
+#![crate_type = "bin"]
+extern crate envparse;
+use core::net::Ipv4Addr;
+
+const DNS: [Ipv4Addr; 2] = envparse::parse_env!("DNS" as [Ipv4Addr; 2]);
+const BIND: Ipv4Addr = envparse::parse_env!("BIND" as Ipv4Addr);
+const PEERS: [Option<Ipv4Addr>; 4] = envparse::parse_env!("PEERS" as [Option<Ipv4Addr>; 4]);
+const MISSING: [Ipv4Addr; 2] = envparse::parse_env!("MISSING" as [Ipv4Addr; 2] else [Ipv4Addr::LOCALHOST; 2]);
+
+fn main() {
+    assert_eq!(DNS, [Ipv4Addr::new(8, 8, 8, 8), Ipv4Addr::new(1, 1, 1, 1)]);
+    assert_eq!(BIND, Ipv4Addr::UNSPECIFIED);
+    assert_eq!(PEERS, [Some(Ipv4Addr::new(10, 0, 0, 1)), None, Some(Ipv4Addr::new(10, 0, 0, 3)), None]);
+    assert_eq!(MISSING, [Ipv4Addr::LOCALHOST; 2]);
+}
@@ -0,0 +1,31 @@
+// compile-flags: --error-format=human
+// rustc-env:AUTO=auto
+// rustc-env:MAX= MAX 
+// rustc-env:VALUE=0x100
+// rustc-env:NEG=-1
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+use envparse::Limit;
+
+const AUTO: Limit<u64> = envparse::parse_env!("AUTO" as limit(u64));
+const MAX: Limit<u64> = envparse::parse_env!("MAX" as limit(u64));
+const VALUE: Limit<u32> = envparse::parse_env!("VALUE" as limit(u32) else Limit::Max);
+const NEG: Limit<i8> = envparse::parse_env!("NEG" as limit(i8));
+const BLANK: Limit<usize> = envparse::parse_env!("BLANK" as limit(usize) else Limit::Value(3));
+const MISSING: Limit<u16> = envparse::parse_env!("MISSING" as limit(u16) else Limit::Auto);
+
+fn main() {
+    assert_eq!(AUTO, Limit::Auto);
+    assert_eq!(MAX, Limit::Max);
+    assert_eq!(VALUE, Limit::Value(256));
+    assert_eq!(NEG, Limit::Value(-1));
+    assert_eq!(BLANK, Limit::Value(3));
+    assert_eq!(MISSING, Limit::Auto);
+    assert_eq!(AUTO.resolve(10), 10);
+    assert_eq!(MAX.resolve(10), u64::MAX);
+    assert_eq!(VALUE.resolve(10), 256);
+    assert_eq!(NEG.resolve(0), -1);
+    assert_eq!(Limit::<i8>::Max.resolve(0), i8::MAX);
+}
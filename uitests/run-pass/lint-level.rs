@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:STRICTNESS= Deny 
+// rustc-env:LEVELS=allow,warn,forbid
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::LintLevel;
+
+const STRICTNESS: LintLevel = envparse::parse_env!("STRICTNESS" as lint_level);
+const MISSING: LintLevel = envparse::parse_env!("MISSING" as lint_level else LintLevel::Warn);
+const LEVELS: [LintLevel; 3] = envparse::parse_env!("LEVELS" as [lint_level; 3]);
+const STRICT: bool = STRICTNESS.at_least(LintLevel::Deny);
+
+envparse::assert_parses!("FORBID" as lint_level => LintLevel::Forbid);
+
+fn main() {
+    assert_eq!(STRICTNESS, LintLevel::Deny);
+    assert_eq!(MISSING, LintLevel::Warn);
+    assert_eq!(LEVELS, [LintLevel::Allow, LintLevel::Warn, LintLevel::Forbid]);
+    assert!(STRICT);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:PORTS=8080, 80,443,80
+// rustc-env:LEVELS=3 -1 3 0
+// rustc-env:SAME=7,7,7,7,7
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PORTS: ([u16; 4], usize) = envparse::parse_env!("PORTS" as list(u16; max 4) unique);
+const LEVELS: ([i8; 3], usize) = envparse::parse_env!("LEVELS" as list(i8; max 3) loose unique);
+const SAME: ([u32; 1], usize) = envparse::parse_env!("SAME" as list(u32; max 1) unique);
+const BLANK: ([u8; 2], usize) = envparse::parse_env!("BLANK" as list(u8; max 2) unique else &[9, 2, 9]);
+const MISSING: ([usize; 3], usize) = envparse::parse_env!("MISSING" as list(usize; max 3) unique else &[]);
+
+fn main() {
+    assert_eq!(PORTS, ([80, 443, 8080, 0], 3));
+    assert_eq!(LEVELS, ([-1, 0, 3], 3));
+    assert_eq!(SAME, ([7], 1));
+    assert_eq!(BLANK, ([2, 9], 2));
+    assert_eq!(MISSING, ([0, 0, 0], 0));
+}
@@ -0,0 +1,23 @@
+// compile-flags: --error-format=human
+// rustc-env:BACKOFF=100, 200,400
+// rustc-env:FULL=1,2,3,4
+// rustc-env:FLAGS=yes,no
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const BACKOFF: ([u32; 8], usize) = envparse::parse_env!("BACKOFF" as list(u32; max 8));
+const FULL: ([u8; 4], usize) = envparse::parse_env!("FULL" as list(u8; max 4) else &[9]);
+const FLAGS: ([bool; 3], usize) = envparse::parse_env!("FLAGS" as list(bool; max 3, fill true));
+const BLANK: ([u16; 2], usize) = envparse::parse_env!("BLANK" as list(u16; max 2));
+const BLANK_DEF: ([u16; 3], usize) = envparse::parse_env!("BLANK" as list(u16; max 3, fill 7) else &[1, 2]);
+const MISSING: ([i8; 2], usize) = envparse::parse_env!("MISSING" as list(i8; max 2) else &[-1]);
+
+fn main() {
+    assert_eq!(BACKOFF, ([100, 200, 400, 0, 0, 0, 0, 0], 3));
+    assert_eq!(FULL, ([1, 2, 3, 4], 4));
+    assert_eq!(FLAGS, ([true, false, true], 2));
+    assert_eq!(BLANK, ([0, 0], 0));
+    assert_eq!(BLANK_DEF, ([1, 2, 7], 2));
+    assert_eq!(MISSING, ([-1, 0], 1));
+}
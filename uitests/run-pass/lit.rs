@@ -0,0 +1,30 @@
+// compile-flags: --error-format=human
+#![crate_type = "bin"]
+extern crate envparse;
+
+const HEX: u32 = envparse::parse_env!(lit "0x1F" as u32);
+const NEG: i16 = envparse::parse_env!(lit " -300 " as i16);
+const YES: bool = envparse::parse_env!(lit "yes" as bool);
+
+const IN_RANGE: u8 = envparse::parse_env!(lit "0b101" as u8 in 0..8);
+const IN_RANGE_NEG: i64 = envparse::parse_env!(lit "-60" as i64 in -60..=0);
+
+const DEF_SET: usize = envparse::parse_env!(lit "12" as usize else 4);
+const DEF_EMPTY: usize = envparse::parse_env!(lit "" as usize else 4);
+const DEF_BOOL: bool = envparse::parse_env!(lit "  " as bool else true);
+
+const DEF_IN_SET: u16 = envparse::parse_env!(lit "7" as u16 (in 1..10) else 5);
+const DEF_IN_EMPTY: u16 = envparse::parse_env!(lit "" as u16 (in 1..10) else 5);
+
+fn main() {
+    assert_eq!(HEX, 0x1f);
+    assert_eq!(NEG, -300);
+    assert_eq!(YES, true);
+    assert_eq!(IN_RANGE, 5);
+    assert_eq!(IN_RANGE_NEG, -60);
+    assert_eq!(DEF_SET, 12);
+    assert_eq!(DEF_EMPTY, 4);
+    assert_eq!(DEF_BOOL, true);
+    assert_eq!(DEF_IN_SET, 7);
+    assert_eq!(DEF_IN_EMPTY, 5);
+}
@@ -0,0 +1,29 @@
+// compile-flags: --error-format=human
+// rustc-env:SPACES=1 2 3
+// rustc-env:MIXED= 1, 2 ,,3,
+// rustc-env:TABS=yes	no
+// rustc-env:SHORT=7 8
+// rustc-env:BLANK= , 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const SPACES: [u32; 3] = envparse::parse_env!("SPACES" as [u32; 3] loose);
+const MIXED: [u32; 3] = envparse::parse_env!("MIXED" as [u32; 3] loose);
+const TABS: [bool; 2] = envparse::parse_env!("TABS" as [bool; 2] loose else [true, true]);
+const SHORT: [u8; 3] = envparse::parse_env!("SHORT" as [u8; 3] loose else [1, 2, 3]);
+const LIST: ([u32; 4], usize) = envparse::parse_env!("MIXED" as list(u32; max 4) loose);
+const LIST_FILL: ([u32; 4], usize) = envparse::parse_env!("SPACES" as list(u32; max 4, fill 9) loose);
+const LIST_BLANK: ([u8; 2], usize) = envparse::parse_env!("BLANK" as list(u8; max 2) loose else &[5]);
+const LIST_MISSING: ([u8; 2], usize) = envparse::parse_env!("MISSING" as list(u8; max 2, fill 1) loose else &[]);
+
+fn main() {
+    assert_eq!(SPACES, [1, 2, 3]);
+    assert_eq!(MIXED, [1, 2, 3]);
+    assert_eq!(TABS, [true, false]);
+    assert_eq!(SHORT, [7, 8, 3]);
+    assert_eq!(LIST, ([1, 2, 3, 0], 3));
+    assert_eq!(LIST_FILL, ([1, 2, 3, 9], 3));
+    // Only separators is an empty list in loose mode, not a blank value.
+    assert_eq!(LIST_BLANK, ([0, 0], 0));
+    assert_eq!(LIST_MISSING, ([1, 1], 0));
+}
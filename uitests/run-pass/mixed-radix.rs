@@ -0,0 +1,15 @@
+// compile-flags: --error-format=human
+// rustc-env:MASKS=0xff,0b1010,16
+// rustc-env:LOOSE_MASKS=0o17 0XFF 1_000
+#![crate_type = "bin"]
+extern crate envparse;
+
+const MASKS: [u8; 3] = envparse::parse_env!("MASKS" as [u8; 3]);
+const MASK_LIST: ([u32; 4], usize) = envparse::parse_env!("MASKS" as list(u32; max 4));
+const LOOSE_MASKS: [u16; 3] = envparse::parse_env!("LOOSE_MASKS" as [u16; 3] loose);
+
+fn main() {
+    assert_eq!(MASKS, [255, 10, 16]);
+    assert_eq!(MASK_LIST, ([255, 10, 16, 0], 3));
+    assert_eq!(LOOSE_MASKS, [15, 255, 1000]);
+}
@@ -0,0 +1,23 @@
+// compile-flags: --error-format=human
+// rustc-env:BARE=644
+// rustc-env:ZERO=0644
+// rustc-env:PREFIXED=0o1777
+// rustc-env:MODES=600, 0640, 0o755
+#![crate_type = "bin"]
+extern crate envparse;
+
+const BARE: u32 = envparse::parse_env!("BARE" as mode);
+const ZERO: u32 = envparse::parse_env!("ZERO" as mode);
+const PREFIXED: u32 = envparse::parse_env!("PREFIXED" as mode else 0);
+const MISSING: u32 = envparse::parse_env!("MISSING" as mode else 0o600);
+const MODES: [u32; 3] = envparse::parse_env!("MODES" as [mode; 3]);
+
+envparse::assert_parses!("0755" as mode => 0o755);
+
+fn main() {
+    assert_eq!(BARE, 0o644);
+    assert_eq!(ZERO, 0o644);
+    assert_eq!(PREFIXED, 0o1777);
+    assert_eq!(MISSING, 0o600);
+    assert_eq!(MODES, [0o600, 0o640, 0o755]);
+}
@@ -0,0 +1,25 @@
+// compile-flags: --error-format=human
+// rustc-env:WORKERS=4
+// rustc-env:OFFSET=-3
+// rustc-env:SIZES=1, 2, 0x10
+#![crate_type = "bin"]
+extern crate envparse;
+use core::num::{NonZeroI8, NonZeroU32, NonZeroUsize};
+
+const WORKERS: NonZeroU32 = envparse::parse_env!("WORKERS" as NonZeroU32);
+const WORKERS_IN: NonZeroU32 = envparse::parse_env!("WORKERS" as NonZeroU32 in 1..=16);
+const WORKERS_FROM: NonZeroU32 = envparse::parse_env!("WORKERS" as NonZeroU32 in 2..);
+const OFFSET: NonZeroI8 = envparse::parse_env!("OFFSET" as NonZeroI8 in ..0);
+const SIZES: [NonZeroUsize; 3] = envparse::parse_env!("SIZES" as [NonZeroUsize; 3]);
+const MISSING: NonZeroU32 = envparse::parse_env!("MISSING" as NonZeroU32 (in 1..10) else NonZeroU32::MIN);
+const TRY_MISSING: Option<NonZeroU32> = envparse::parse_env!(try "MISSING" as NonZeroU32 in 1..10);
+
+fn main() {
+    assert_eq!(WORKERS.get(), 4);
+    assert_eq!(WORKERS_IN.get(), 4);
+    assert_eq!(WORKERS_FROM.get(), 4);
+    assert_eq!(OFFSET.get(), -3);
+    assert_eq!(SIZES.map(NonZeroUsize::get), [1, 2, 16]);
+    assert_eq!(MISSING, NonZeroU32::MIN);
+    assert_eq!(TRY_MISSING, None);
+}
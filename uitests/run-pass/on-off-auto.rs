@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:COLOR_ON=on
+// rustc-env:COLOR_OFF= OFF
+// rustc-env:COLOR_AUTO=Auto
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::OnOffAuto;
+
+const COLOR_ON: OnOffAuto = envparse::parse_env!("COLOR_ON" as on_off_auto);
+const COLOR_OFF: OnOffAuto = envparse::parse_env!("COLOR_OFF" as on_off_auto);
+const COLOR_AUTO: OnOffAuto = envparse::parse_env!("COLOR_AUTO" as on_off_auto else OnOffAuto::Off);
+const MISSING: OnOffAuto = envparse::parse_env!("MISSING" as on_off_auto else OnOffAuto::Auto);
+const TRY_MISSING: Option<OnOffAuto> = envparse::parse_env!(try "MISSING" as on_off_auto);
+
+fn main() {
+    assert_eq!(COLOR_ON, OnOffAuto::On);
+    assert_eq!(COLOR_OFF, OnOffAuto::Off);
+    assert_eq!(COLOR_AUTO, OnOffAuto::Auto);
+    assert_eq!(MISSING, OnOffAuto::Auto);
+    assert_eq!(TRY_MISSING, None);
+}
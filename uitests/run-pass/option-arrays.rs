@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:OVERRIDES=1,,0
+// rustc-env:OVERRIDES_SHORT=1
+// rustc-env:OVERRIDES_TRAILING=,0
+// rustc-env:OVERRIDES_EMPTY=
+// rustc-env:LIMITS=10, , 0x20
+#![crate_type = "bin"]
+extern crate envparse;
+
+const OVERRIDES: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES" as [Option<bool>; 3]);
+const OVERRIDES_SHORT: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES_SHORT" as [Option<bool>; 3]);
+const OVERRIDES_TRAILING: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES_TRAILING" as [Option<bool>; 3]);
+const OVERRIDES_EMPTY: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES_EMPTY" as [Option<bool>; 3]);
+const OVERRIDES_DEF: [Option<bool>; 3] = envparse::parse_env!("OVERRIDES" as [Option<bool>; 3] else [None; 3]);
+const MISSING: [Option<bool>; 3] = envparse::parse_env!("MISSING" as [Option<bool>; 3] else [Some(true), None, None]);
+const LIMITS: [Option<u32>; 4] = envparse::parse_env!("LIMITS" as [Option<u32>; 4]);
+
+fn main() {
+    assert_eq!(OVERRIDES, [Some(true), None, Some(false)]);
+    assert_eq!(OVERRIDES_SHORT, [Some(true), None, None]);
+    assert_eq!(OVERRIDES_TRAILING, [None, Some(false), None]);
+    assert_eq!(OVERRIDES_EMPTY, [None, None, None]);
+    assert_eq!(OVERRIDES_DEF, [Some(true), None, Some(false)]);
+    assert_eq!(MISSING, [Some(true), None, None]);
+    assert_eq!(LIMITS, [Some(10), None, Some(32), None]);
+}
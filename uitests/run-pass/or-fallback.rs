@@ -0,0 +1,31 @@
+// compile-flags: --error-format=human
+// rustc-env:GOOD=12
+// rustc-env:BAD=twelve
+// rustc-env:BIG=300
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::Status;
+
+const GOOD: u32 = envparse::parse_env!("GOOD" as u32 or_fallback 1);
+const BAD: u32 = envparse::parse_env!("BAD" as u32 or_fallback 2);
+const OUT_OF_RANGE: u8 = envparse::parse_env!("BIG" as u8 or_fallback 3);
+
+const GOOD_STATUS: Status<u32> = envparse::parse_env!(status "GOOD" as u32 or_fallback 1);
+const BAD_STATUS: Status<bool> = envparse::parse_env!(status "BAD" as bool or_fallback true);
+const BLANK_STATUS: Status<usize> = envparse::parse_env!(status "BLANK" as usize or_fallback 4);
+const MISSING_STATUS: Status<u32> = envparse::parse_env!(status "MISSING" as u32 or_fallback 5);
+
+const HEALTH: [(&str, bool); 4] =
+    [GOOD_STATUS.health(), BAD_STATUS.health(), BLANK_STATUS.health(), MISSING_STATUS.health()];
+
+fn main() {
+    assert_eq!(GOOD, 12);
+    assert_eq!(BAD, 2);
+    assert_eq!(OUT_OF_RANGE, 3);
+    assert_eq!(GOOD_STATUS, Status { value: 12, var_name: "GOOD", fell_back: false });
+    assert_eq!(BAD_STATUS, Status { value: true, var_name: "BAD", fell_back: true });
+    assert_eq!(BLANK_STATUS.value, 4);
+    assert_eq!(MISSING_STATUS.value, 5);
+    assert_eq!(HEALTH, [("GOOD", false), ("BAD", true), ("BLANK", true), ("MISSING", true)]);
+}
@@ -0,0 +1,19 @@
+// compile-flags: --error-format=human
+// rustc-env:ARENA=16
+// rustc-env:HUGE=0x2
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PAGE_SIZE: usize = 4096;
+
+const ARENA: usize = envparse::parse_env!("ARENA" as pages(4096));
+const ARENA_IN: usize = envparse::parse_env!("ARENA" as pages(PAGE_SIZE) in PAGE_SIZE..=1 << 20);
+const HUGE: usize = envparse::parse_env!("HUGE" as pages(2 << 20) else 0);
+const MISSING: usize = envparse::parse_env!("MISSING" as pages(PAGE_SIZE) (in ..=1 << 20) else 8 * PAGE_SIZE);
+
+fn main() {
+    assert_eq!(ARENA, 65536);
+    assert_eq!(ARENA_IN, 65536);
+    assert_eq!(HUGE, 4 << 20);
+    assert_eq!(MISSING, 32768);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:WEIGHTS=a:3,b:1,c:5
+// rustc-env:SPACED= fast : 10 , slow:0x20 
+// rustc-env:BLANK= 
+// rustc-env:ARROWS=up=1,down=-1
+#![crate_type = "bin"]
+extern crate envparse;
+
+const WEIGHTS: ([&str; 3], [u32; 3]) = envparse::parse_env!("WEIGHTS" as pairs(str ':' u32; 3));
+const SPACED: ([&str; 2], [u8; 2]) = envparse::parse_env!("SPACED" as pairs(str ':' u8; 2));
+const BLANK: ([&str; 1], [bool; 1]) = envparse::parse_env!("BLANK" as pairs(str ':' bool; 1) else (["x"], [true]));
+const MISSING: ([&str; 2], [u32; 2]) = envparse::parse_env!("MISSING" as pairs(str ':' u32; 2) else (["a", "b"], [1, 2]));
+const ARROWS: ([&str; 2], [i8; 2]) = envparse::parse_env!("ARROWS" as pairs(str '=' i8; 2));
+
+fn main() {
+    assert_eq!(WEIGHTS, (["a", "b", "c"], [3, 1, 5]));
+    assert_eq!(SPACED, (["fast", "slow"], [10, 32]));
+    assert_eq!(BLANK, (["x"], [true]));
+    assert_eq!(MISSING, (["a", "b"], [1, 2]));
+    assert_eq!(ARROWS, (["up", "down"], [1, -1]));
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:ENTRY=timeout:500
+// rustc-env:ENTRY_SPACES= retries = 0x10
+// rustc-env:ENTRY_UNICODE=naïve→yes
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ENTRY: (&str, u32) = envparse::parse_env!("ENTRY" as (str ':' u32));
+const ENTRY_SPACES: (&str, u8) = envparse::parse_env!("ENTRY_SPACES" as (str '=' u8));
+const ENTRY_UNICODE: (&str, bool) = envparse::parse_env!("ENTRY_UNICODE" as (str '→' bool));
+const ENTRY_LEN: (&str, u32) = envparse::parse_env!("ENTRY" as (str (in 1..=7) ':' u32));
+const ENTRY_DEF: (&str, u32) = envparse::parse_env!("ENTRY" as (str ':' u32) else ("none", 0));
+const MISSING: (&str, i64) = envparse::parse_env!("MISSING" as (str (in 1..) ':' i64) else ("none", -1));
+
+fn main() {
+    assert_eq!(ENTRY, ("timeout", 500));
+    assert_eq!(ENTRY_SPACES, ("retries", 16));
+    assert_eq!(ENTRY_UNICODE, ("naïve", true));
+    assert_eq!(ENTRY_LEN, ("timeout", 500));
+    assert_eq!(ENTRY_DEF, ("timeout", 500));
+    assert_eq!(MISSING, ("none", -1));
+}
@@ -0,0 +1,15 @@
+// compile-flags: --error-format=human
+// rustc-env:AGENT=mycrate/1.0 (+https://example.com)
+// rustc-env:PADDED=  ~tilde~  
+#![crate_type = "bin"]
+extern crate envparse;
+
+const AGENT: &str = envparse::parse_env!("AGENT" as str printable);
+const PADDED: &str = envparse::parse_env!("PADDED" as trimmed_str printable else "x");
+const MISSING: &str = envparse::parse_env!("MISSING" as str printable else "default value");
+
+fn main() {
+    assert_eq!(AGENT, "mycrate/1.0 (+https://example.com)");
+    assert_eq!(PADDED, "~tilde~");
+    assert_eq!(MISSING, "default value");
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:YES=yes
+// rustc-env:NEVER= Never 
+// rustc-env:PROMPT=PROMPT
+// rustc-env:POLICIES=always, no, ask
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::PromptPolicy;
+
+const YES: PromptPolicy = envparse::parse_env!("YES" as prompt_policy);
+const NEVER: PromptPolicy = envparse::parse_env!("NEVER" as prompt_policy);
+const PROMPT: PromptPolicy = envparse::parse_env!("PROMPT" as prompt_policy else PromptPolicy::Never);
+const POLICIES: [PromptPolicy; 3] = envparse::parse_env!("POLICIES" as [prompt_policy; 3]);
+const MISSING: PromptPolicy = envparse::parse_env!("MISSING" as prompt_policy else PromptPolicy::Ask);
+
+fn main() {
+    assert_eq!(YES, PromptPolicy::Always);
+    assert_eq!(NEVER, PromptPolicy::Never);
+    assert_eq!(PROMPT, PromptPolicy::Ask);
+    assert_eq!(POLICIES, [PromptPolicy::Always, PromptPolicy::Never, PromptPolicy::Ask]);
+    assert_eq!(MISSING, PromptPolicy::Ask);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:COEF=1.5
+// rustc-env:NEG=-0.25
+// rustc-env:LOUD=4
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const COEF: i128 = envparse::parse_env!("COEF" as q(16, 16));
+const NEG: i16 = envparse::parse_env!("NEG" as q(1, 15)) as i16;
+const LOUD: i128 = envparse::parse_env!("LOUD" as q(1, 15) clamp);
+const BLANK: i128 = envparse::parse_env!("BLANK" as q(8, 8) else 0x100);
+const MISSING: i128 = envparse::parse_env!("MISSING" as q(8, 8) clamp else -0x8000);
+
+fn main() {
+    assert_eq!(COEF, 0x0001_8000);
+    assert_eq!(NEG, -0x2000);
+    assert_eq!(LOUD, 0x7fff);
+    assert_eq!(BLANK, 0x100);
+    assert_eq!(MISSING, -0x8000);
+}
@@ -0,0 +1,22 @@
+// compile-flags: --error-format=human
+// rustc-env:U128_MAX=340282366920938463463374607431768211455
+// rustc-env:I128_MIN=-170141183460469231731687303715884105728
+// rustc-env:I128_MAX=170141183460469231731687303715884105727
+#![crate_type = "bin"]
+extern crate envparse;
+
+const U128_MAX: u128 = envparse::parse_env!("U128_MAX" as u128 in ..);
+const U128_FROM: u128 = envparse::parse_env!("U128_MAX" as u128 in 1..);
+const I128_MIN: i128 = envparse::parse_env!("I128_MIN" as i128 in ..);
+const I128_MAX: i128 = envparse::parse_env!("I128_MAX" as i128 in ..);
+const I128_MAX_INCL: i128 = envparse::parse_env!("I128_MAX" as i128 in ..=i128::MAX);
+const U128_DEFAULT: u128 = envparse::parse_env!("MISSING" as u128 (in ..) else u128::MAX);
+
+fn main() {
+    assert_eq!(U128_MAX, u128::MAX);
+    assert_eq!(U128_FROM, u128::MAX);
+    assert_eq!(I128_MIN, i128::MIN);
+    assert_eq!(I128_MAX, i128::MAX);
+    assert_eq!(I128_MAX_INCL, i128::MAX);
+    assert_eq!(U128_DEFAULT, u128::MAX);
+}
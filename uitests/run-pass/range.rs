@@ -32,20 +32,20 @@ macro_rules! def_check {
             const TRY_THIRTY2_7: Option<$tunsigned> = envparse::parse_env!(try "THIRTY_TWO" as $tunsigned in 30..);
 
             const THIRTY2_DEF_1: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned else 42);
-            const THIRTY2_DEF_2: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 0..33) else 42);
-            const THIRTY2_DEF_3: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 0..=32) else 42);
-            const THIRTY2_DEF_4: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in ..=32) else 42);
-            const THIRTY2_DEF_5: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in ..33) else 42);
+            const THIRTY2_DEF_2: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 0..33) else 31);
+            const THIRTY2_DEF_3: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 0..=32) else 31);
+            const THIRTY2_DEF_4: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in ..=32) else 31);
+            const THIRTY2_DEF_5: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in ..33) else 31);
             const THIRTY2_DEF_6: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in ..) else 42);
-            const THIRTY2_DEF_7: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 30..) else 42);
+            const THIRTY2_DEF_7: $tunsigned = envparse::parse_env!("THIRTY_TWO" as $tunsigned (in 30..) else 31);
 
             const MISSING_1: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned else 42);
-            const MISSING_2: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 0..33) else 42);
-            const MISSING_3: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 0..=32) else 42);
-            const MISSING_4: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in ..=32) else 42);
-            const MISSING_5: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in ..33) else 42);
+            const MISSING_2: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 0..33) else 31);
+            const MISSING_3: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 0..=32) else 31);
+            const MISSING_4: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in ..=32) else 31);
+            const MISSING_5: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in ..33) else 31);
             const MISSING_6: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in ..) else 42);
-            const MISSING_7: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 30..) else 42);
+            const MISSING_7: $tunsigned = envparse::parse_env!("MISSING" as $tunsigned (in 30..) else 31);
 
             pub fn check() {
                 assert_eq!(THIRTY2_1, 32);
@@ -80,12 +80,12 @@ macro_rules! def_check {
                 assert_eq!(TRY_THIRTY2_7, Some(32));
 
                 assert_eq!(MISSING_1, 42);
-                assert_eq!(MISSING_2, 42);
-                assert_eq!(MISSING_3, 42);
-                assert_eq!(MISSING_4, 42);
-                assert_eq!(MISSING_5, 42);
+                assert_eq!(MISSING_2, 31);
+                assert_eq!(MISSING_3, 31);
+                assert_eq!(MISSING_4, 31);
+                assert_eq!(MISSING_5, 31);
                 assert_eq!(MISSING_6, 42);
-                assert_eq!(MISSING_7, 42);
+                assert_eq!(MISSING_7, 31);
             }
         }
     };
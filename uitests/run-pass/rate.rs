@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:UPLOAD=10MB/s
+// rustc-env:DOWNLOAD=1k/ms
+// rustc-env:TRICKLE=120B/min
+// rustc-env:LINKS=1G/s, 100mB/s
+#![crate_type = "bin"]
+extern crate envparse;
+
+const UPLOAD: u64 = envparse::parse_env!("UPLOAD" as rate);
+const DOWNLOAD: u64 = envparse::parse_env!("DOWNLOAD" as rate in 1..);
+const TRICKLE: u64 = envparse::parse_env!("TRICKLE" as rate);
+const LINKS: [u64; 2] = envparse::parse_env!("LINKS" as [rate; 2]);
+const MISSING: u64 = envparse::parse_env!("MISSING" as rate (in 1..) else 4096);
+
+fn main() {
+    assert_eq!(UPLOAD, 10 << 20);
+    assert_eq!(DOWNLOAD, 1_000_000);
+    assert_eq!(TRICKLE, 2);
+    assert_eq!(LINKS, [1 << 30, 100_000_000]);
+    assert_eq!(MISSING, 4096);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:GAIN=-3/4
+// rustc-env:WHOLE=2
+#![crate_type = "bin"]
+extern crate envparse;
+
+const GAIN: (i128, i128) = envparse::parse_env!("GAIN" as ratio);
+const WHOLE: (i128, i128) = envparse::parse_env!("WHOLE" as ratio);
+const GAIN_DEF: (i128, i128) = envparse::parse_env!("GAIN" as ratio else (1, 2));
+const MISSING: (i128, i128) = envparse::parse_env!("MISSING" as ratio else (1, 2));
+const TRY_GAIN: Option<(i128, i128)> = envparse::parse_env!(try "GAIN" as ratio);
+const LIT: (i128, i128) = envparse::parse_env!(lit "10/4" as ratio);
+
+fn main() {
+    assert_eq!(GAIN, (-3, 4));
+    assert_eq!(WHOLE, (2, 1));
+    assert_eq!(GAIN_DEF, (-3, 4));
+    assert_eq!(MISSING, (1, 2));
+    assert_eq!(TRY_GAIN, Some((-3, 4)));
+    assert_eq!(LIT, (10, 4));
+}
@@ -0,0 +1,25 @@
+// compile-flags: --error-format=human
+// rustc-env:MINUS=N-1
+// rustc-env:HALF=n / 2
+// rustc-env:PLAIN=3
+// rustc-env:UNDER=N-100
+// rustc-env:OVER=N*100
+#![crate_type = "bin"]
+extern crate envparse;
+
+const CORES: usize = 8;
+const MINUS: usize = envparse::parse_env!("MINUS" as usize relative_to CORES);
+const HALF: usize = envparse::parse_env!("HALF" as usize relative_to CORES);
+const PLAIN: usize = envparse::parse_env!("PLAIN" as usize relative_to CORES else 1);
+const UNDER: u32 = envparse::parse_env!("UNDER" as u32 relative_to 8);
+const OVER: u8 = envparse::parse_env!("OVER" as u8 relative_to (CORES as u8 * 2));
+const MISSING: usize = envparse::parse_env!("MISSING" as usize relative_to CORES else CORES);
+
+fn main() {
+    assert_eq!(MINUS, 7);
+    assert_eq!(HALF, 4);
+    assert_eq!(PLAIN, 3);
+    assert_eq!(UNDER, 0);
+    assert_eq!(OVER, 255);
+    assert_eq!(MISSING, 8);
+}
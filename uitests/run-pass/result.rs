@@ -0,0 +1,23 @@
+// compile-flags: --error-format=human
+// rustc-env:GOOD=0x20
+// rustc-env:BAD=12x
+// rustc-env:HUGE=300
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const GOOD: Result<u32, &str> = envparse::parse_env!(result "GOOD" as u32);
+const BAD: Result<u32, &str> = envparse::parse_env!(result "BAD" as u32);
+const HUGE: Result<u8, &str> = envparse::parse_env!(result "HUGE" as u8 else 1);
+const BLANK: Result<bool, &str> = envparse::parse_env!(result "BLANK" as bool else true);
+const UNSET: Result<u32, &str> = envparse::parse_env!(result "MISSING" as u32);
+const UNSET_DEFAULT: Result<u32, &str> = envparse::parse_env!(result "MISSING" as u32 else 7);
+
+fn main() {
+    assert_eq!(GOOD, Ok(32));
+    assert_eq!(BAD, Err("the value in `BAD` doesn't parse as a `u32`: invalid digit `x` for base 10."));
+    assert_eq!(HUGE, Err("the value in `HUGE` doesn't parse as a `u8`, or is out of range."));
+    assert_eq!(BLANK, Ok(true));
+    assert_eq!(UNSET, Err("`MISSING` isn't set."));
+    assert_eq!(UNSET_DEFAULT, Ok(7));
+}
@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:LOG=info,my_crate::net=trace,my_crate::db=off
+// rustc-env:MODULES_ONLY=my_crate=debug
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const GLOBAL: u8 = envparse::parse_env!("LOG" as rust_log);
+const NET: u8 = envparse::parse_env!("LOG" as rust_log "my_crate::net");
+const DB: u8 = envparse::parse_env!("LOG" as rust_log "my_crate::db::pool" else 3);
+const OTHER: u8 = envparse::parse_env!("LOG" as rust_log "other");
+const MODULES_ONLY: u8 = envparse::parse_env!("MODULES_ONLY" as rust_log else 2);
+const MODULE: u8 = envparse::parse_env!("MODULES_ONLY" as rust_log "my_crate");
+const BLANK: u8 = envparse::parse_env!("BLANK" as rust_log else 2);
+const MISSING: u8 = envparse::parse_env!("MISSING" as rust_log "my_crate" else 1);
+
+fn main() {
+    assert_eq!(GLOBAL, 3);
+    assert_eq!(NET, 5);
+    assert_eq!(DB, 0);
+    assert_eq!(OTHER, 3);
+    assert_eq!(MODULES_ONLY, 0);
+    assert_eq!(MODULE, 4);
+    assert_eq!(BLANK, 2);
+    assert_eq!(MISSING, 1);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:POOL=8:1:64
+// rustc-env:SPACED= 0x10 : 0 : 0x20
+// rustc-env:SIGNED=-5:-10:10
+// rustc-env:EDGE=64:1:64
+#![crate_type = "bin"]
+extern crate envparse;
+
+const POOL: usize = envparse::parse_env!("POOL" as self_bounded(usize));
+const SPACED: u8 = envparse::parse_env!("SPACED" as self_bounded(u8));
+const SIGNED: i32 = envparse::parse_env!("SIGNED" as self_bounded(i32) else 0);
+const EDGE: u16 = envparse::parse_env!("EDGE" as self_bounded(u16));
+const MISSING: usize = envparse::parse_env!("MISSING" as self_bounded(usize) else 4);
+
+fn main() {
+    assert_eq!(POOL, 8);
+    assert_eq!(SPACED, 16);
+    assert_eq!(SIGNED, -5);
+    assert_eq!(EDGE, 64);
+    assert_eq!(MISSING, 4);
+}
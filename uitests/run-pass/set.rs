@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:ENABLED=alpha,gamma
+// rustc-env:ENABLED_MESSY= Gamma ,, beta,gamma,
+// rustc-env:ENABLED_NONE=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const ENABLED: u64 = envparse::parse_env_set!("ENABLED" in ["alpha", "beta", "gamma"]);
+const ENABLED_MESSY: u64 = envparse::parse_env_set!("ENABLED_MESSY" in ["alpha", "beta", "gamma",]);
+const ENABLED_NONE: u64 = envparse::parse_env_set!("ENABLED_NONE" in ["alpha", "beta", "gamma"]);
+const ENABLED_DEF: u64 = envparse::parse_env_set!("ENABLED" in ["alpha", "beta", "gamma"] else 0b111);
+const MISSING: u64 = envparse::parse_env_set!("MISSING" in ["alpha", "beta", "gamma"] else 0b010);
+
+fn main() {
+    assert_eq!(ENABLED, 0b101);
+    assert_eq!(ENABLED_MESSY, 0b110);
+    assert_eq!(ENABLED_NONE, 0);
+    assert_eq!(ENABLED_DEF, 0b101);
+    assert_eq!(MISSING, 0b010);
+}
@@ -0,0 +1,26 @@
+// compile-flags: --error-format=human
+// rustc-env:ORDER_ASC=asc
+// rustc-env:ORDER_DESC=Descending
+// rustc-env:ORDER_SYM=>
+// rustc-env:ORDERS=<, desc, ascending
+#![crate_type = "bin"]
+extern crate envparse;
+use envparse::SortDir;
+
+const ORDER_ASC: SortDir = envparse::parse_env!("ORDER_ASC" as sort_dir);
+const ORDER_DESC: SortDir = envparse::parse_env!("ORDER_DESC" as sort_dir);
+const ORDER_SYM: SortDir = envparse::parse_env!("ORDER_SYM" as sort_dir else SortDir::Ascending);
+const ORDERS: [SortDir; 3] = envparse::parse_env!("ORDERS" as [sort_dir; 3]);
+const MISSING: SortDir = envparse::parse_env!("MISSING" as sort_dir else SortDir::Descending);
+
+fn main() {
+    assert_eq!(ORDER_ASC, SortDir::Ascending);
+    assert_eq!(ORDER_DESC, SortDir::Descending);
+    assert_eq!(ORDER_SYM, SortDir::Descending);
+    assert_eq!(ORDERS, [SortDir::Ascending, SortDir::Descending, SortDir::Ascending]);
+    assert_eq!(MISSING, SortDir::Descending);
+
+    let mut v = [3, 1, 2];
+    v.sort_by(|a, b| ORDER_DESC.apply(a.cmp(b)));
+    assert_eq!(v, [3, 2, 1]);
+}
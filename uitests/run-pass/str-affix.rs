@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:URL=https://example.com
+// rustc-env:SHOUTY_URL=HTTPS://EXAMPLE.COM
+// rustc-env:CONFIG=settings.toml
+#![crate_type = "bin"]
+extern crate envparse;
+
+const URL: &str = envparse::parse_env!("URL" as str starts_with "https://");
+const SHOUTY_URL: &str = envparse::parse_env!("SHOUTY_URL" as trimmed_str starts_with_ignore_case "https://");
+const CONFIG: &str = envparse::parse_env!("CONFIG" as str ends_with ".toml" else "default.toml");
+const SHOUTY_CONFIG: &str = envparse::parse_env!("CONFIG" as str ends_with_ignore_case ".TOML");
+const MISSING: &str = envparse::parse_env!("MISSING" as str starts_with "https://" else "https://localhost");
+
+fn main() {
+    assert_eq!(URL, "https://example.com");
+    assert_eq!(SHOUTY_URL, "HTTPS://EXAMPLE.COM");
+    assert_eq!(CONFIG, "settings.toml");
+    assert_eq!(SHOUTY_CONFIG, "settings.toml");
+    assert_eq!(MISSING, "https://localhost");
+}
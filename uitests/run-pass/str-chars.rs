@@ -0,0 +1,20 @@
+// compile-flags: --error-format=human
+// rustc-env:NAME=my_crate_2
+// rustc-env:PADDED=  some-name 
+// rustc-env:BLANK=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const NAME: &str = envparse::parse_env!("NAME" as str chars "a-zA-Z0-9_");
+const PADDED: &str = envparse::parse_env!("PADDED" as trimmed_str chars "a-z-");
+const BLANK: &str = envparse::parse_env!("BLANK" as str chars "a-z");
+const BLANK_DEF: &str = envparse::parse_env!("BLANK" as trimmed_str chars "a-z" else "fallback");
+const MISSING: &str = envparse::parse_env!("MISSING" as str chars "-+0-9" else "-42");
+
+fn main() {
+    assert_eq!(NAME, "my_crate_2");
+    assert_eq!(PADDED, "some-name");
+    assert_eq!(BLANK, "");
+    assert_eq!(BLANK_DEF, "fallback");
+    assert_eq!(MISSING, "-42");
+}
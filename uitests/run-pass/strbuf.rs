@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:PLAIN=hello
+// rustc-env:QUOTED="tab\there\n"
+// rustc-env:EMPTY=
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PLAIN: ([u8; 8], usize) = envparse::parse_env!("PLAIN" as strbuf[8]);
+const EXACT: ([u8; 5], usize) = envparse::parse_env!("PLAIN" as strbuf[5]);
+const QUOTED: ([u8; 16], usize) = envparse::parse_env!("QUOTED" as strbuf[16]);
+const EMPTY: ([u8; 4], usize) = envparse::parse_env!("EMPTY" as strbuf[4] else "dflt");
+const MISSING: ([u8; 8], usize) = envparse::parse_env!("MISSING" as strbuf[8] else "abc");
+
+fn main() {
+    assert_eq!(PLAIN, (*b"hello\0\0\0", 5));
+    assert_eq!(EXACT, (*b"hello", 5));
+    assert_eq!(&QUOTED.0[..QUOTED.1], b"tab\there\n");
+    assert_eq!(QUOTED.0[QUOTED.1..], [0; 7]);
+    assert_eq!(EMPTY, (*b"dflt", 4));
+    assert_eq!(MISSING, (*b"abc\0\0\0\0\0", 3));
+}
@@ -0,0 +1,28 @@
+// compile-flags: --error-format=human
+// rustc-env:GREETING=hello world
+#![crate_type = "bin"]
+extern crate envparse;
+
+const GREETING: &str = envparse::parse_env!("GREETING" as str);
+const TRIMMED: &str = envparse::parse_env!("GREETING" as trimmed_str);
+const MISSING: &str = envparse::parse_env!("MISSING" as str else "fallback");
+const TRY_MISSING: Option<&str> = envparse::parse_env!(try "MISSING" as trimmed_str);
+
+// What you'd get from `$(cat file)` or a here-doc.
+const RAW_NEWLINE: &str = envparse::parse_env!(lit "foo\n" as str);
+const TRIMMED_NEWLINE: &str = envparse::parse_env!(lit " foo\n" as trimmed_str);
+const BLANK: &str = envparse::parse_env!(lit " \n" as trimmed_str else "fallback");
+const NUM_NEWLINE: u32 = envparse::parse_env!(lit "42\n" as u32);
+const BOOL_NEWLINE: bool = envparse::parse_env!(lit "yes\n" as bool);
+
+fn main() {
+    assert_eq!(GREETING, "hello world");
+    assert_eq!(TRIMMED, "hello world");
+    assert_eq!(MISSING, "fallback");
+    assert_eq!(TRY_MISSING, None);
+    assert_eq!(RAW_NEWLINE, "foo\n");
+    assert_eq!(TRIMMED_NEWLINE, "foo");
+    assert_eq!(BLANK, "fallback");
+    assert_eq!(NUM_NEWLINE, 42);
+    assert_eq!(BOOL_NEWLINE, true);
+}
@@ -0,0 +1,21 @@
+// compile-flags: --error-format=human
+// rustc-env:DEBUG=on # enabled for staging
+// rustc-env:TIGHT=0x10#sixteen
+// rustc-env:ONLY_COMMENT=# nothing here
+// rustc-env:PLAIN=42
+#![crate_type = "bin"]
+extern crate envparse;
+
+const DEBUG: bool = envparse::parse_env!("DEBUG" as bool strip_comments);
+const TIGHT: u32 = envparse::parse_env!("TIGHT" as u32 strip_comments);
+const ONLY_COMMENT: u32 = envparse::parse_env!("ONLY_COMMENT" as u32 strip_comments else 7);
+const PLAIN: i64 = envparse::parse_env!("PLAIN" as i64 strip_comments else 0);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool strip_comments else true);
+
+fn main() {
+    assert!(DEBUG);
+    assert_eq!(TIGHT, 16);
+    assert_eq!(ONLY_COMMENT, 7);
+    assert_eq!(PLAIN, 42);
+    assert!(MISSING);
+}
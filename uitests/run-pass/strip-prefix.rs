@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:PREFIXED=default:30
+// rustc-env:PLAIN=45
+// rustc-env:ONLY_PREFIX=Default:
+#![crate_type = "bin"]
+extern crate envparse;
+
+const PREFIXED: u32 = envparse::parse_env!("PREFIXED" as u32 strip_prefix "default:");
+const PLAIN: u32 = envparse::parse_env!("PLAIN" as u32 strip_prefix "default:");
+const ONLY_PREFIX: u32 = envparse::parse_env!("ONLY_PREFIX" as u32 strip_prefix "default:" else 10);
+const MISSING: bool = envparse::parse_env!("MISSING" as bool strip_prefix "default:" else true);
+
+fn main() {
+    assert_eq!(PREFIXED, 30);
+    assert_eq!(PLAIN, 45);
+    assert_eq!(ONLY_PREFIX, 10);
+    assert!(MISSING);
+}
@@ -0,0 +1,27 @@
+// compile-flags: --error-format=human
+// rustc-env:SET=12
+// rustc-env:ZERO=0
+// rustc-env:EMPTY=
+#![crate_type = "bin"]
+extern crate envparse;
+
+// Set and valid: `Some` of the value, whatever the default.
+const SET: Option<u32> = envparse::parse_env!(try "SET" as u32 else Some(4));
+const SET_NONE: Option<u32> = envparse::parse_env!(try "SET" as u32 else None);
+const ZERO: Option<i64> = envparse::parse_env!(try "ZERO" as i64 else Some(-1));
+// Empty: the same as unset.
+const EMPTY: Option<u32> = envparse::parse_env!(try "EMPTY" as u32 else Some(4));
+const EMPTY_NONE: Option<bool> = envparse::parse_env!(try "EMPTY" as bool else None);
+// Unset: the default, `Some` or `None`.
+const MISSING: Option<u32> = envparse::parse_env!(try "MISSING" as u32 else Some(4));
+const MISSING_NONE: Option<u32> = envparse::parse_env!(try "MISSING" as u32 else None);
+
+fn main() {
+    assert_eq!(SET, Some(12));
+    assert_eq!(SET_NONE, Some(12));
+    assert_eq!(ZERO, Some(0));
+    assert_eq!(EMPTY, Some(4));
+    assert_eq!(EMPTY_NONE, None);
+    assert_eq!(MISSING, Some(4));
+    assert_eq!(MISSING_NONE, None);
+}
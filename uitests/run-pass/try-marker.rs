@@ -0,0 +1,24 @@
+// compile-flags: --error-format=human
+// rustc-env:BAD_OPTIONAL=9000?
+// rustc-env:GOOD_OPTIONAL=200 ?
+// rustc-env:GOOD=7
+// rustc-env:ONLY_MARKER=?
+// rustc-env:WORD=maybe?
+#![crate_type = "bin"]
+extern crate envparse;
+
+const BAD_OPTIONAL: u8 = envparse::parse_env!("BAD_OPTIONAL" as u8 try_marker else 3);
+const GOOD_OPTIONAL: u8 = envparse::parse_env!("GOOD_OPTIONAL" as u8 try_marker else 3);
+const GOOD: u8 = envparse::parse_env!("GOOD" as u8 try_marker else 3);
+const ONLY_MARKER: u8 = envparse::parse_env!("ONLY_MARKER" as u8 try_marker else 3);
+const WORD: bool = envparse::parse_env!("WORD" as bool try_marker else true);
+const MISSING: u8 = envparse::parse_env!("MISSING" as u8 try_marker else 3);
+
+fn main() {
+    assert_eq!(BAD_OPTIONAL, 3);
+    assert_eq!(GOOD_OPTIONAL, 200);
+    assert_eq!(GOOD, 7);
+    assert_eq!(ONLY_MARKER, 3);
+    assert!(WORD);
+    assert_eq!(MISSING, 3);
+}
@@ -0,0 +1,42 @@
+// compile-flags: --error-format=human
+// rustc-env:ZERO=0
+// rustc-env:NEG_ZERO=-0
+// rustc-env:HEX_ZERO=0x0
+// rustc-env:EMPTY=
+#![crate_type = "bin"]
+extern crate envparse;
+
+// `0` is a real value, and only an unset or empty variable gives `None`.
+const ZERO_I8: Option<i8> = envparse::parse_env!(try "ZERO" as i8);
+const ZERO_I32: Option<i32> = envparse::parse_env!(try "ZERO" as i32);
+const ZERO_ISIZE: Option<isize> = envparse::parse_env!(try "ZERO" as isize);
+const ZERO_I128: Option<i128> = envparse::parse_env!(try "ZERO" as i128);
+const ZERO_INT: Option<i128> = envparse::parse_env!(try "ZERO" as int);
+const NEG_ZERO: Option<i64> = envparse::parse_env!(try "NEG_ZERO" as i64);
+const HEX_ZERO: Option<i16> = envparse::parse_env!(try "HEX_ZERO" as i16);
+const ZERO_IN: Option<i32> = envparse::parse_env!(try "ZERO" as i32 in -10..=10);
+const EMPTY: Option<i32> = envparse::parse_env!(try "EMPTY" as i32);
+const EMPTY_IN: Option<i32> = envparse::parse_env!(try "EMPTY" as i32 in -10..=10);
+const MISSING: Option<i32> = envparse::parse_env!(try "MISSING" as i32);
+
+// The same goes for `else`, where `0` mustn't be mistaken for a blank value.
+const ZERO_ELSE: i32 = envparse::parse_env!("ZERO" as i32 else 7);
+const ZERO_IN_ELSE: i32 = envparse::parse_env!("ZERO" as i32 (in -10..=10) else 7);
+const EMPTY_ELSE: i32 = envparse::parse_env!("EMPTY" as i32 else 7);
+
+fn main() {
+    assert_eq!(ZERO_I8, Some(0));
+    assert_eq!(ZERO_I32, Some(0));
+    assert_eq!(ZERO_ISIZE, Some(0));
+    assert_eq!(ZERO_I128, Some(0));
+    assert_eq!(ZERO_INT, Some(0));
+    assert_eq!(NEG_ZERO, Some(0));
+    assert_eq!(HEX_ZERO, Some(0));
+    assert_eq!(ZERO_IN, Some(0));
+    assert_eq!(EMPTY, None);
+    assert_eq!(EMPTY_IN, None);
+    assert_eq!(MISSING, None);
+    assert_eq!(ZERO_ELSE, 0);
+    assert_eq!(ZERO_IN_ELSE, 0);
+    assert_eq!(EMPTY_ELSE, 7);
+}
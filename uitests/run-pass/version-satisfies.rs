@@ -0,0 +1,24 @@
+// compile-flags: --error-format=human
+// rustc-env:TOOL_VERSION=1.75.0
+// rustc-env:TAGGED= v2.0.1 
+// rustc-env:BLANK= 
+#![crate_type = "bin"]
+extern crate envparse;
+
+const NEW: bool = envparse::parse_env!("TOOL_VERSION" satisfies >=1.70);
+const NEWER: bool = envparse::parse_env!("TOOL_VERSION" satisfies >= 1.80);
+const EXACT: bool = envparse::parse_env!("TOOL_VERSION" satisfies =1.75);
+const OLD: bool = envparse::parse_env!("TOOL_VERSION" satisfies <1.75.0);
+const TAGGED: bool = envparse::parse_env!("TAGGED" satisfies (>1) else false);
+const BLANK: bool = envparse::parse_env!("BLANK" satisfies (>=1.70) else true);
+const MISSING: bool = envparse::parse_env!("MISSING" satisfies (<=2.0) else false);
+
+fn main() {
+    assert!(NEW);
+    assert!(!NEWER);
+    assert!(EXACT);
+    assert!(!OLD);
+    assert!(TAGGED);
+    assert!(BLANK);
+    assert!(!MISSING);
+}
@@ -0,0 +1,18 @@
+// compile-flags: --error-format=human
+// rustc-env:TAG=v1.2.3
+// rustc-env:PLAIN=1.2.3
+// rustc-env:SUPPORTED=V2.0.0, 1.10.4
+#![crate_type = "bin"]
+extern crate envparse;
+
+const TAG: (u64, u64, u64) = envparse::parse_env!("TAG" as version);
+const PLAIN: (u64, u64, u64) = envparse::parse_env!("PLAIN" as version);
+const SUPPORTED: [(u64, u64, u64); 2] = envparse::parse_env!("SUPPORTED" as [version; 2]);
+const MISSING: (u64, u64, u64) = envparse::parse_env!("MISSING" as version else (0, 1, 0));
+
+fn main() {
+    assert_eq!(TAG, (1, 2, 3));
+    assert_eq!(PLAIN, (1, 2, 3));
+    assert_eq!(SUPPORTED, [(2, 0, 0), (1, 10, 4)]);
+    assert_eq!(MISSING, (0, 1, 0));
+}
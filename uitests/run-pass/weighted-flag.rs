@@ -0,0 +1,23 @@
+// compile-flags: --error-format=human
+// rustc-env:HALF=true:50
+// rustc-env:FULL=on
+// rustc-env:DISABLED=false
+// rustc-env:STAGES=on:10, on:50, on
+#![crate_type = "bin"]
+extern crate envparse;
+
+const HALF: (bool, u8) = envparse::parse_env!("HALF" as weighted_flag);
+const FULL: (bool, u8) = envparse::parse_env!("FULL" as weighted_flag);
+const DISABLED: (bool, u8) = envparse::parse_env!("DISABLED" as weighted_flag else (true, 5));
+const MISSING: (bool, u8) = envparse::parse_env!("MISSING" as weighted_flag else (true, 5));
+const STAGES: [(bool, u8); 3] = envparse::parse_env!("STAGES" as [weighted_flag; 3]);
+
+envparse::assert_parses!("on:25" as weighted_flag => (true, 25));
+
+fn main() {
+    assert_eq!(HALF, (true, 50));
+    assert_eq!(FULL, (true, 100));
+    assert_eq!(DISABLED, (false, 0));
+    assert_eq!(MISSING, (true, 5));
+    assert_eq!(STAGES, [(true, 10), (true, 50), (true, 100)]);
+}
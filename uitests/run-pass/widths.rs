@@ -0,0 +1,118 @@
+// compile-flags: --error-format=human
+#![crate_type = "bin"]
+extern crate envparse;
+
+// Every width, at and just past its limits.
+
+envparse::assert_parses!("0" as u8 => 0);
+envparse::assert_parses!("1" as u8 => 1);
+envparse::assert_parses!("127" as u8 => 127);
+envparse::assert_parses!("254" as u8 => 254);
+envparse::assert_parses!("255" as u8 => u8::MAX);
+envparse::assert_fails!("256" as u8 => OutOfRange);
+envparse::assert_fails!("-1" as u8 => UnexpectedSign);
+
+envparse::assert_parses!("0" as u16 => 0);
+envparse::assert_parses!("1" as u16 => 1);
+envparse::assert_parses!("32767" as u16 => 32767);
+envparse::assert_parses!("65534" as u16 => 65534);
+envparse::assert_parses!("65535" as u16 => u16::MAX);
+envparse::assert_fails!("65536" as u16 => OutOfRange);
+envparse::assert_fails!("-1" as u16 => UnexpectedSign);
+
+envparse::assert_parses!("0" as u32 => 0);
+envparse::assert_parses!("1" as u32 => 1);
+envparse::assert_parses!("2147483647" as u32 => 2147483647);
+envparse::assert_parses!("4294967294" as u32 => 4294967294);
+envparse::assert_parses!("4294967295" as u32 => u32::MAX);
+envparse::assert_fails!("4294967296" as u32 => OutOfRange);
+envparse::assert_fails!("-1" as u32 => UnexpectedSign);
+
+envparse::assert_parses!("0" as u64 => 0);
+envparse::assert_parses!("1" as u64 => 1);
+envparse::assert_parses!("9223372036854775807" as u64 => 9223372036854775807);
+envparse::assert_parses!("18446744073709551614" as u64 => 18446744073709551614);
+envparse::assert_parses!("18446744073709551615" as u64 => u64::MAX);
+envparse::assert_fails!("18446744073709551616" as u64 => OutOfRange);
+envparse::assert_fails!("-1" as u64 => UnexpectedSign);
+
+envparse::assert_parses!("0" as u128 => 0);
+envparse::assert_parses!("1" as u128 => 1);
+envparse::assert_parses!("170141183460469231731687303715884105727" as u128 => 170141183460469231731687303715884105727);
+envparse::assert_parses!("340282366920938463463374607431768211454" as u128 => 340282366920938463463374607431768211454);
+envparse::assert_parses!("340282366920938463463374607431768211455" as u128 => u128::MAX);
+envparse::assert_fails!("340282366920938463463374607431768211456" as u128 => IntOverflow);
+envparse::assert_fails!("-1" as u128 => UnexpectedSign);
+
+envparse::assert_parses!("-128" as i8 => i8::MIN);
+envparse::assert_parses!("-64" as i8 => -64);
+envparse::assert_parses!("0" as i8 => 0);
+envparse::assert_parses!("1" as i8 => 1);
+envparse::assert_parses!("63" as i8 => 63);
+envparse::assert_parses!("126" as i8 => 126);
+envparse::assert_parses!("127" as i8 => i8::MAX);
+envparse::assert_fails!("128" as i8 => OutOfRange);
+envparse::assert_fails!("-129" as i8 => OutOfRange);
+
+envparse::assert_parses!("-32768" as i16 => i16::MIN);
+envparse::assert_parses!("-16384" as i16 => -16384);
+envparse::assert_parses!("0" as i16 => 0);
+envparse::assert_parses!("1" as i16 => 1);
+envparse::assert_parses!("16383" as i16 => 16383);
+envparse::assert_parses!("32766" as i16 => 32766);
+envparse::assert_parses!("32767" as i16 => i16::MAX);
+envparse::assert_fails!("32768" as i16 => OutOfRange);
+envparse::assert_fails!("-32769" as i16 => OutOfRange);
+
+envparse::assert_parses!("-2147483648" as i32 => i32::MIN);
+envparse::assert_parses!("-1073741824" as i32 => -1073741824);
+envparse::assert_parses!("0" as i32 => 0);
+envparse::assert_parses!("1" as i32 => 1);
+envparse::assert_parses!("1073741823" as i32 => 1073741823);
+envparse::assert_parses!("2147483646" as i32 => 2147483646);
+envparse::assert_parses!("2147483647" as i32 => i32::MAX);
+envparse::assert_fails!("2147483648" as i32 => OutOfRange);
+envparse::assert_fails!("-2147483649" as i32 => OutOfRange);
+
+envparse::assert_parses!("-9223372036854775808" as i64 => i64::MIN);
+envparse::assert_parses!("-4611686018427387904" as i64 => -4611686018427387904);
+envparse::assert_parses!("0" as i64 => 0);
+envparse::assert_parses!("1" as i64 => 1);
+envparse::assert_parses!("4611686018427387903" as i64 => 4611686018427387903);
+envparse::assert_parses!("9223372036854775806" as i64 => 9223372036854775806);
+envparse::assert_parses!("9223372036854775807" as i64 => i64::MAX);
+envparse::assert_fails!("9223372036854775808" as i64 => OutOfRange);
+envparse::assert_fails!("-9223372036854775809" as i64 => OutOfRange);
+
+envparse::assert_parses!("-170141183460469231731687303715884105728" as i128 => i128::MIN);
+envparse::assert_parses!("-85070591730234615865843651857942052864" as i128 => -85070591730234615865843651857942052864);
+envparse::assert_parses!("0" as i128 => 0);
+envparse::assert_parses!("1" as i128 => 1);
+envparse::assert_parses!("85070591730234615865843651857942052863" as i128 => 85070591730234615865843651857942052863);
+envparse::assert_parses!("170141183460469231731687303715884105726" as i128 => 170141183460469231731687303715884105726);
+envparse::assert_parses!("170141183460469231731687303715884105727" as i128 => i128::MAX);
+envparse::assert_fails!("170141183460469231731687303715884105728" as i128 => OutOfRange);
+envparse::assert_fails!("-170141183460469231731687303715884105729" as i128 => OutOfRange);
+
+#[cfg(target_pointer_width = "64")]
+mod pointer_sized {
+    envparse::assert_parses!("0" as usize => 0);
+    envparse::assert_parses!("1" as usize => 1);
+    envparse::assert_parses!("9223372036854775807" as usize => 9223372036854775807);
+    envparse::assert_parses!("18446744073709551614" as usize => 18446744073709551614);
+    envparse::assert_parses!("18446744073709551615" as usize => usize::MAX);
+    envparse::assert_fails!("18446744073709551616" as usize => OutOfRange);
+    envparse::assert_fails!("-1" as usize => UnexpectedSign);
+
+    envparse::assert_parses!("-9223372036854775808" as isize => isize::MIN);
+    envparse::assert_parses!("-4611686018427387904" as isize => -4611686018427387904);
+    envparse::assert_parses!("0" as isize => 0);
+    envparse::assert_parses!("1" as isize => 1);
+    envparse::assert_parses!("4611686018427387903" as isize => 4611686018427387903);
+    envparse::assert_parses!("9223372036854775806" as isize => 9223372036854775806);
+    envparse::assert_parses!("9223372036854775807" as isize => isize::MAX);
+    envparse::assert_fails!("9223372036854775808" as isize => OutOfRange);
+    envparse::assert_fails!("-9223372036854775809" as isize => OutOfRange);
+}
+
+fn main() {}
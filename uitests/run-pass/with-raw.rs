@@ -0,0 +1,17 @@
+// compile-flags: --error-format=human
+// rustc-env:LIMIT=0x20
+// rustc-env:VERBOSE=yes
+#![crate_type = "bin"]
+extern crate envparse;
+
+const LIMIT: (u32, &str) = envparse::parse_env!("LIMIT" as u32 with_raw);
+const VERBOSE: (bool, &str) = envparse::parse_env!("VERBOSE" as bool with_raw else false);
+const MISSING: (u32, &str) = envparse::parse_env!("MISSING" as u32 with_raw else 7);
+
+fn main() {
+    assert_eq!(LIMIT, (32, "0x20"));
+    assert_eq!(VERBOSE, (true, "yes"));
+    assert_eq!(MISSING, (7, ""));
+    let (value, raw) = envparse::parse_env!("LIMIT" as u32 with_raw);
+    assert_eq!((value, raw), (32, "0x20"));
+}
@@ -0,0 +1,19 @@
+// compile-flags: --error-format=human
+// rustc-env:SEED=0xffff_ffff
+// rustc-env:STEP=-3
+#![crate_type = "bin"]
+extern crate envparse;
+use core::num::Wrapping;
+
+const SEED: Wrapping<u32> = envparse::parse_env!("SEED" as Wrapping<u32>);
+const STEP: Wrapping<i8> = envparse::parse_env!("STEP" as Wrapping<i8> in -8..8);
+const MISSING: Wrapping<u64> = envparse::parse_env!("MISSING" as Wrapping<u64> else Wrapping(7));
+const RANGED: Wrapping<u16> = envparse::parse_env!("MISSING" as Wrapping<u16> (in 1..10) else Wrapping(5));
+
+fn main() {
+    assert_eq!(SEED, Wrapping(u32::MAX));
+    assert_eq!(SEED + Wrapping(1), Wrapping(0));
+    assert_eq!(STEP, Wrapping(-3));
+    assert_eq!(MISSING, Wrapping(7));
+    assert_eq!(RANGED, Wrapping(5));
+}